@@ -374,6 +374,35 @@ def resolve_reporter(
     )
 
 
+def git_metadata_overrides(evaluator):
+    """Git settings for the experiment: none with --no-git, else repo info collected by bt."""
+    if env_flag("BT_EVAL_NO_GIT"):
+        from braintrust.git_fields import GitMetadataSettings
+
+        return GitMetadataSettings(collect="none"), None
+    repo_info = evaluator.repo_info
+    serialized = os.getenv("BT_EVAL_REPO_INFO")
+    if repo_info is None and serialized:
+        from braintrust.git_fields import RepoInfo
+
+        repo_info = RepoInfo(**json.loads(serialized))
+    return evaluator.git_metadata_settings, repo_info
+
+
+def apply_dataset_override(evaluator):
+    """Apply --dataset (and --dataset-version), replacing the evaluator's own data."""
+    name = os.getenv("BT_EVAL_DATASET")
+    if not name:
+        return
+    from braintrust import init_dataset
+
+    evaluator.data = init_dataset(
+        project=evaluator.project_name,
+        name=name,
+        version=os.getenv("BT_EVAL_DATASET_VERSION") or None,
+    )
+
+
 def _init_experiment_for_eval(evaluator):
     base_experiment_name = None
     if isinstance(evaluator.data, BaseExperiment):
@@ -385,6 +414,8 @@ def _init_experiment_for_eval(evaluator):
 
     from braintrust.framework import init_experiment
 
+    git_metadata_settings, repo_info = git_metadata_overrides(evaluator)
+
     return init_experiment(
         project_name=evaluator.project_name,
         project_id=evaluator.project_id,
@@ -395,8 +426,8 @@ def _init_experiment_for_eval(evaluator):
         update=evaluator.update,
         base_experiment=base_experiment_name,
         base_experiment_id=evaluator.base_experiment_id,
-        git_metadata_settings=evaluator.git_metadata_settings,
-        repo_info=evaluator.repo_info,
+        git_metadata_settings=git_metadata_settings,
+        repo_info=repo_info,
         dataset=dataset,
     )
 
@@ -413,6 +444,7 @@ def run_evaluator_supports_progress() -> bool:
 async def run_evaluator_task(
     evaluator, position: int, no_send_logs: bool, progress_cb, supports_progress: bool
 ):
+    apply_dataset_override(evaluator)
     experiment = None
     if not no_send_logs:
         experiment = _init_experiment_for_eval(evaluator)
@@ -38,9 +38,12 @@ type EvalFunction = (
 
 type LoginFunction = (options?: Record<string, unknown>) => Promise<unknown>;
 
+type InitDatasetFunction = (options: Record<string, unknown>) => unknown;
+
 type BraintrustModule = {
   Eval?: EvalFunction;
   login?: LoginFunction;
+  initDataset?: InitDatasetFunction;
   default?: BraintrustModule;
 };
 
@@ -182,6 +185,41 @@ function parseSerializedFilters(serialized: string | undefined): EvalFilter[] {
   }
 }
 
+// Apply --no-git, or the repo info bt collected when the evaluator doesn't set its own.
+function withGitMetadata(
+  evaluator: Record<string, unknown>,
+): Record<string, unknown> {
+  if (envFlag("BT_EVAL_NO_GIT")) {
+    return { ...evaluator, gitMetadataSettings: { collect: "none" } };
+  }
+  const serialized = process.env.BT_EVAL_REPO_INFO;
+  if (serialized && evaluator.repoInfo === undefined) {
+    return { ...evaluator, repoInfo: JSON.parse(serialized) };
+  }
+  return evaluator;
+}
+
+// Apply --dataset (and --dataset-version), replacing the evaluator's own data.
+function withDatasetOverride(
+  braintrust: BraintrustModule,
+  projectName: string,
+  evaluator: Record<string, unknown>,
+): Record<string, unknown> {
+  const dataset = process.env.BT_EVAL_DATASET;
+  if (!dataset) {
+    return evaluator;
+  }
+  if (typeof braintrust.initDataset !== "function") {
+    throw new Error("Unable to load initDataset() from braintrust package.");
+  }
+  const data = braintrust.initDataset({
+    project: projectName,
+    dataset,
+    version: process.env.BT_EVAL_DATASET_VERSION || undefined,
+  });
+  return { ...evaluator, data };
+}
+
 function readRunnerConfig(): RunnerConfig {
   return {
     jsonl: envFlag("BT_EVAL_JSONL"),
@@ -1163,7 +1201,11 @@ async function createEvalRunner(config: RunnerConfig) {
     globalThis._lazy_load = false;
     const evaluatorName = getEvaluatorName(evaluator, projectName);
     const opts = makeEvalOptions(evaluatorName, options);
-    const result = await Eval(projectName, evaluator, opts);
+    const result = await Eval(
+      projectName,
+      withDatasetOverride(braintrust, projectName, withGitMetadata(evaluator)),
+      opts,
+    );
     const failingResults = result.results.filter(
       (r: { error?: unknown }) => r.error !== undefined,
     );
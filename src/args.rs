@@ -26,6 +26,14 @@ pub struct BaseArgs {
     /// Path to a .env file to load before running commands.
     #[arg(long, env = "BRAINTRUST_ENV_FILE")]
     pub env_file: Option<PathBuf>,
+
+    /// YAML file of prompt answers for non-interactive use (or via BT_ANSWERS)
+    #[arg(long, env = "BT_ANSWERS", value_name = "FILE")]
+    pub answers: Option<PathBuf>,
+
+    /// Pick the option at this index for the next selection prompt (repeatable)
+    #[arg(long, value_name = "N")]
+    pub select_index: Vec<usize>,
 }
 
 #[derive(Debug, Clone, Args)]
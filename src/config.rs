@@ -0,0 +1,75 @@
+use std::env;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The bt config directory (`$XDG_CONFIG_HOME/bt`, `~/.config/bt`, or `%APPDATA%\bt`).
+pub fn config_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    {
+        env::var_os("APPDATA").map(|path| PathBuf::from(path).join("bt"))
+    }
+    #[cfg(not(windows))]
+    {
+        if let Some(xdg) = env::var_os("XDG_CONFIG_HOME") {
+            return Some(PathBuf::from(xdg).join("bt"));
+        }
+        env::var_os("HOME").map(|path| PathBuf::from(path).join(".config").join("bt"))
+    }
+}
+
+/// The bt state directory (`$XDG_STATE_HOME/bt`, `~/.local/state/bt`, or `%LOCALAPPDATA%\bt`).
+pub fn state_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    {
+        env::var_os("LOCALAPPDATA").map(|path| PathBuf::from(path).join("bt"))
+    }
+    #[cfg(not(windows))]
+    {
+        if let Some(xdg) = env::var_os("XDG_STATE_HOME") {
+            return Some(PathBuf::from(xdg).join("bt"));
+        }
+        env::var_os("HOME").map(|path| PathBuf::from(path).join(".local").join("state").join("bt"))
+    }
+}
+
+/// Persistent settings stored in `<config dir>/config.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Config {
+    /// Active project, used when `--project`/`BRAINTRUST_DEFAULT_PROJECT` isn't set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+fn config_path() -> Result<PathBuf> {
+    config_dir()
+        .map(|dir| dir.join("config.json"))
+        .context("failed to resolve bt config directory")
+}
+
+impl Config {
+    pub fn load() -> Result<Self> {
+        let path = config_path()?;
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .with_context(|| format!("failed to parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self) -> Result<PathBuf> {
+        let path = config_path()?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        Ok(path)
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use urlencoding::encode;
+
+use crate::http::ApiClient;
+use crate::projects::api::ObjectList;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dataset {
+    pub id: String,
+    pub name: String,
+    pub project_id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default)]
+    pub metadata: Option<Value>,
+}
+
+pub async fn list_datasets(client: &ApiClient, project_id: &str) -> Result<Vec<Dataset>> {
+    let path = format!("/v1/dataset?project_id={}", encode(project_id));
+    let list: ObjectList<Dataset> = client.get(&path).await?;
+    Ok(list.objects)
+}
+
+pub async fn get_dataset_by_name(
+    client: &ApiClient,
+    project_id: &str,
+    name: &str,
+) -> Result<Option<Dataset>> {
+    let path = format!(
+        "/v1/dataset?project_id={}&dataset_name={}",
+        encode(project_id),
+        encode(name)
+    );
+    let list: ObjectList<Dataset> = client.get(&path).await?;
+    Ok(list.objects.into_iter().next())
+}
+
+pub async fn create_dataset(
+    client: &ApiClient,
+    project_id: &str,
+    name: &str,
+    description: Option<&str>,
+    metadata: Option<&Value>,
+) -> Result<Dataset> {
+    let mut body = serde_json::json!({ "project_id": project_id, "name": name });
+    if let Some(description) = description {
+        body["description"] = serde_json::json!(description);
+    }
+    if let Some(metadata) = metadata {
+        body["metadata"] = metadata.clone();
+    }
+    client.post("/v1/dataset", &body).await
+}
+
+pub async fn delete_dataset(client: &ApiClient, dataset_id: &str) -> Result<()> {
+    let path = format!("/v1/dataset/{}", encode(dataset_id));
+    client.delete(&path).await
+}
+
+pub async fn insert_rows(client: &ApiClient, dataset_id: &str, rows: &[Value]) -> Result<()> {
+    let path = format!("/v1/dataset/{}/insert", encode(dataset_id));
+    let _: Value = client
+        .post(&path, &serde_json::json!({ "events": rows }))
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct FetchResponse {
+    events: Vec<Value>,
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
+/// Fetch one page of dataset records, returning the records and the cursor for the next page.
+/// `version` pins the read to the dataset as of that transaction id.
+pub async fn fetch_rows(
+    client: &ApiClient,
+    dataset_id: &str,
+    limit: usize,
+    cursor: Option<&str>,
+    version: Option<&str>,
+) -> Result<(Vec<Value>, Option<String>)> {
+    let path = format!("/v1/dataset/{}/fetch", encode(dataset_id));
+    let mut body = serde_json::json!({ "limit": limit });
+    if let Some(cursor) = cursor {
+        body["cursor"] = serde_json::json!(cursor);
+    }
+    if let Some(version) = version {
+        body["version"] = serde_json::json!(version);
+    }
+    let page: FetchResponse = client.post(&path, &body).await?;
+    Ok((page.events, page.cursor))
+}
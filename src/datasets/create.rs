@@ -0,0 +1,49 @@
+use anyhow::{bail, Result};
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::{self, print_command_status, with_spinner, CommandStatus};
+
+use super::api;
+
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    name: Option<&str>,
+    description: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let name = match name {
+        Some(n) if !n.is_empty() => n.to_string(),
+        _ => {
+            if !ui::is_interactive() {
+                bail!("dataset name required. Use: bt datasets create <name>");
+            }
+            ui::input("Dataset name")?
+        }
+    };
+
+    let exists = with_spinner(
+        "Checking dataset...",
+        api::get_dataset_by_name(client, &project.id, &name),
+    )
+    .await?;
+    if exists.is_some() {
+        bail!("dataset '{name}' already exists in '{}'", project.name);
+    }
+
+    let dataset = with_spinner(
+        "Creating dataset...",
+        api::create_dataset(client, &project.id, &name, description, None),
+    )
+    .await?;
+    if json {
+        println!("{}", serde_json::to_string(&dataset)?);
+    } else {
+        print_command_status(
+            CommandStatus::Success,
+            &format!("Created dataset '{name}' in '{}'", project.name),
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anyhow::{bail, Result};
+
+use crate::http::ApiClient;
+use crate::ui::{self, print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Dataset};
+
+pub async fn run(client: &ApiClient, dataset: &Dataset, yes: bool) -> Result<()> {
+    if !yes {
+        if !ui::is_interactive() {
+            bail!(
+                "refusing to delete without confirmation; pass --yes to delete non-interactively"
+            );
+        }
+        if !ui::confirm(&format!("Delete dataset '{}'?", dataset.name), false)? {
+            return Ok(());
+        }
+    }
+
+    with_spinner(
+        &format!("Deleting '{}'...", dataset.name),
+        api::delete_dataset(client, &dataset.id),
+    )
+    .await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Deleted '{}'", dataset.name),
+    );
+    Ok(())
+}
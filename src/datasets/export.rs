@@ -0,0 +1,181 @@
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+use crate::http::ApiClient;
+use crate::sql::{write_parquet, AtomicFile};
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Dataset};
+use super::openai::{to_chat, ChatOptions};
+use super::version::{self, DatasetVersion};
+
+const PAGE_SIZE: usize = 1000;
+const FIELDS: &[&str] = &["id", "input", "expected", "metadata", "tags", "created"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// One JSON object per record
+    Jsonl,
+    /// Comma-separated values with JSON-encoded cells
+    Csv,
+    /// Apache Parquet with one string column per field
+    Parquet,
+    /// OpenAI chat fine-tuning JSONL (`{"messages": [...]}` per record)
+    OpenaiJsonl,
+}
+
+impl ExportFormat {
+    /// The format implied by an output path's extension, defaulting to JSONL.
+    pub fn for_path(path: Option<&Path>) -> Self {
+        match path.and_then(|p| p.extension()).and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => ExportFormat::Csv,
+            Some(ext) if ext.eq_ignore_ascii_case("parquet") => ExportFormat::Parquet,
+            _ => ExportFormat::Jsonl,
+        }
+    }
+}
+
+pub async fn run(
+    client: &ApiClient,
+    dataset: &Dataset,
+    output: Option<&Path>,
+    format: ExportFormat,
+    version: Option<&DatasetVersion>,
+    chat: &ChatOptions<'_>,
+) -> Result<()> {
+    let rows = with_spinner("Fetching records...", async {
+        let version = match version {
+            Some(version) => Some(version::resolve(client, &dataset.id, version).await?),
+            None => None,
+        };
+        fetch_all(client, &dataset.id, version.as_deref()).await
+    })
+    .await?;
+    let mut contents = Vec::new();
+    let mut written = rows.len();
+    match format {
+        ExportFormat::Jsonl => write_jsonl(&mut contents, &rows)?,
+        ExportFormat::Csv => write_csv(&mut contents, &rows)?,
+        ExportFormat::Parquet => {
+            let headers: Vec<String> = FIELDS.iter().map(|f| f.to_string()).collect();
+            write_parquet(&mut contents, &headers, &Value::Null, &rows)?;
+        }
+        ExportFormat::OpenaiJsonl => {
+            let examples: Vec<Value> = rows.iter().filter_map(|r| to_chat(r, chat)).collect();
+            if examples.len() < rows.len() {
+                eprintln!(
+                    "Skipped {} records without '{}' or '{}'",
+                    rows.len() - examples.len(),
+                    chat.user_field,
+                    chat.assistant_field
+                );
+            }
+            for example in &examples {
+                serde_json::to_writer(&mut contents, example)?;
+                contents.push(b'\n');
+            }
+            written = examples.len();
+        }
+    }
+
+    write_output(output, &contents, written)
+}
+
+pub(super) fn write_jsonl(out: &mut Vec<u8>, rows: &[Map<String, Value>]) -> Result<()> {
+    for row in rows {
+        serde_json::to_writer(&mut *out, row)?;
+        out.push(b'\n');
+    }
+    Ok(())
+}
+
+/// Write `contents` to `output` atomically, or to stdout when no path is given.
+pub(super) fn write_output(output: Option<&Path>, contents: &[u8], records: usize) -> Result<()> {
+    match output {
+        Some(path) => {
+            let mut file = AtomicFile::create(path)?;
+            file.write_all(contents)?;
+            file.commit()?;
+            print_command_status(
+                CommandStatus::Success,
+                &format!("Wrote {records} records to {}", path.display()),
+            );
+        }
+        None => std::io::stdout()
+            .write_all(contents)
+            .context("failed to write to stdout")?,
+    }
+    Ok(())
+}
+
+/// Every record of the dataset (as of `version`, when given), following cursors until the
+/// last page, trimmed to `FIELDS`.
+pub(super) async fn fetch_all(
+    client: &ApiClient,
+    dataset_id: &str,
+    version: Option<&str>,
+) -> Result<Vec<Map<String, Value>>> {
+    let mut rows = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let (events, next) =
+            api::fetch_rows(client, dataset_id, PAGE_SIZE, cursor.as_deref(), version).await?;
+        if events.is_empty() {
+            break;
+        }
+        rows.extend(events.iter().map(trim));
+        match next {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    Ok(rows)
+}
+
+/// The user-facing fields of a fetched record.
+pub(super) fn trim(event: &Value) -> Map<String, Value> {
+    FIELDS
+        .iter()
+        .filter_map(|f| event.get(*f).map(|v| (f.to_string(), v.clone())))
+        .collect()
+}
+
+/// CSV with one column per field; strings are written as-is, other values as JSON.
+fn write_csv(out: &mut Vec<u8>, rows: &[Map<String, Value>]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(out);
+    writer.write_record(FIELDS)?;
+    for row in rows {
+        writer.write_record(FIELDS.iter().map(|f| match row.get(*f) {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(s)) => s.clone(),
+            Some(other) => other.to_string(),
+        }))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn csv_encodes_structured_cells_as_json() {
+        let row = json!({ "id": "r1", "input": { "q": "2+2" }, "expected": "4, probably" });
+        let mut out = Vec::new();
+        write_csv(&mut out, &[row.as_object().unwrap().clone()]).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "id,input,expected,metadata,tags,created\nr1,\"{\"\"q\"\":\"\"2+2\"\"}\",\"4, probably\",,,\n"
+        );
+        assert_eq!(
+            ExportFormat::for_path(Some(Path::new("data.PARQUET"))),
+            ExportFormat::Parquet
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use std::env;
+
+use anyhow::{bail, Context, Result};
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use urlencoding::encode;
+
+use crate::http::ApiClient;
+use crate::ingest::RowIds;
+use crate::projects::api::Project;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api;
+use super::insert::insert_all;
+
+const DATASETS_SERVER_URL: &str = "https://datasets-server.huggingface.co";
+
+#[derive(Debug, Deserialize)]
+struct ParquetListing {
+    parquet_files: Vec<ParquetFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ParquetFile {
+    config: String,
+    split: String,
+    url: String,
+}
+
+pub struct ImportOptions<'a> {
+    pub repo_id: &'a str,
+    pub config: Option<&'a str>,
+    pub split: &'a str,
+    pub mapping: &'a [(String, String)],
+    pub dataset: Option<&'a str>,
+    pub limit: Option<usize>,
+}
+
+/// Download a Hugging Face dataset split as Parquet and upload its rows, creating the
+/// Braintrust dataset if needed.
+pub async fn run(client: &ApiClient, project: &Project, opts: ImportOptions<'_>) -> Result<()> {
+    let http = Client::builder()
+        .user_agent("bt-datasets-import")
+        .build()
+        .context("failed to initialize HTTP client")?;
+
+    let rows = with_spinner(
+        &format!("Downloading {} ({})...", opts.repo_id, opts.split),
+        download_split(&http, &opts),
+    )
+    .await?;
+    if rows.is_empty() {
+        bail!("split '{}' of {} has no rows", opts.split, opts.repo_id);
+    }
+    let mut records: Vec<Value> = rows
+        .into_iter()
+        .map(|row| Value::Object(map_row(row, opts.mapping)))
+        .collect();
+    // Retried batches must overwrite, not duplicate, records the server already stored.
+    RowIds::random().assign_all(&mut records);
+
+    let name = opts
+        .dataset
+        .unwrap_or_else(|| opts.repo_id.rsplit('/').next().unwrap_or(opts.repo_id));
+    let dataset = match api::get_dataset_by_name(client, &project.id, name).await? {
+        Some(dataset) => dataset,
+        None => {
+            let description = format!("Imported from Hugging Face {}", opts.repo_id);
+            api::create_dataset(client, &project.id, name, Some(&description), None).await?
+        }
+    };
+    insert_all(client, &dataset, &records).await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Imported {} records into '{}'", records.len(), dataset.name),
+    );
+    Ok(())
+}
+
+async fn download_split(
+    http: &Client,
+    opts: &ImportOptions<'_>,
+) -> Result<Vec<Map<String, Value>>> {
+    let url = format!(
+        "{DATASETS_SERVER_URL}/parquet?dataset={}",
+        encode(opts.repo_id)
+    );
+    let listing: ParquetListing = get(http, &url)
+        .await?
+        .json()
+        .await
+        .context("failed to parse Hugging Face parquet listing")?;
+
+    let config = match opts.config {
+        Some(config) => config.to_string(),
+        None => match listing.parquet_files.first() {
+            Some(file) => file.config.clone(),
+            None => bail!("no parquet files found for {}", opts.repo_id),
+        },
+    };
+    let files: Vec<&ParquetFile> = listing
+        .parquet_files
+        .iter()
+        .filter(|f| f.config == config && f.split == opts.split)
+        .collect();
+    if files.is_empty() {
+        bail!(
+            "split '{}' not found in config '{config}' of {}",
+            opts.split,
+            opts.repo_id
+        );
+    }
+
+    let mut rows = Vec::new();
+    for file in files {
+        let bytes = get(http, &file.url)
+            .await?
+            .bytes()
+            .await
+            .context("failed to download parquet file")?;
+        let reader = SerializedFileReader::new(bytes).context("failed to read parquet file")?;
+        for row in reader.get_row_iter(None)? {
+            if opts.limit.is_some_and(|limit| rows.len() >= limit) {
+                return Ok(rows);
+            }
+            if let Value::Object(row) = row?.to_json_value() {
+                rows.push(row);
+            }
+        }
+    }
+    Ok(rows)
+}
+
+/// GET `url`, authenticating with `HF_TOKEN` when set (needed for gated or private datasets).
+async fn get(http: &Client, url: &str) -> Result<reqwest::Response> {
+    let mut request = http.get(url);
+    if let Ok(token) = env::var("HF_TOKEN") {
+        let token = token.trim();
+        if !token.is_empty() {
+            request = request.bearer_auth(token);
+        }
+    }
+    let response = request.send().await.context("request failed")?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        bail!("Hugging Face request failed ({status}): {body}");
+    }
+    Ok(response)
+}
+
+/// Copy each mapped column into its dataset field; without a mapping the whole row
+/// becomes the record's input.
+fn map_row(mut row: Map<String, Value>, mapping: &[(String, String)]) -> Map<String, Value> {
+    if mapping.is_empty() {
+        return Map::from_iter([("input".to_string(), Value::Object(row))]);
+    }
+    mapping
+        .iter()
+        .filter_map(|(column, field)| Some((field.clone(), row.remove(column)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn map_row_moves_columns_into_fields() {
+        let row = json!({ "question": "2+2?", "answer": "4", "id": 7 });
+        let row = row.as_object().unwrap().clone();
+        let mapping = [
+            ("question".to_string(), "input".to_string()),
+            ("answer".to_string(), "expected".to_string()),
+        ];
+        assert_eq!(
+            Value::Object(map_row(row.clone(), &mapping)),
+            json!({ "input": "2+2?", "expected": "4" })
+        );
+        assert_eq!(
+            Value::Object(map_row(row, &[])),
+            json!({ "input": { "question": "2+2?", "answer": "4", "id": 7 } })
+        );
+    }
+}
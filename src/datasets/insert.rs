@@ -0,0 +1,175 @@
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+use crate::http::ApiClient;
+use crate::ingest::{self, read_input, RowIds};
+use crate::ui::{print_command_status, CommandStatus};
+
+use super::api::{self, Dataset};
+use super::stream::{self, UploadOptions};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum InputFormat {
+    /// One JSON object per line
+    Jsonl,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+impl InputFormat {
+    /// The format implied by an input path's extension, defaulting to JSONL.
+    pub fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => InputFormat::Csv,
+            _ => InputFormat::Jsonl,
+        }
+    }
+}
+
+/// Parse one `a=b` pair of a `--map` argument (`field=column` for inserts).
+pub fn parse_mapping(raw: &str) -> Result<(String, String), String> {
+    let (field, column) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=NAME, got '{raw}'"))?;
+    let (field, column) = (field.trim(), column.trim());
+    if field.is_empty() || column.is_empty() {
+        return Err(format!("expected NAME=NAME, got '{raw}'"));
+    }
+    Ok((field.to_string(), column.to_string()))
+}
+
+pub async fn run(
+    client: &ApiClient,
+    dataset: &Dataset,
+    file: &Path,
+    format: InputFormat,
+    mapping: &[(String, String)],
+    upload: &UploadOptions,
+) -> Result<()> {
+    if format == InputFormat::Jsonl {
+        return stream::run(client, dataset, file, mapping, upload).await;
+    }
+
+    let contents = read_input(file, "records")?;
+    let records = csv_records(&contents, mapping, &RowIds::random())?;
+    if records.is_empty() {
+        bail!("no records to insert");
+    }
+
+    insert_all(client, dataset, &records).await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Inserted {} records into '{}'", records.len(), dataset.name),
+    );
+    Ok(())
+}
+
+/// Insert `records` in batches behind a progress bar.
+pub(super) async fn insert_all(
+    client: &ApiClient,
+    dataset: &Dataset,
+    records: &[Value],
+) -> Result<()> {
+    ingest::insert_batches(records, "records", |batch| {
+        api::insert_rows(client, &dataset.id, batch)
+    })
+    .await
+}
+
+/// The mapped records of a CSV file, with ids from `ids` for rows that lack one.
+fn csv_records(contents: &str, mapping: &[(String, String)], ids: &RowIds) -> Result<Vec<Value>> {
+    let mut records: Vec<Value> = parse_csv(contents)?
+        .into_iter()
+        .map(|record| Value::Object(apply_mapping(record, mapping)))
+        .collect();
+    ids.assign_all(&mut records);
+    Ok(records)
+}
+
+/// One object per CSV row keyed by header. Cells holding JSON objects or arrays are parsed.
+fn parse_csv(contents: &str) -> Result<Vec<Map<String, Value>>> {
+    let mut reader = csv::Reader::from_reader(contents.as_bytes());
+    let headers = reader
+        .headers()
+        .context("failed to read CSV header")?
+        .clone();
+    reader
+        .records()
+        .enumerate()
+        .map(|(idx, row)| {
+            let row = row.with_context(|| format!("invalid CSV on row {}", idx + 1))?;
+            Ok(headers
+                .iter()
+                .zip(row.iter())
+                .map(|(header, cell)| (header.to_string(), parse_cell(cell)))
+                .collect())
+        })
+        .collect()
+}
+
+fn parse_cell(cell: &str) -> Value {
+    let trimmed = cell.trim_start();
+    if trimmed.starts_with(['{', '[']) {
+        if let Ok(value) = serde_json::from_str(cell) {
+            return value;
+        }
+    }
+    Value::String(cell.to_string())
+}
+
+/// Build a dataset record by copying each mapped column into its field;
+/// without a mapping the record is used as-is.
+pub(super) fn apply_mapping(
+    record: Map<String, Value>,
+    mapping: &[(String, String)],
+) -> Map<String, Value> {
+    if mapping.is_empty() {
+        return record;
+    }
+    mapping
+        .iter()
+        .filter_map(|(field, column)| Some((field.clone(), record.get(column)?.clone())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn csv_rows_map_onto_dataset_fields() {
+        let csv = "question,answer,tags\nWhat is 2+2?,4,\"[\"\"math\"\"]\"\n";
+        let mapping: Vec<_> = ["input=question", " expected=answer", "tags=tags"]
+            .into_iter()
+            .map(|pair| parse_mapping(pair).unwrap())
+            .collect();
+        let records: Vec<Value> = parse_csv(csv)
+            .unwrap()
+            .into_iter()
+            .map(|r| Value::Object(apply_mapping(r, &mapping)))
+            .collect();
+        assert_eq!(
+            records,
+            vec![json!({ "input": "What is 2+2?", "expected": "4", "tags": ["math"] })]
+        );
+        assert!(parse_mapping("input").is_err());
+    }
+
+    #[test]
+    fn separate_uploads_get_distinct_ids() {
+        let ids =
+            |records: &[Value]| -> Vec<Value> { records.iter().map(|r| r["id"].clone()).collect() };
+        let first = csv_records("q\na\nb\n", &[], &RowIds::random()).unwrap();
+        let second = csv_records("q\nc\nd\n", &[], &RowIds::random()).unwrap();
+        assert_eq!(ids(&first).len(), 2);
+        assert_ne!(first[0]["id"], first[1]["id"]);
+        assert!(ids(&first).iter().all(|id| !ids(&second).contains(id)));
+
+        let kept = csv_records("id,q\nmine,a\n", &[], &RowIds::random()).unwrap();
+        assert_eq!(kept[0]["id"], "mine");
+    }
+}
@@ -0,0 +1,60 @@
+use anyhow::Result;
+use dialoguer::console;
+use unicode_width::UnicodeWidthStr;
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::with_spinner;
+
+use super::api;
+
+pub async fn run(client: &ApiClient, project: &Project, json: bool) -> Result<()> {
+    let datasets = with_spinner(
+        "Loading datasets...",
+        api::list_datasets(client, &project.id),
+    )
+    .await?;
+
+    if json {
+        println!("{}", serde_json::to_string(&datasets)?);
+        return Ok(());
+    }
+
+    println!(
+        "{} datasets found in {}\n",
+        console::style(datasets.len()),
+        console::style(&project.name).bold()
+    );
+    let name_width = datasets
+        .iter()
+        .map(|d| d.name.width())
+        .max()
+        .unwrap_or(0)
+        .max(15);
+    println!(
+        "{}  {}  {}",
+        console::style(format!("{:name_width$}", "Dataset"))
+            .dim()
+            .bold(),
+        console::style(format!("{:20}", "Created")).dim().bold(),
+        console::style("Description").dim().bold()
+    );
+    for dataset in &datasets {
+        let padding = name_width - dataset.name.width();
+        let created = dataset
+            .created
+            .as_deref()
+            .map(|c| c.get(..19).unwrap_or(c).replacen('T', " ", 1))
+            .unwrap_or_else(|| "-".to_string());
+        let description = dataset
+            .description
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .unwrap_or("-");
+        println!(
+            "{}{:padding$}  {created:20}  {description}",
+            dataset.name, ""
+        );
+    }
+    Ok(())
+}
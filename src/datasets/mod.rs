@@ -0,0 +1,384 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Result};
+use clap::{Args, Subcommand};
+
+use crate::args::BaseArgs;
+use crate::http::ApiClient;
+use crate::login::login;
+use crate::projects::api::Project;
+use crate::projects::resolve_project;
+use crate::ui::{self, with_spinner};
+
+pub(crate) mod api;
+mod create;
+mod delete;
+mod export;
+mod import_hf;
+mod insert;
+mod list;
+mod openai;
+mod sample;
+mod split;
+mod stream;
+mod update;
+mod validate;
+pub(crate) mod version;
+
+pub(crate) use sample::sample;
+
+#[derive(Debug, Clone, Args)]
+pub struct DatasetsArgs {
+    #[command(subcommand)]
+    command: Option<DatasetsCommands>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum DatasetsCommands {
+    /// List datasets in the active project
+    List,
+    /// Create a new dataset
+    Create(CreateArgs),
+    /// Delete a dataset
+    Delete(DeleteArgs),
+    /// Insert records from a JSONL or CSV file
+    Insert(InsertArgs),
+    /// Upsert records from a JSONL file, matching existing ones by a key field
+    Update(UpdateArgs),
+    /// Export every record to JSONL, CSV, or Parquet
+    Export(ExportArgs),
+    /// Write a random sample of records as JSONL
+    Sample(SampleArgs),
+    /// Split a dataset's records into two new datasets
+    Split(SplitArgs),
+    /// Check every record against a JSON Schema
+    Validate(ValidateArgs),
+    /// Import records from an external source
+    #[command(subcommand)]
+    Import(ImportCommands),
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum ImportCommands {
+    /// Import a Hugging Face dataset split
+    Hf(HfArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+struct HfArgs {
+    /// Hugging Face dataset id, e.g. rajpurkar/squad
+    repo_id: String,
+
+    /// Split to import
+    #[arg(long, default_value = "train")]
+    split: String,
+
+    /// Dataset config (defaults to the first one)
+    #[arg(long)]
+    config: Option<String>,
+
+    /// Map source columns to record fields, e.g. question=input,answer=expected
+    /// (without it, each row becomes the record's input)
+    #[arg(
+        long,
+        value_name = "COLUMN=FIELD",
+        value_delimiter = ',',
+        value_parser = insert::parse_mapping
+    )]
+    map: Vec<(String, String)>,
+
+    /// Braintrust dataset to import into (defaults to the repo name; created if missing)
+    #[arg(long)]
+    dataset: Option<String>,
+
+    /// Import at most this many rows
+    #[arg(long, value_name = "N")]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct CreateArgs {
+    /// Name of the dataset to create
+    name: Option<String>,
+
+    /// Optional description
+    #[arg(long)]
+    description: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct DeleteArgs {
+    /// Dataset name (pick interactively when omitted)
+    name: Option<String>,
+
+    /// Skip the confirmation prompt (required when not running interactively)
+    #[arg(long, short = 'y', visible_alias = "force")]
+    yes: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct InsertArgs {
+    /// Dataset name
+    name: String,
+
+    /// JSONL or CSV file of records (use `-` for stdin)
+    #[arg(long, short = 'f', value_name = "PATH")]
+    file: PathBuf,
+
+    /// Input format (defaults to the file's extension, or jsonl)
+    #[arg(long, value_enum)]
+    format: Option<insert::InputFormat>,
+
+    /// Batches to upload at once (JSONL only)
+    #[arg(long, default_value_t = 4, value_name = "N", value_parser = clap::value_parser!(u64).range(1..=32))]
+    concurrency: u64,
+
+    /// Continue an interrupted JSONL upload from its checkpoint file
+    #[arg(long)]
+    resume: bool,
+
+    /// Map record fields to input columns, e.g. input=question,expected=answer
+    #[arg(
+        long,
+        value_name = "FIELD=COLUMN",
+        value_delimiter = ',',
+        value_parser = insert::parse_mapping
+    )]
+    map: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct UpdateArgs {
+    /// Dataset name
+    name: String,
+
+    /// JSONL file of records to upsert (use `-` for stdin)
+    #[arg(long, short = 'f', value_name = "PATH")]
+    file: PathBuf,
+
+    /// Field that identifies a record, e.g. metadata.external_id
+    #[arg(long, default_value = "id", value_name = "FIELD")]
+    key: String,
+}
+
+#[derive(Debug, Clone, Args)]
+struct ExportArgs {
+    /// Dataset name (pick interactively when omitted)
+    name: Option<String>,
+
+    /// File to write (defaults to stdout)
+    #[arg(short = 'o', long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Output format (defaults to the output file's extension, or jsonl)
+    #[arg(long, value_enum)]
+    format: Option<export::ExportFormat>,
+
+    /// Export the dataset as of this transaction id or RFC 3339 timestamp
+    #[arg(long, value_name = "XACT_ID|TIMESTAMP", value_parser = version::parse_version)]
+    version: Option<version::DatasetVersion>,
+
+    /// System prompt to prepend to each example (openai-jsonl only)
+    #[arg(long)]
+    system_prompt: Option<String>,
+
+    /// Field holding the user turn, e.g. input.question (openai-jsonl only)
+    #[arg(long, default_value = "input", value_name = "PATH")]
+    user_field: String,
+
+    /// Field holding the assistant turn (openai-jsonl only)
+    #[arg(long, default_value = "expected", value_name = "PATH")]
+    assistant_field: String,
+}
+
+#[derive(Debug, Clone, Args)]
+struct SampleArgs {
+    /// Dataset name (pick interactively when omitted)
+    name: Option<String>,
+
+    /// Number of records to sample
+    #[arg(short = 'n', long = "count", default_value_t = 10, value_name = "N")]
+    count: usize,
+
+    /// Seed for a reproducible sample
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// File to write (defaults to stdout)
+    #[arg(short = 'o', long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct SplitArgs {
+    /// Dataset to split
+    name: String,
+
+    /// Share of records that go into the first dataset
+    #[arg(long, default_value = "0.8", value_parser = split::parse_ratio)]
+    ratio: f64,
+
+    /// Names of the two datasets to create, e.g. train-set,test-set
+    #[arg(long, value_name = "FIRST,SECOND", value_parser = split::parse_names)]
+    into: (String, String),
+
+    /// Seed for a reproducible split
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct ValidateArgs {
+    /// Dataset name (pick interactively when omitted)
+    name: Option<String>,
+
+    /// JSON Schema file that each record must satisfy
+    #[arg(long, value_name = "PATH")]
+    schema: PathBuf,
+}
+
+pub async fn run(base: BaseArgs, args: DatasetsArgs) -> Result<()> {
+    let ctx = login(&base).await?;
+    let client = ApiClient::new(&ctx)?;
+    let project = resolve_project(&client, base.project.as_deref()).await?;
+
+    match args.command {
+        None | Some(DatasetsCommands::List) => list::run(&client, &project, base.json).await,
+        Some(DatasetsCommands::Create(a)) => {
+            create::run(
+                &client,
+                &project,
+                a.name.as_deref(),
+                a.description.as_deref(),
+                base.json,
+            )
+            .await
+        }
+        Some(DatasetsCommands::Delete(a)) => {
+            let dataset = resolve_dataset(&client, &project, a.name.as_deref()).await?;
+            delete::run(&client, &dataset, a.yes).await
+        }
+        Some(DatasetsCommands::Insert(a)) => {
+            let dataset = find_dataset(&client, &project, &a.name).await?;
+            let format = a
+                .format
+                .unwrap_or_else(|| insert::InputFormat::for_path(&a.file));
+            let upload = stream::UploadOptions {
+                concurrency: a.concurrency as usize,
+                resume: a.resume,
+            };
+            insert::run(&client, &dataset, &a.file, format, &a.map, &upload).await
+        }
+        Some(DatasetsCommands::Update(a)) => {
+            let dataset = find_dataset(&client, &project, &a.name).await?;
+            update::run(&client, &dataset, &a.file, &a.key).await
+        }
+        Some(DatasetsCommands::Export(a)) => {
+            let dataset = resolve_dataset(&client, &project, a.name.as_deref()).await?;
+            let format = a
+                .format
+                .unwrap_or_else(|| export::ExportFormat::for_path(a.output.as_deref()));
+            let chat = openai::ChatOptions {
+                system_prompt: a.system_prompt.as_deref(),
+                user_field: &a.user_field,
+                assistant_field: &a.assistant_field,
+            };
+            export::run(
+                &client,
+                &dataset,
+                a.output.as_deref(),
+                format,
+                a.version.as_ref(),
+                &chat,
+            )
+            .await
+        }
+        Some(DatasetsCommands::Sample(a)) => {
+            let dataset = resolve_dataset(&client, &project, a.name.as_deref()).await?;
+            sample::run(&client, &dataset, a.count, a.seed, a.output.as_deref()).await
+        }
+        Some(DatasetsCommands::Split(a)) => {
+            let dataset = find_dataset(&client, &project, &a.name).await?;
+            let (first, second) = &a.into;
+            split::run(
+                &client,
+                &project,
+                &dataset,
+                a.ratio,
+                (first, second),
+                a.seed,
+            )
+            .await
+        }
+        Some(DatasetsCommands::Import(ImportCommands::Hf(a))) => {
+            let opts = import_hf::ImportOptions {
+                repo_id: &a.repo_id,
+                config: a.config.as_deref(),
+                split: &a.split,
+                mapping: &a.map,
+                dataset: a.dataset.as_deref(),
+                limit: a.limit,
+            };
+            import_hf::run(&client, &project, opts).await
+        }
+        Some(DatasetsCommands::Validate(a)) => {
+            let dataset = resolve_dataset(&client, &project, a.name.as_deref()).await?;
+            validate::run(&client, &dataset, &a.schema, base.json).await
+        }
+    }
+}
+
+async fn find_dataset(client: &ApiClient, project: &Project, name: &str) -> Result<api::Dataset> {
+    with_spinner(
+        "Loading dataset...",
+        api::get_dataset_by_name(client, &project.id, name),
+    )
+    .await?
+    .ok_or_else(|| anyhow!("dataset '{name}' not found in '{}'", project.name))
+}
+
+/// The transaction id `version` pins `dataset` in the `--project` project to. Only
+/// timestamps need the API, so transaction ids skip logging in.
+pub(crate) async fn resolve_version(
+    base: &BaseArgs,
+    dataset: &str,
+    version: &version::DatasetVersion,
+) -> Result<String> {
+    if let version::DatasetVersion::XactId(id) = version {
+        return Ok(id.clone());
+    }
+    let ctx = login(base).await?;
+    let client = ApiClient::new(&ctx)?;
+    let project = resolve_project(&client, base.project.as_deref()).await?;
+    let dataset = find_dataset(&client, &project, dataset).await?;
+    with_spinner(
+        "Resolving dataset version...",
+        version::resolve(&client, &dataset.id, version),
+    )
+    .await
+}
+
+/// The named dataset, or one picked interactively from the project's datasets.
+async fn resolve_dataset(
+    client: &ApiClient,
+    project: &Project,
+    name: Option<&str>,
+) -> Result<api::Dataset> {
+    if let Some(name) = name {
+        return find_dataset(client, project, name).await;
+    }
+    if !ui::is_interactive() {
+        bail!("dataset name required");
+    }
+    let mut datasets = with_spinner(
+        "Loading datasets...",
+        api::list_datasets(client, &project.id),
+    )
+    .await?;
+    if datasets.is_empty() {
+        bail!("no datasets found in '{}'", project.name);
+    }
+    let names: Vec<&str> = datasets.iter().map(|d| d.name.as_str()).collect();
+    let selection = ui::fuzzy_select("Select dataset", &names)?;
+    Ok(datasets.swap_remove(selection))
+}
@@ -0,0 +1,80 @@
+use serde_json::{json, Map, Value};
+
+/// How dataset records become chat fine-tuning examples.
+pub struct ChatOptions<'a> {
+    pub system_prompt: Option<&'a str>,
+    /// Dotted path of the user turn, e.g. `input` or `input.question`.
+    pub user_field: &'a str,
+    /// Dotted path of the assistant turn, e.g. `expected`.
+    pub assistant_field: &'a str,
+}
+
+/// A `{"messages": [...]}` example for OpenAI chat fine-tuning, or `None` when the record
+/// is missing either turn. A user field that already holds a message list is used as-is.
+pub fn to_chat(record: &Map<String, Value>, opts: &ChatOptions<'_>) -> Option<Value> {
+    let user = lookup(record, opts.user_field).filter(|v| !v.is_null())?;
+    let assistant = lookup(record, opts.assistant_field).filter(|v| !v.is_null())?;
+
+    let mut messages = Vec::new();
+    if let Some(system) = opts.system_prompt {
+        messages.push(json!({ "role": "system", "content": system }));
+    }
+    match user.as_array() {
+        Some(turns) if turns.iter().all(|t| t.get("role").is_some()) => {
+            if opts.system_prompt.is_some()
+                && turns.first().and_then(|t| t.get("role")) == Some(&json!("system"))
+            {
+                messages.clear();
+            }
+            messages.extend(turns.iter().cloned());
+        }
+        _ => messages.push(json!({ "role": "user", "content": content(user) })),
+    }
+    messages.push(json!({ "role": "assistant", "content": content(assistant) }));
+    Some(json!({ "messages": messages }))
+}
+
+/// Follow a dotted path such as `input.question` into a record.
+pub(super) fn lookup<'a>(record: &'a Map<String, Value>, path: &str) -> Option<&'a Value> {
+    let mut parts = path.split('.');
+    let first = record.get(parts.next()?)?;
+    parts.try_fold(first, |value, key| value.get(key))
+}
+
+/// Strings pass through; anything else is sent as compact JSON.
+fn content(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_chat_builds_messages_from_fields() {
+        let record = json!({ "input": { "question": "2+2?" }, "expected": 4 });
+        let record = record.as_object().unwrap();
+        let opts = ChatOptions {
+            system_prompt: Some("Be brief."),
+            user_field: "input.question",
+            assistant_field: "expected",
+        };
+        assert_eq!(
+            to_chat(record, &opts),
+            Some(json!({ "messages": [
+                { "role": "system", "content": "Be brief." },
+                { "role": "user", "content": "2+2?" },
+                { "role": "assistant", "content": "4" },
+            ] }))
+        );
+
+        let missing = ChatOptions {
+            assistant_field: "output",
+            ..opts
+        };
+        assert_eq!(to_chat(record, &missing), None);
+    }
+}
@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde_json::{Map, Value};
+
+use crate::http::ApiClient;
+use crate::ui::with_spinner;
+
+use super::api::Dataset;
+use super::export::{fetch_all, write_jsonl, write_output};
+
+/// Write `n` records picked at random (reproducibly when `seed` is set) as JSONL.
+pub async fn run(
+    client: &ApiClient,
+    dataset: &Dataset,
+    n: usize,
+    seed: Option<u64>,
+    output: Option<&Path>,
+) -> Result<()> {
+    let rows = with_spinner("Fetching records...", fetch_all(client, &dataset.id, None)).await?;
+    let mut rng = match seed {
+        Some(seed) => fastrand::Rng::with_seed(seed),
+        None => fastrand::Rng::new(),
+    };
+    let rows = sample(rows, n, &mut rng);
+
+    let mut contents = Vec::new();
+    write_jsonl(&mut contents, &rows)?;
+    write_output(output, &contents, rows.len())
+}
+
+/// Up to `n` rows chosen uniformly without replacement, in the order they were drawn.
+pub(crate) fn sample(
+    mut rows: Vec<Map<String, Value>>,
+    n: usize,
+    rng: &mut fastrand::Rng,
+) -> Vec<Map<String, Value>> {
+    let n = n.min(rows.len());
+    // Partial Fisher-Yates: the first `n` slots end up holding the sample.
+    for i in 0..n {
+        let j = rng.usize(i..rows.len());
+        rows.swap(i, j);
+    }
+    rows.truncate(n);
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn sample_is_reproducible_with_a_seed() {
+        let rows: Vec<Map<String, Value>> = (0..20)
+            .map(|i| json!({ "id": i }).as_object().unwrap().clone())
+            .collect();
+        let first = sample(rows.clone(), 5, &mut fastrand::Rng::with_seed(42));
+        let second = sample(rows.clone(), 5, &mut fastrand::Rng::with_seed(42));
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 5);
+
+        let all = sample(rows, 50, &mut fastrand::Rng::with_seed(1));
+        assert_eq!(all.len(), 20);
+    }
+}
@@ -0,0 +1,129 @@
+use anyhow::{bail, Result};
+use serde_json::{Map, Value};
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Dataset};
+use super::export::fetch_all;
+use super::insert::insert_all;
+use super::sample::sample;
+
+type Rows = Vec<Map<String, Value>>;
+
+/// Parse a `--ratio` between 0 and 1 (exclusive).
+pub fn parse_ratio(raw: &str) -> Result<f64, String> {
+    let ratio: f64 = raw.parse().map_err(|_| format!("invalid ratio '{raw}'"))?;
+    if !(ratio > 0.0 && ratio < 1.0) {
+        return Err("ratio must be between 0 and 1".to_string());
+    }
+    Ok(ratio)
+}
+
+/// Parse `--into first,second`.
+pub fn parse_names(raw: &str) -> Result<(String, String), String> {
+    match raw.split(',').map(str::trim).collect::<Vec<_>>().as_slice() {
+        [first, second] if !first.is_empty() && !second.is_empty() && first != second => {
+            Ok((first.to_string(), second.to_string()))
+        }
+        _ => Err(format!(
+            "expected two different dataset names, e.g. train,test; got '{raw}'"
+        )),
+    }
+}
+
+/// Shuffle `source` into two new datasets, the first holding `ratio` of the records.
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    source: &Dataset,
+    ratio: f64,
+    (first, second): (&str, &str),
+    seed: Option<u64>,
+) -> Result<()> {
+    for name in [first, second] {
+        if api::get_dataset_by_name(client, &project.id, name)
+            .await?
+            .is_some()
+        {
+            bail!("dataset '{name}' already exists in '{}'", project.name);
+        }
+    }
+
+    let rows = with_spinner("Fetching records...", fetch_all(client, &source.id, None)).await?;
+    if rows.is_empty() {
+        bail!("dataset '{}' has no records", source.name);
+    }
+    let mut rng = match seed {
+        Some(seed) => fastrand::Rng::with_seed(seed),
+        None => fastrand::Rng::new(),
+    };
+    let (head, tail) = split(rows, ratio, &mut rng);
+
+    for (name, rows) in [(first, head), (second, tail)] {
+        let dataset = with_spinner(
+            &format!("Creating '{name}'..."),
+            api::create_dataset(
+                client,
+                &project.id,
+                name,
+                source.description.as_deref(),
+                source.metadata.as_ref(),
+            ),
+        )
+        .await?;
+        let records: Vec<Value> = rows.into_iter().map(Value::Object).collect();
+        if !records.is_empty() {
+            insert_all(client, &dataset, &records).await?;
+        }
+        print_command_status(
+            CommandStatus::Success,
+            &format!("Created '{name}' with {} records", records.len()),
+        );
+    }
+    Ok(())
+}
+
+/// Shuffle the rows and cut them at `ratio`, keeping at least one row on each side
+/// when there are two or more.
+fn split(rows: Rows, ratio: f64, rng: &mut fastrand::Rng) -> (Rows, Rows) {
+    let len = rows.len();
+    let mut shuffled = sample(rows, len, rng);
+    let cut = if len < 2 {
+        len
+    } else {
+        ((len as f64 * ratio).round() as usize).clamp(1, len - 1)
+    };
+    let tail = shuffled.split_off(cut);
+    (shuffled, tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn split_partitions_every_row() {
+        let rows: Vec<Map<String, Value>> = (0..10)
+            .map(|i| json!({ "id": i }).as_object().unwrap().clone())
+            .collect();
+        let (train, test) = split(rows, 0.8, &mut fastrand::Rng::with_seed(7));
+        assert_eq!((train.len(), test.len()), (8, 2));
+        let mut ids: Vec<i64> = train
+            .iter()
+            .chain(&test)
+            .map(|r| r["id"].as_i64().unwrap())
+            .collect();
+        ids.sort();
+        assert_eq!(ids, (0..10).collect::<Vec<_>>());
+
+        assert_eq!(
+            parse_names("train, test"),
+            Ok(("train".into(), "test".into()))
+        );
+        assert!(parse_names("train").is_err());
+        assert!(parse_ratio("1").is_err());
+    }
+}
@@ -0,0 +1,328 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{bail, Context, Result};
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+use crate::http::ApiClient;
+use crate::ingest::{
+    checkpoint_path, load_checkpoint, save_checkpoint, with_retries, RowIds, BATCH_SIZE,
+};
+use crate::ui::{print_command_status, progress_bar, CommandStatus};
+
+use super::api::{self, Dataset};
+use super::insert::apply_mapping;
+
+pub struct UploadOptions {
+    /// Batches in flight at once.
+    pub concurrency: usize,
+    /// Continue from the checkpoint left by an interrupted upload.
+    pub resume: bool,
+}
+
+/// Stream a JSONL file (or stdin for `-`) into a dataset in concurrent batches, recording
+/// progress in a checkpoint file next to the input so an interrupted upload can resume.
+pub async fn run(
+    client: &ApiClient,
+    dataset: &Dataset,
+    file: &Path,
+    mapping: &[(String, String)],
+    opts: &UploadOptions,
+) -> Result<()> {
+    let from_stdin = file == Path::new("-");
+    if from_stdin && opts.resume {
+        bail!("--resume needs a file; stdin can't be resumed");
+    }
+    let checkpoint = (!from_stdin).then(|| checkpoint_path(file));
+    let saved = match &checkpoint {
+        Some(path) if opts.resume => load_checkpoint::<Checkpoint>(path)?,
+        _ => None,
+    };
+    if let (Some(saved), Some(path)) = (&saved, &checkpoint) {
+        if saved.dataset_id != dataset.id {
+            bail!(
+                "{} belongs to a different dataset; delete it to start over",
+                path.display()
+            );
+        }
+    }
+    // A resumed upload reuses the interrupted one's ids, so re-sent lines overwrite
+    // their earlier copies rather than duplicating them.
+    let mut progress = saved.unwrap_or_else(|| Checkpoint {
+        dataset_id: dataset.id.clone(),
+        ids: RowIds::random(),
+        lines: 0,
+    });
+    let resume_from = progress.lines;
+
+    // File reads block, so open, skip and parse on the blocking pool and hand batches
+    // over a channel sized to the number of uploads in flight.
+    let source = file.to_path_buf();
+    let mapping = mapping.to_vec();
+    let ids = progress.ids.clone();
+    let (batches, total_bytes, skipped_bytes) = tokio::task::spawn_blocking(move || {
+        let (reader, total_bytes): (Box<dyn BufRead + Send>, u64) = if from_stdin {
+            (Box::new(BufReader::new(io::stdin())), 0)
+        } else {
+            let handle = File::open(&source)
+                .with_context(|| format!("failed to open {}", source.display()))?;
+            let size = handle.metadata().map(|m| m.len()).unwrap_or(0);
+            (Box::new(BufReader::new(handle)), size)
+        };
+        let mut batches = BatchReader::new(reader, ids, mapping);
+        let skipped_bytes = batches.skip_lines(resume_from)?;
+        anyhow::Ok((batches, total_bytes, skipped_bytes))
+    })
+    .await??;
+    let concurrency = opts.concurrency.max(1);
+    let (tx, mut rx) = mpsc::channel(concurrency);
+    tokio::task::spawn_blocking(move || {
+        for batch in batches {
+            if tx.blocking_send(batch).is_err() {
+                break;
+            }
+        }
+    });
+    let batches = stream::poll_fn(move |cx| rx.poll_recv(cx));
+
+    let bar = progress_bar(total_bytes, "Uploading");
+    bar.set_position(skipped_bytes);
+    let started = Instant::now();
+    let mut rows = 0u64;
+    let mut frontier = Frontier::new(resume_from);
+
+    let mut uploads = batches
+        .map(|batch| async move {
+            let batch = batch?;
+            with_retries(|| api::insert_rows(client, &dataset.id, &batch.records))
+                .await
+                .with_context(|| format!("failed to upload lines ending at {}", batch.end_line))?;
+            anyhow::Ok(batch)
+        })
+        .buffer_unordered(concurrency);
+
+    while let Some(result) = uploads.next().await {
+        let batch = match result {
+            Ok(batch) => batch,
+            Err(err) => {
+                bar.abandon();
+                if checkpoint.is_some() && frontier.committed > 0 {
+                    eprintln!(
+                        "Uploaded through line {}; rerun with --resume to continue",
+                        frontier.committed
+                    );
+                }
+                return Err(err);
+            }
+        };
+        rows += batch.records.len() as u64;
+        bar.inc(batch.bytes);
+        let rate = rows as f64 / started.elapsed().as_secs_f64().max(0.001);
+        bar.set_message(format!("Uploading {rows} rows ({rate:.0} rows/s)"));
+        if frontier.complete(batch.index, batch.end_line) {
+            if let Some(path) = &checkpoint {
+                progress.lines = frontier.committed;
+                save_checkpoint(path, &progress)?;
+            }
+        }
+    }
+    bar.finish_and_clear();
+    if let Some(path) = &checkpoint {
+        let _ = std::fs::remove_file(path);
+    }
+
+    let rate = rows as f64 / started.elapsed().as_secs_f64().max(0.001);
+    print_command_status(
+        CommandStatus::Success,
+        &format!(
+            "Inserted {rows} records into '{}' ({rate:.0} rows/s)",
+            dataset.name
+        ),
+    );
+    Ok(())
+}
+
+/// Progress of an upload: every line up to `lines` has been inserted into `dataset_id`
+/// with ids from `ids`.
+#[derive(Debug, Serialize, Deserialize)]
+struct Checkpoint {
+    dataset_id: String,
+    ids: RowIds,
+    lines: u64,
+}
+
+/// Tracks batches finishing out of order; `committed` is the last line of the longest
+/// run of finished batches from the start.
+struct Frontier {
+    next: usize,
+    finished: BTreeMap<usize, u64>,
+    committed: u64,
+}
+
+impl Frontier {
+    fn new(committed: u64) -> Self {
+        Self {
+            next: 0,
+            finished: BTreeMap::new(),
+            committed,
+        }
+    }
+
+    /// Record a finished batch, returning true when `committed` moved forward.
+    fn complete(&mut self, index: usize, end_line: u64) -> bool {
+        self.finished.insert(index, end_line);
+        let before = self.committed;
+        while let Some(end_line) = self.finished.remove(&self.next) {
+            self.committed = end_line;
+            self.next += 1;
+        }
+        self.committed != before
+    }
+}
+
+struct Batch {
+    index: usize,
+    /// Line number (1-based) of the batch's last line.
+    end_line: u64,
+    bytes: u64,
+    records: Vec<Value>,
+}
+
+/// Reads JSONL lazily, one batch of up to `BATCH_SIZE` records at a time. Records
+/// without an `id` get one from `ids` for their line number.
+struct BatchReader {
+    reader: Box<dyn BufRead + Send>,
+    ids: RowIds,
+    mapping: Vec<(String, String)>,
+    line: u64,
+    next_index: usize,
+    done: bool,
+}
+
+impl BatchReader {
+    fn new(reader: Box<dyn BufRead + Send>, ids: RowIds, mapping: Vec<(String, String)>) -> Self {
+        Self {
+            reader,
+            ids,
+            mapping,
+            line: 0,
+            next_index: 0,
+            done: false,
+        }
+    }
+
+    /// Skip the first `lines` lines, returning the bytes skipped.
+    fn skip_lines(&mut self, lines: u64) -> Result<u64> {
+        let mut bytes = 0;
+        let mut buf = String::new();
+        while self.line < lines {
+            buf.clear();
+            let read = self.reader.read_line(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            bytes += read as u64;
+            self.line += 1;
+        }
+        Ok(bytes)
+    }
+
+    fn next_batch(&mut self) -> Result<Option<Batch>> {
+        let mut records = Vec::new();
+        let mut bytes = 0;
+        let mut buf = String::new();
+        while records.len() < BATCH_SIZE {
+            buf.clear();
+            let read = self
+                .reader
+                .read_line(&mut buf)
+                .with_context(|| format!("failed to read line {}", self.line + 1))?;
+            if read == 0 {
+                self.done = true;
+                break;
+            }
+            bytes += read as u64;
+            self.line += 1;
+            if buf.trim().is_empty() {
+                continue;
+            }
+            let record = match serde_json::from_str(&buf)
+                .with_context(|| format!("invalid JSON on line {}", self.line))?
+            {
+                Value::Object(record) => record,
+                _ => bail!("line {} is not a JSON object", self.line),
+            };
+            let mut record = apply_mapping(record, &self.mapping);
+            self.ids.assign(self.line, &mut record);
+            records.push(Value::Object(record));
+        }
+        if records.is_empty() {
+            return Ok(None);
+        }
+        let index = self.next_index;
+        self.next_index += 1;
+        Ok(Some(Batch {
+            index,
+            end_line: self.line,
+            bytes,
+            records,
+        }))
+    }
+}
+
+impl Iterator for BatchReader {
+    type Item = Result<Batch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_batch() {
+            Ok(batch) => batch.map(Ok),
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frontier_only_advances_over_contiguous_batches() {
+        let mut frontier = Frontier::new(0);
+        assert!(!frontier.complete(1, 1000));
+        assert_eq!(frontier.committed, 0);
+        assert!(frontier.complete(0, 500));
+        assert_eq!(frontier.committed, 1000);
+        assert!(frontier.complete(2, 1200));
+        assert_eq!(frontier.committed, 1200);
+    }
+
+    #[test]
+    fn batch_reader_skips_and_counts_lines() {
+        let input = "{\"a\":1}\n\n{\"a\":2}\n{\"a\":3}\n";
+        let ids = RowIds::random();
+        let mut reader = BatchReader::new(Box::new(input.as_bytes()), ids.clone(), Vec::new());
+        assert_eq!(reader.skip_lines(2).unwrap(), 9);
+        let batches: Vec<Batch> = reader.collect::<Result<_>>().unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].records.len(), 2);
+        assert_eq!(batches[0].end_line, 4);
+        let mut expected = serde_json::Map::new();
+        ids.assign(4, &mut expected);
+        assert_eq!(batches[0].records[1]["id"], expected["id"]);
+
+        let mut bad = BatchReader::new(Box::new("[1]\n".as_bytes()), ids, Vec::new());
+        assert!(bad.next().unwrap().is_err());
+    }
+}
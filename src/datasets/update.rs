@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde_json::{Map, Value};
+
+use crate::http::ApiClient;
+use crate::ingest::read_input;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api::Dataset;
+use super::export::fetch_all;
+use super::insert::insert_all;
+use super::openai::lookup;
+
+/// Apply a JSONL file of patches to a dataset. Each patch is matched to an existing
+/// record by `key` and merged into it; patches that match nothing become new records.
+pub async fn run(client: &ApiClient, dataset: &Dataset, file: &Path, key: &str) -> Result<()> {
+    let contents = read_input(file, "patches")?;
+    let patches = parse_patches(&contents, key)?;
+    if patches.is_empty() {
+        bail!("no records to update");
+    }
+
+    let rows = with_spinner("Loading records...", fetch_all(client, &dataset.id, None)).await?;
+    let existing = index_by_key(&rows, key)?;
+    let (records, updated) = upserts(patches, key, &existing);
+
+    insert_all(client, dataset, &records).await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!(
+            "Upserted {} records into '{}' ({updated} matched by {key})",
+            records.len(),
+            dataset.name
+        ),
+    );
+    Ok(())
+}
+
+/// Parse the patch file, requiring every line to be an object carrying `key`.
+fn parse_patches(contents: &str, key: &str) -> Result<Vec<Map<String, Value>>> {
+    let mut patches = Vec::new();
+    for (idx, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let patch = match serde_json::from_str(line)
+            .with_context(|| format!("invalid JSON on line {}", idx + 1))?
+        {
+            Value::Object(patch) => patch,
+            _ => bail!("line {} is not a JSON object", idx + 1),
+        };
+        if lookup(&patch, key).is_none_or(Value::is_null) {
+            bail!("line {} has no '{key}'", idx + 1);
+        }
+        patches.push(patch);
+    }
+    Ok(patches)
+}
+
+/// Record ids keyed by the JSON encoding of each record's `key` value.
+fn index_by_key(rows: &[Map<String, Value>], key: &str) -> Result<HashMap<String, String>> {
+    let mut index = HashMap::new();
+    for row in rows {
+        let (Some(value), Some(Value::String(id))) = (lookup(row, key), row.get("id")) else {
+            continue;
+        };
+        if index.insert(value.to_string(), id.clone()).is_some() {
+            bail!("'{key}' is not unique in the dataset: {value} appears more than once");
+        }
+    }
+    Ok(index)
+}
+
+/// Turn patches into insert events, returning them with the number that target existing
+/// records. Matched patches take the record's id and merge rather than replace; the rest
+/// are inserted as new records.
+fn upserts(
+    patches: Vec<Map<String, Value>>,
+    key: &str,
+    existing: &HashMap<String, String>,
+) -> (Vec<Value>, usize) {
+    let mut updated = 0;
+    let records = patches
+        .into_iter()
+        .map(|mut patch| {
+            let id = lookup(&patch, key).and_then(|value| existing.get(&value.to_string()));
+            if let Some(id) = id {
+                updated += 1;
+                patch.insert("id".to_string(), Value::String(id.clone()));
+                patch.insert("_is_merge".to_string(), Value::Bool(true));
+            }
+            Value::Object(patch)
+        })
+        .collect();
+    (records, updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn upserts_merge_matches_and_insert_the_rest() {
+        let rows: Vec<Map<String, Value>> = vec![
+            serde_json::from_value(json!({ "id": "r1", "metadata": { "ext": "a" } })).unwrap(),
+            serde_json::from_value(json!({ "id": "r2", "metadata": { "ext": "b" } })).unwrap(),
+        ];
+        let existing = index_by_key(&rows, "metadata.ext").unwrap();
+        let patches = parse_patches(
+            "{\"metadata\":{\"ext\":\"b\"},\"expected\":\"fixed\"}\n\n{\"metadata\":{\"ext\":\"c\"}}\n",
+            "metadata.ext",
+        )
+        .unwrap();
+        let (records, updated) = upserts(patches, "metadata.ext", &existing);
+        assert_eq!(updated, 1);
+        assert_eq!(
+            records,
+            vec![
+                json!({ "id": "r2", "_is_merge": true, "metadata": { "ext": "b" }, "expected": "fixed" }),
+                json!({ "metadata": { "ext": "c" } }),
+            ]
+        );
+        assert!(parse_patches("{\"expected\":1}", "id").is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context, Result};
+use dialoguer::console;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::http::ApiClient;
+use crate::ui::{print_command_status, CommandStatus};
+
+use super::api::{self, Dataset};
+use super::export::trim;
+
+const PAGE_SIZE: usize = 1000;
+
+/// A schema violation in one record.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Failure {
+    id: String,
+    path: String,
+    message: String,
+}
+
+/// Check every record against the JSON Schema at `schema`, failing if any record is invalid.
+pub async fn run(client: &ApiClient, dataset: &Dataset, schema: &Path, json: bool) -> Result<()> {
+    let contents = std::fs::read_to_string(schema)
+        .with_context(|| format!("failed to read {}", schema.display()))?;
+    let schema: Value = serde_json::from_str(&contents)
+        .with_context(|| format!("failed to parse {}", schema.display()))?;
+    let validator =
+        jsonschema::validator_for(&schema).map_err(|err| anyhow!("invalid schema: {err}"))?;
+
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let (events, next) =
+            api::fetch_rows(client, &dataset.id, PAGE_SIZE, cursor.as_deref(), None).await?;
+        for event in &events {
+            checked += 1;
+            let failed = check(&validator, event);
+            if !json {
+                for failure in &failed {
+                    println!(
+                        "{} {}  {}  {}",
+                        console::style("✗").red(),
+                        failure.id,
+                        console::style(&failure.path).dim(),
+                        failure.message
+                    );
+                }
+            }
+            failures.extend(failed);
+        }
+        match next {
+            Some(next) if !events.is_empty() => cursor = Some(next),
+            _ => break,
+        }
+    }
+
+    if json {
+        println!("{}", serde_json::to_string(&failures)?);
+    }
+    if !failures.is_empty() {
+        let mut ids: Vec<&str> = failures.iter().map(|f| f.id.as_str()).collect();
+        ids.dedup();
+        bail!(
+            "{} of {checked} records in '{}' failed validation",
+            ids.len(),
+            dataset.name
+        );
+    }
+    if !json {
+        print_command_status(
+            CommandStatus::Success,
+            &format!("All {checked} records in '{}' are valid", dataset.name),
+        );
+    }
+    Ok(())
+}
+
+/// Validate the user-facing fields of one record.
+fn check(validator: &jsonschema::Validator, event: &Value) -> Vec<Failure> {
+    let record = Value::Object(trim(event));
+    let id = record
+        .get("id")
+        .and_then(Value::as_str)
+        .unwrap_or("<no id>")
+        .to_string();
+    validator
+        .iter_errors(&record)
+        .map(|err| {
+            let path = err.instance_path.to_string();
+            Failure {
+                id: id.clone(),
+                path: if path.is_empty() {
+                    "/".to_string()
+                } else {
+                    path
+                },
+                message: err.to_string(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn check_reports_record_id_and_path() {
+        let schema = json!({
+            "type": "object",
+            "required": ["input", "expected"],
+            "properties": { "input": { "type": "object", "required": ["question"] } },
+        });
+        let validator = jsonschema::validator_for(&schema).unwrap();
+
+        let valid =
+            json!({ "id": "r1", "input": { "question": "?" }, "expected": "4", "_xact_id": "1" });
+        assert!(check(&validator, &valid).is_empty());
+
+        let invalid = json!({ "id": "r2", "input": {}, "expected": "4" });
+        let failures = check(&validator, &invalid);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].id, "r2");
+        assert_eq!(failures[0].path, "/input");
+    }
+}
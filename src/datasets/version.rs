@@ -0,0 +1,69 @@
+use anyhow::{bail, Result};
+use serde_json::Value;
+
+use crate::filter::parse_timestamp;
+use crate::http::ApiClient;
+use crate::sql::{query_rows, quote_literal};
+
+/// A `--version`: a transaction id, or a point in time to resolve to one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DatasetVersion {
+    XactId(String),
+    /// RFC 3339, normalized to UTC.
+    At(String),
+}
+
+/// Parse a `--version` given as a transaction id or an RFC 3339 timestamp.
+pub fn parse_version(raw: &str) -> Result<DatasetVersion, String> {
+    let raw = raw.trim();
+    if !raw.is_empty() && raw.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(DatasetVersion::XactId(raw.to_string()));
+    }
+    let time = parse_timestamp(raw)
+        .ok_or_else(|| format!("expected a transaction id or RFC 3339 timestamp, got '{raw}'"))?;
+    Ok(DatasetVersion::At(
+        humantime::format_rfc3339(time).to_string(),
+    ))
+}
+
+/// The transaction id to read `dataset_id` at. A timestamp resolves to the last write
+/// made at or before it, so the snapshot includes everything up to that moment.
+pub async fn resolve(
+    client: &ApiClient,
+    dataset_id: &str,
+    version: &DatasetVersion,
+) -> Result<String> {
+    let at = match version {
+        DatasetVersion::XactId(id) => return Ok(id.clone()),
+        DatasetVersion::At(at) => at,
+    };
+    let query = format!(
+        "SELECT _xact_id FROM dataset({}) WHERE created <= {} ORDER BY _xact_id DESC LIMIT 1",
+        quote_literal(dataset_id),
+        quote_literal(at)
+    );
+    let rows = query_rows(client, &query).await?;
+    match rows.first().and_then(|row| row.get("_xact_id")) {
+        Some(Value::String(id)) => Ok(id.clone()),
+        Some(Value::Number(id)) => Ok(id.to_string()),
+        _ => bail!("the dataset has no records at or before {at}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_parse_as_xact_ids_or_timestamps() {
+        assert_eq!(
+            parse_version("1000194739547289060").unwrap(),
+            DatasetVersion::XactId("1000194739547289060".to_string())
+        );
+        assert_eq!(
+            parse_version("2024-01-01T00:00:00").unwrap(),
+            DatasetVersion::At("2024-01-01T00:00:00Z".to_string())
+        );
+        assert!(parse_version("yesterday").is_err());
+    }
+}
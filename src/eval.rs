@@ -30,6 +30,7 @@ use ratatui::widgets::{Cell, Row, Table};
 use ratatui::Terminal;
 
 use crate::args::BaseArgs;
+use crate::git;
 
 const MAX_NAME_LENGTH: usize = 40;
 const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
@@ -126,6 +127,24 @@ pub struct EvalArgs {
     /// Re-run evals when input files change.
     #[arg(long, short = 'w')]
     pub watch: bool,
+
+    /// Don't attach git commit, branch, and author metadata to experiments.
+    #[arg(long)]
+    pub no_git: bool,
+
+    /// Run every evaluator against this dataset from its project instead of its own data.
+    #[arg(long, value_name = "NAME")]
+    pub dataset: Option<String>,
+
+    /// Pin --dataset to a transaction id or RFC 3339 timestamp. Timestamps are resolved
+    /// against the dataset in --project.
+    #[arg(
+        long,
+        requires = "dataset",
+        value_name = "XACT_ID|TIMESTAMP",
+        value_parser = crate::datasets::version::parse_version
+    )]
+    pub dataset_version: Option<crate::datasets::version::DatasetVersion>,
 }
 
 #[derive(Debug, Clone)]
@@ -135,15 +154,28 @@ struct EvalRunOptions {
     num_workers: Option<usize>,
     list: bool,
     filter: Vec<String>,
+    no_git: bool,
+    dataset: Option<String>,
+    /// Transaction id of --dataset-version, resolved before the runner starts.
+    dataset_version: Option<String>,
 }
 
 pub async fn run(base: BaseArgs, args: EvalArgs) -> Result<()> {
+    let dataset_version = match (&args.dataset, &args.dataset_version) {
+        (Some(dataset), Some(version)) => {
+            Some(crate::datasets::resolve_version(&base, dataset, version).await?)
+        }
+        _ => None,
+    };
     let options = EvalRunOptions {
         jsonl: args.jsonl,
         terminate_on_failure: args.terminate_on_failure,
         num_workers: args.num_workers,
         list: args.list,
         filter: args.filter,
+        no_git: args.no_git,
+        dataset: args.dataset,
+        dataset_version,
     };
 
     if args.watch {
@@ -307,6 +339,19 @@ async fn run_eval_files_once(
             serde_json::to_string(&parsed).context("failed to serialize eval filters")?;
         cmd.env("BT_EVAL_FILTER_PARSED", serialized);
     }
+    if options.no_git {
+        cmd.env("BT_EVAL_NO_GIT", "1");
+    } else if let Some(repo_info) = git::collect(Path::new(".")) {
+        let serialized =
+            serde_json::to_string(&repo_info).context("failed to serialize git metadata")?;
+        cmd.env("BT_EVAL_REPO_INFO", serialized);
+    }
+    if let Some(dataset) = &options.dataset {
+        cmd.env("BT_EVAL_DATASET", dataset);
+    }
+    if let Some(version) = &options.dataset_version {
+        cmd.env("BT_EVAL_DATASET_VERSION", version);
+    }
     cmd.env(
         "BT_EVAL_SSE_SOCK",
         socket_path.to_string_lossy().to_string(),
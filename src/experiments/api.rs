@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use urlencoding::encode;
+
+use crate::git::GitMetadata;
+use crate::http::{ApiClient, HttpError};
+use crate::projects::api::ObjectList;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Experiment {
+    pub id: String,
+    pub name: String,
+    pub project_id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default)]
+    pub dataset_id: Option<String>,
+    #[serde(default)]
+    pub repo_info: Option<Value>,
+    #[serde(default)]
+    pub metadata: Option<Value>,
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+}
+
+/// Experiments in a project, newest first.
+pub async fn list_experiments(
+    client: &ApiClient,
+    project_id: &str,
+    limit: Option<usize>,
+) -> Result<Vec<Experiment>> {
+    let mut path = format!("/v1/experiment?project_id={}", encode(project_id));
+    if let Some(limit) = limit {
+        path.push_str(&format!("&limit={limit}"));
+    }
+    let list: ObjectList<Experiment> = client.get(&path).await?;
+    Ok(list.objects)
+}
+
+/// Up to `limit` of a project's experiments, newest first, continuing after the
+/// experiment with id `starting_after`.
+pub async fn list_experiments_page(
+    client: &ApiClient,
+    project_id: &str,
+    limit: usize,
+    starting_after: Option<&str>,
+) -> Result<Vec<Experiment>> {
+    let mut path = format!(
+        "/v1/experiment?project_id={}&limit={limit}",
+        encode(project_id)
+    );
+    if let Some(id) = starting_after {
+        path.push_str(&format!("&starting_after={}", encode(id)));
+    }
+    let list: ObjectList<Experiment> = client.get(&path).await?;
+    Ok(list.objects)
+}
+
+pub async fn get_experiment_by_name(
+    client: &ApiClient,
+    project_id: &str,
+    name: &str,
+) -> Result<Option<Experiment>> {
+    let path = format!(
+        "/v1/experiment?project_id={}&experiment_name={}",
+        encode(project_id),
+        encode(name)
+    );
+    let list: ObjectList<Experiment> = client.get(&path).await?;
+    Ok(list.objects.into_iter().next())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreSummary {
+    pub name: String,
+    pub score: f64,
+    #[serde(default)]
+    pub diff: Option<f64>,
+    #[serde(default)]
+    pub improvements: Option<u64>,
+    #[serde(default)]
+    pub regressions: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricSummary {
+    pub name: String,
+    pub metric: f64,
+    #[serde(default)]
+    pub unit: String,
+    #[serde(default)]
+    pub diff: Option<f64>,
+    #[serde(default)]
+    pub improvements: Option<u64>,
+    #[serde(default)]
+    pub regressions: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Summary {
+    pub project_name: String,
+    pub experiment_name: String,
+    #[serde(default)]
+    pub project_url: Option<String>,
+    #[serde(default)]
+    pub experiment_url: Option<String>,
+    #[serde(default)]
+    pub comparison_experiment_name: Option<String>,
+    #[serde(default)]
+    pub scores: BTreeMap<String, ScoreSummary>,
+    #[serde(default)]
+    pub metrics: BTreeMap<String, MetricSummary>,
+}
+
+/// Score and metric averages for an experiment, compared against `comparison_id` when given
+/// (the API otherwise picks the project's default baseline).
+pub async fn summarize(
+    client: &ApiClient,
+    experiment_id: &str,
+    comparison_id: Option<&str>,
+) -> Result<Summary> {
+    let mut path = format!(
+        "/v1/experiment/{}/summarize?summarize_scores=true",
+        encode(experiment_id)
+    );
+    if let Some(comparison_id) = comparison_id {
+        path.push_str(&format!(
+            "&comparison_experiment_id={}",
+            encode(comparison_id)
+        ));
+    }
+    client.get(&path).await
+}
+
+/// Summaries requested at once by [`summarize_all`].
+const SUMMARY_CONCURRENCY: usize = 8;
+
+/// The summary of each experiment, in order, fetched a few at a time. An experiment the
+/// API can't summarize gets `None`; any other failure is returned.
+pub async fn summarize_all(
+    client: &ApiClient,
+    experiments: &[Experiment],
+) -> Result<Vec<Option<Summary>>> {
+    stream::iter(experiments)
+        .map(|experiment| async move {
+            match summarize(client, &experiment.id, None).await {
+                Ok(summary) => Ok(Some(summary)),
+                Err(err)
+                    if err
+                        .downcast_ref::<HttpError>()
+                        .is_some_and(|http| http.status == StatusCode::NOT_FOUND) =>
+                {
+                    Ok(None)
+                }
+                Err(err) => Err(err),
+            }
+        })
+        .buffered(SUMMARY_CONCURRENCY)
+        .try_collect()
+        .await
+}
+
+#[derive(Debug, Deserialize)]
+struct DatasetRef {
+    name: String,
+}
+
+pub async fn get_dataset_name(client: &ApiClient, dataset_id: &str) -> Result<String> {
+    let path = format!("/v1/dataset/{}", encode(dataset_id));
+    let dataset: DatasetRef = client.get(&path).await?;
+    Ok(dataset.name)
+}
+
+pub async fn delete_experiment(client: &ApiClient, experiment_id: &str) -> Result<()> {
+    let path = format!("/v1/experiment/{}", encode(experiment_id));
+    client.delete(&path).await
+}
+
+#[derive(Debug, Deserialize)]
+struct FetchResponse {
+    events: Vec<Value>,
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
+/// Fetch one page of experiment events, returning the events and the cursor for the next page.
+pub async fn fetch_events(
+    client: &ApiClient,
+    experiment_id: &str,
+    limit: usize,
+    cursor: Option<&str>,
+) -> Result<(Vec<Value>, Option<String>)> {
+    let path = format!("/v1/experiment/{}/fetch", encode(experiment_id));
+    let mut body = serde_json::json!({ "limit": limit });
+    if let Some(cursor) = cursor {
+        body["cursor"] = serde_json::json!(cursor);
+    }
+    let page: FetchResponse = client.post(&path, &body).await?;
+    Ok((page.events, page.cursor))
+}
+
+/// The experiment with this name, created (with `repo_info` when given) if the project
+/// doesn't have one yet.
+pub async fn get_or_create_experiment(
+    client: &ApiClient,
+    project_id: &str,
+    name: &str,
+    repo_info: Option<&GitMetadata>,
+) -> Result<Experiment> {
+    let mut body = serde_json::json!({
+        "project_id": project_id,
+        "name": name,
+        "ensure_new": false,
+    });
+    if let Some(repo_info) = repo_info {
+        body["repo_info"] = serde_json::to_value(repo_info)?;
+    }
+    client.post("/v1/experiment", &body).await
+}
+
+pub async fn insert_events(
+    client: &ApiClient,
+    experiment_id: &str,
+    events: &[Value],
+) -> Result<()> {
+    let path = format!("/v1/experiment/{}/insert", encode(experiment_id));
+    let _: Value = client
+        .post(&path, &serde_json::json!({ "events": events }))
+        .await?;
+    Ok(())
+}
+
+/// Attach feedback (scores, comments, expected values) to logged events.
+pub async fn log_feedback(
+    client: &ApiClient,
+    experiment_id: &str,
+    feedback: &[Value],
+) -> Result<()> {
+    let path = format!("/v1/experiment/{}/feedback", encode(experiment_id));
+    let _: Value = client
+        .post(&path, &serde_json::json!({ "feedback": feedback }))
+        .await?;
+    Ok(())
+}
+
+/// Patch an experiment's description, metadata, and/or tags.
+pub async fn update_experiment(
+    client: &ApiClient,
+    experiment_id: &str,
+    patch: &Value,
+) -> Result<Experiment> {
+    let path = format!("/v1/experiment/{}", encode(experiment_id));
+    client.patch(&path, patch).await
+}
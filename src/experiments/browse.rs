@@ -0,0 +1,436 @@
+use std::io;
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::ExecutableCommand;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::Frame;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState};
+use ratatui::Terminal;
+use serde_json::{json, Map, Value};
+use urlencoding::encode;
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::{query_rows, quote_literal, sort_rows, truncate_cell};
+use crate::ui::{self, is_limited_terminal, with_spinner};
+
+use super::api::{self, Experiment, Summary};
+use super::list::format_created;
+
+const MAX_CELL_WIDTH: usize = 40;
+
+/// Columns ahead of the per-score columns in each table.
+const EXPERIMENT_COLUMNS: [&str; 2] = ["name", "created"];
+const CASE_COLUMNS: [&str; 3] = ["input", "output", "expected"];
+
+/// Rows and column names shown in one table, with the sort and selection applied.
+struct TableView {
+    title: String,
+    headers: Vec<String>,
+    /// Index of the first score column; scores display as percentages.
+    first_score: usize,
+    rows: Vec<Map<String, Value>>,
+    sort: Option<(usize, bool)>,
+    state: TableState,
+}
+
+impl TableView {
+    fn new(
+        title: String,
+        headers: Vec<String>,
+        first_score: usize,
+        rows: Vec<Map<String, Value>>,
+    ) -> Self {
+        let mut state = TableState::default();
+        if !rows.is_empty() {
+            state.select(Some(0));
+        }
+        Self {
+            title,
+            headers,
+            first_score,
+            rows,
+            sort: None,
+            state,
+        }
+    }
+
+    fn selected(&self) -> Option<&Map<String, Value>> {
+        self.state.selected().and_then(|idx| self.rows.get(idx))
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let current = self.state.selected().unwrap_or(0);
+        let next = current
+            .saturating_add_signed(delta)
+            .min(self.rows.len() - 1);
+        self.state.select(Some(next));
+    }
+
+    /// Step through columns ascending then descending, then back to unsorted.
+    fn cycle_sort(&mut self) -> String {
+        self.sort = match self.sort {
+            None => Some((0, false)),
+            Some((idx, false)) => Some((idx, true)),
+            Some((idx, true)) if idx + 1 < self.headers.len() => Some((idx + 1, false)),
+            Some((_, true)) => None,
+        };
+        match self.sort {
+            Some((idx, descending)) => {
+                sort_rows(&mut self.rows, &self.headers[idx], descending);
+                format!(
+                    "Sorted by {} {}",
+                    self.headers[idx],
+                    if descending { "desc" } else { "asc" }
+                )
+            }
+            None => {
+                self.rows.sort_by_key(|row| row["#"].as_u64());
+                "Unsorted".to_string()
+            }
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame<'_>, area: ratatui::layout::Rect) {
+        let cells: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                self.headers
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, h)| {
+                        let cell = display(row.get(h), idx >= self.first_score);
+                        truncate_cell(&cell, MAX_CELL_WIDTH)
+                    })
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<Constraint> = self
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(idx, header)| {
+                let width = cells
+                    .iter()
+                    .map(|row| row[idx].chars().count())
+                    .chain([header.chars().count() + 2])
+                    .max()
+                    .unwrap_or(0);
+                Constraint::Length(width as u16)
+            })
+            .collect();
+        let header = Row::new(self.headers.iter().enumerate().map(|(idx, h)| {
+            let arrow = match self.sort {
+                Some((sorted, false)) if sorted == idx => " ▲",
+                Some((sorted, true)) if sorted == idx => " ▼",
+                _ => "",
+            };
+            Cell::from(format!("{h}{arrow}"))
+        }))
+        .style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Cyan),
+        );
+        let table = Table::new(cells.into_iter().map(Row::new), widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .title(self.title.as_str())
+                    .borders(Borders::ALL),
+            )
+            .row_highlight_style(Style::default().bg(Color::DarkGray));
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+}
+
+struct Browser {
+    experiments: TableView,
+    /// Experiment and summary for each row, indexed by the row's `#` column.
+    sources: Vec<(Experiment, Option<Summary>)>,
+    cases: Option<TableView>,
+    status: String,
+    project_url: String,
+}
+
+pub async fn run(client: &ApiClient, project: &Project, app_url: &str) -> Result<()> {
+    if !ui::is_interactive() || is_limited_terminal() {
+        bail!("browse needs an interactive terminal; use `bt experiments list` instead");
+    }
+    let sources = with_spinner("Loading experiments...", async {
+        let experiments = api::list_experiments(client, &project.id, Some(100)).await?;
+        let summaries = api::summarize_all(client, &experiments).await?;
+        anyhow::Ok(experiments.into_iter().zip(summaries).collect::<Vec<_>>())
+    })
+    .await?;
+
+    let project_url = format!(
+        "{}/app/{}/p/{}",
+        app_url.trim_end_matches('/'),
+        encode(client.org_name()),
+        encode(&project.name)
+    );
+    let (headers, rows) = experiment_rows(&sources);
+    let browser = Browser {
+        experiments: TableView::new(
+            format!("Experiments in {}", project.name),
+            headers,
+            EXPERIMENT_COLUMNS.len(),
+            rows,
+        ),
+        sources,
+        cases: None,
+        status: "↑/↓ select, s sort, Enter cases, o open in browser, q quit".to_string(),
+        project_url,
+    };
+
+    let handle = tokio::runtime::Handle::current();
+    tokio::task::block_in_place(|| run_blocking(browser, client, &handle))
+}
+
+fn run_blocking(
+    mut browser: Browser,
+    client: &ApiClient,
+    handle: &tokio::runtime::Handle,
+) -> Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    stdout.execute(EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let res = (|| -> Result<()> {
+        loop {
+            terminal.draw(|f| draw(f, &mut browser))?;
+            if event::poll(Duration::from_millis(200))? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press
+                        && handle_key(&mut browser, key, client, handle)
+                    {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    })();
+
+    disable_raw_mode().ok();
+    terminal.backend_mut().execute(LeaveAlternateScreen).ok();
+    terminal.show_cursor().ok();
+    res
+}
+
+/// Handle a key press, returning true to quit.
+fn handle_key(
+    browser: &mut Browser,
+    key: KeyEvent,
+    client: &ApiClient,
+    handle: &tokio::runtime::Handle,
+) -> bool {
+    let in_cases = browser.cases.is_some();
+    let view = match browser.cases.as_mut() {
+        Some(cases) => cases,
+        None => &mut browser.experiments,
+    };
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => view.move_by(-1),
+        KeyCode::Down | KeyCode::Char('j') => view.move_by(1),
+        KeyCode::PageUp => view.move_by(-10),
+        KeyCode::PageDown => view.move_by(10),
+        KeyCode::Char('s') => browser.status = view.cycle_sort(),
+        KeyCode::Esc | KeyCode::Char('q') if in_cases => {
+            browser.cases = None;
+            browser.status =
+                "↑/↓ select, s sort, Enter cases, o open in browser, q quit".to_string();
+        }
+        KeyCode::Esc | KeyCode::Char('q') => return true,
+        KeyCode::Enter if !in_cases => {
+            if let Some((experiment, _)) = browser.selected_source() {
+                let experiment = experiment.clone();
+                browser.status = match handle.block_on(case_rows(client, &experiment)) {
+                    Ok((headers, rows)) => {
+                        let title = format!("Cases in {} ({})", experiment.name, rows.len());
+                        browser.cases =
+                            Some(TableView::new(title, headers, CASE_COLUMNS.len(), rows));
+                        "↑/↓ select, s sort, o open in browser, Esc back".to_string()
+                    }
+                    Err(err) => format!("Failed to load cases: {err}"),
+                };
+            }
+        }
+        KeyCode::Char('o') => {
+            if let Some(url) = browser.selected_url() {
+                browser.status = match open::that(&url) {
+                    Ok(()) => format!("Opened {url}"),
+                    Err(err) => format!("Failed to open {url}: {err}"),
+                };
+            }
+        }
+        _ => {}
+    }
+    false
+}
+
+impl Browser {
+    fn selected_source(&self) -> Option<&(Experiment, Option<Summary>)> {
+        let idx = self.experiments.selected()?.get("#")?.as_u64()?;
+        self.sources.get(idx as usize)
+    }
+
+    fn selected_url(&self) -> Option<String> {
+        let (experiment, summary) = self.selected_source()?;
+        let url = summary
+            .as_ref()
+            .and_then(|s| s.experiment_url.clone())
+            .unwrap_or_else(|| {
+                format!(
+                    "{}/experiments/{}",
+                    self.project_url,
+                    encode(&experiment.name)
+                )
+            });
+        Some(url)
+    }
+}
+
+fn draw(frame: &mut Frame<'_>, browser: &mut Browser) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(frame.area());
+    match browser.cases.as_mut() {
+        Some(cases) => cases.render(frame, chunks[0]),
+        None => browser.experiments.render(frame, chunks[0]),
+    }
+    frame.render_widget(
+        Paragraph::new(browser.status.as_str()).style(Style::default().fg(Color::DarkGray)),
+        chunks[1],
+    );
+}
+
+/// One row per experiment with a column per score; `#` keeps the original order.
+fn experiment_rows(
+    sources: &[(Experiment, Option<Summary>)],
+) -> (Vec<String>, Vec<Map<String, Value>>) {
+    let mut score_names: Vec<String> = sources
+        .iter()
+        .filter_map(|(_, summary)| summary.as_ref())
+        .flat_map(|summary| summary.scores.keys().cloned())
+        .collect();
+    score_names.sort();
+    score_names.dedup();
+
+    let rows = sources
+        .iter()
+        .enumerate()
+        .map(|(idx, (experiment, summary))| {
+            let mut row = Map::new();
+            row.insert("#".to_string(), json!(idx));
+            row.insert("name".to_string(), json!(experiment.name));
+            row.insert(
+                "created".to_string(),
+                json!(format_created(experiment.created.as_deref())),
+            );
+            for name in &score_names {
+                let score = summary
+                    .as_ref()
+                    .and_then(|s| s.scores.get(name))
+                    .map(|s| s.score);
+                row.insert(name.clone(), json!(score));
+            }
+            row
+        })
+        .collect();
+
+    let mut headers = EXPERIMENT_COLUMNS.map(String::from).to_vec();
+    headers.extend(score_names);
+    (headers, rows)
+}
+
+/// Root spans of the experiment with their input, output, and a column per score.
+async fn case_rows(
+    client: &ApiClient,
+    experiment: &Experiment,
+) -> Result<(Vec<String>, Vec<Map<String, Value>>)> {
+    let query = format!(
+        "SELECT input, output, expected, scores FROM experiment({}) WHERE is_root LIMIT 500",
+        quote_literal(&experiment.id)
+    );
+    let spans = query_rows(client, &query).await?;
+
+    let mut score_names: Vec<String> = spans
+        .iter()
+        .filter_map(|span| span.get("scores").and_then(Value::as_object))
+        .flat_map(|scores| scores.keys().cloned())
+        .collect();
+    score_names.sort();
+    score_names.dedup();
+
+    let rows = spans
+        .into_iter()
+        .enumerate()
+        .map(|(idx, mut span)| {
+            let scores = span.remove("scores");
+            span.insert("#".to_string(), json!(idx));
+            for name in &score_names {
+                let score = scores.as_ref().and_then(|s| s.get(name)).cloned();
+                span.insert(name.clone(), score.unwrap_or(Value::Null));
+            }
+            span
+        })
+        .collect();
+
+    let mut headers = CASE_COLUMNS.map(String::from).to_vec();
+    headers.extend(score_names);
+    Ok((headers, rows))
+}
+
+/// Cell text: scores as percentages, strings as-is, other values as compact JSON.
+fn display(value: Option<&Value>, is_score: bool) -> String {
+    match value {
+        None | Some(Value::Null) => "-".to_string(),
+        Some(Value::String(s)) => s.replace('\n', " "),
+        Some(Value::Number(n)) => match n.as_f64() {
+            Some(f) if is_score => format!("{:.1}%", f * 100.0),
+            _ => n.to_string(),
+        },
+        Some(other) => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn experiment_rows_have_a_column_per_score() {
+        let experiment: Experiment = serde_json::from_value(json!({
+            "id": "e1", "name": "run-1", "project_id": "p", "created": "2024-05-01T00:00:00Z",
+        }))
+        .unwrap();
+        let summary: Summary = serde_json::from_value(json!({
+            "project_name": "p",
+            "experiment_name": "run-1",
+            "scores": { "acc": { "name": "acc", "score": 0.5 } },
+        }))
+        .unwrap();
+        let (headers, rows) = experiment_rows(&[(experiment, Some(summary))]);
+        assert_eq!(headers, vec!["name", "created", "acc"]);
+        assert_eq!(display(rows[0].get("acc"), true), "50.0%");
+        assert_eq!(
+            display(rows[0].get("created"), false),
+            "2024-05-01 00:00:00"
+        );
+        assert_eq!(display(Some(&json!(0.5)), false), "0.5");
+    }
+}
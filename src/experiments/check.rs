@@ -0,0 +1,186 @@
+use anyhow::{anyhow, bail, Result};
+use dialoguer::console;
+use serde::Serialize;
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::with_spinner;
+
+use super::api::{self, Experiment, Summary};
+
+/// Baseline name that resolves to the newest experiment run on the `main` branch.
+const LATEST_MAIN: &str = "latest-main";
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Failure {
+    score: String,
+    reason: String,
+}
+
+pub struct Thresholds<'a> {
+    pub min_scores: &'a [(String, f64)],
+    pub max_regression: Option<f64>,
+}
+
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    candidate: &Experiment,
+    baseline: Option<&str>,
+    thresholds: Thresholds<'_>,
+    json: bool,
+) -> Result<()> {
+    let (baseline, summary) = with_spinner("Checking experiment...", async {
+        let baseline = match baseline {
+            Some(name) => Some(find_baseline(client, project, candidate, name).await?),
+            None => None,
+        };
+        let summary = api::summarize(
+            client,
+            &candidate.id,
+            baseline.as_ref().map(|b| b.id.as_str()),
+        )
+        .await?;
+        anyhow::Ok((baseline, summary))
+    })
+    .await?;
+    let failures = evaluate(&summary, &thresholds);
+
+    if json {
+        let value = serde_json::json!({
+            "candidate": candidate.name,
+            "baseline": baseline.as_ref().map(|b| &b.name),
+            "passed": failures.is_empty(),
+            "failures": failures,
+        });
+        println!("{}", serde_json::to_string(&value)?);
+    } else {
+        let against = baseline
+            .as_ref()
+            .map(|b| format!(" against {}", console::style(&b.name).bold()))
+            .unwrap_or_default();
+        println!(
+            "Checking {}{against}",
+            console::style(&candidate.name).bold()
+        );
+        for failure in &failures {
+            println!(
+                "  {} {}: {}",
+                console::style("✗").red(),
+                failure.score,
+                failure.reason
+            );
+        }
+    }
+
+    if !failures.is_empty() {
+        bail!(
+            "{} check(s) failed for '{}'",
+            failures.len(),
+            candidate.name
+        );
+    }
+    if !json {
+        println!("  {} all checks passed", console::style("✓").green());
+    }
+    Ok(())
+}
+
+/// The baseline experiment, where `latest-main` picks the newest other run on `main`.
+async fn find_baseline(
+    client: &ApiClient,
+    project: &Project,
+    candidate: &Experiment,
+    name: &str,
+) -> Result<Experiment> {
+    if name != LATEST_MAIN {
+        return api::get_experiment_by_name(client, &project.id, name)
+            .await?
+            .ok_or_else(|| anyhow!("baseline '{name}' not found in '{}'", project.name));
+    }
+    api::list_experiments(client, &project.id, None)
+        .await?
+        .into_iter()
+        .find(|e| {
+            e.id != candidate.id
+                && e.repo_info
+                    .as_ref()
+                    .and_then(|info| info.get("branch"))
+                    .and_then(|branch| branch.as_str())
+                    == Some("main")
+        })
+        .ok_or_else(|| anyhow!("no experiment on 'main' found in '{}'", project.name))
+}
+
+fn evaluate(summary: &Summary, thresholds: &Thresholds<'_>) -> Vec<Failure> {
+    let mut failures = Vec::new();
+    for (name, min) in thresholds.min_scores {
+        let reason = match summary.scores.get(name) {
+            None => "score missing".to_string(),
+            Some(s) if s.score < *min => format!(
+                "{:.2}% is below the minimum {:.2}%",
+                s.score * 100.0,
+                min * 100.0
+            ),
+            Some(_) => continue,
+        };
+        failures.push(Failure {
+            score: name.clone(),
+            reason,
+        });
+    }
+    if let Some(max) = thresholds.max_regression {
+        for score in summary.scores.values() {
+            if let Some(diff) = score.diff.filter(|d| -d > max) {
+                failures.push(Failure {
+                    score: score.name.clone(),
+                    reason: format!(
+                        "regressed {:.2}% (allowed {:.2}%)",
+                        -diff * 100.0,
+                        max * 100.0
+                    ),
+                });
+            }
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn evaluate_reports_low_missing_and_regressed_scores() {
+        let summary: Summary = serde_json::from_value(json!({
+            "project_name": "p",
+            "experiment_name": "e",
+            "scores": {
+                "accuracy": { "name": "accuracy", "score": 0.85, "diff": -0.05 },
+                "tone": { "name": "tone", "score": 0.9, "diff": -0.01 },
+            },
+        }))
+        .unwrap();
+        let min_scores = [("accuracy".to_string(), 0.9), ("tone".to_string(), 0.5)];
+        let failures = evaluate(
+            &summary,
+            &Thresholds {
+                min_scores: &min_scores,
+                max_regression: Some(0.02),
+            },
+        );
+        let names: Vec<&str> = failures.iter().map(|f| f.score.as_str()).collect();
+        assert_eq!(names, vec!["accuracy", "accuracy"]);
+
+        let missing = [("latency".to_string(), 0.5)];
+        let failures = evaluate(
+            &summary,
+            &Thresholds {
+                min_scores: &missing,
+                max_regression: None,
+            },
+        );
+        assert_eq!(failures[0].reason, "score missing");
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use dialoguer::console;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::http::ApiClient;
+use crate::sql::{query_all_rows, quote_literal, truncate_cell};
+use crate::ui::with_spinner;
+
+use super::api::{self, Experiment};
+
+/// A test case whose score changed between the two experiments.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct CaseChange {
+    input: String,
+    score: String,
+    before: f64,
+    after: f64,
+}
+
+impl CaseChange {
+    fn delta(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+pub async fn run(
+    client: &ApiClient,
+    base: &Experiment,
+    candidate: &Experiment,
+    cases: usize,
+    fail_on_regression: bool,
+    json: bool,
+) -> Result<()> {
+    let (summary, (changes, ambiguous)) = with_spinner("Comparing experiments...", async {
+        let summary = api::summarize(client, &candidate.id, Some(&base.id)).await?;
+        let before = root_spans(client, &base.id).await?;
+        let after = root_spans(client, &candidate.id).await?;
+        anyhow::Ok((summary, case_changes(&before, &after)))
+    })
+    .await?;
+    if ambiguous > 0 {
+        eprintln!(
+            "Warning: skipped {ambiguous} case(s) that appear more than once in an experiment"
+        );
+    }
+
+    let regressed: Vec<&str> = summary
+        .scores
+        .values()
+        .filter(|s| s.diff.is_some_and(|d| d < 0.0))
+        .map(|s| s.name.as_str())
+        .collect();
+
+    if json {
+        let value = serde_json::json!({
+            "base": base.name,
+            "candidate": candidate.name,
+            "scores": summary.scores,
+            "cases": changes,
+        });
+        println!("{}", serde_json::to_string(&value)?);
+    } else {
+        print_report(base, candidate, &summary, &changes, cases);
+    }
+
+    if fail_on_regression && !regressed.is_empty() {
+        bail!("scores regressed: {}", regressed.join(", "));
+    }
+    Ok(())
+}
+
+fn print_report(
+    base: &Experiment,
+    candidate: &Experiment,
+    summary: &api::Summary,
+    changes: &[CaseChange],
+    cases: usize,
+) {
+    println!(
+        "{} → {}\n",
+        console::style(&base.name).bold(),
+        console::style(&candidate.name).bold()
+    );
+    let width = summary
+        .scores
+        .keys()
+        .map(String::len)
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    println!(
+        "{}",
+        console::style(format!(
+            "{:width$}  {:>8}  {:>8}  {:>8}  {:>8}  {:>8}",
+            "Score", "Base", "New", "Diff", "Better", "Worse"
+        ))
+        .dim()
+        .bold()
+    );
+    for score in summary.scores.values() {
+        let diff = score.diff.unwrap_or(0.0);
+        let before = score.score - diff;
+        let diff_text = format!("{:>+7.2}%", diff * 100.0);
+        let diff_text = if diff > 0.0 {
+            console::style(diff_text).green()
+        } else if diff < 0.0 {
+            console::style(diff_text).red()
+        } else {
+            console::style(diff_text).dim()
+        };
+        println!(
+            "{:width$}  {:>7.2}%  {:>7.2}%  {}  {:>8}  {:>8}",
+            score.name,
+            before * 100.0,
+            score.score * 100.0,
+            diff_text,
+            score.improvements.unwrap_or(0),
+            score.regressions.unwrap_or(0),
+        );
+    }
+
+    let mut regressions: Vec<&CaseChange> = changes.iter().filter(|c| c.delta() < 0.0).collect();
+    regressions.sort_by(|a, b| a.delta().total_cmp(&b.delta()));
+    let mut improvements: Vec<&CaseChange> = changes.iter().filter(|c| c.delta() > 0.0).collect();
+    improvements.sort_by(|a, b| b.delta().total_cmp(&a.delta()));
+    print_cases("Regressions", &regressions, cases);
+    print_cases("Improvements", &improvements, cases);
+}
+
+fn print_cases(title: &str, changes: &[&CaseChange], limit: usize) {
+    if changes.is_empty() || limit == 0 {
+        return;
+    }
+    println!(
+        "\n{}",
+        console::style(format!("{title} ({})", changes.len())).bold()
+    );
+    for change in changes.iter().take(limit) {
+        println!(
+            "  {:>6.2} → {:<6.2} {:16} {}",
+            change.before,
+            change.after,
+            change.score,
+            truncate_cell(&change.input, 80)
+        );
+    }
+}
+
+async fn root_spans(client: &ApiClient, experiment_id: &str) -> Result<Vec<Map<String, Value>>> {
+    let query = format!(
+        "SELECT input, origin, scores FROM experiment({}) WHERE is_root",
+        quote_literal(experiment_id)
+    );
+    query_all_rows(client, &query).await
+}
+
+/// Identifies a test case across experiments: the dataset record it came from,
+/// or its input when it has none.
+fn case_key(row: &Map<String, Value>) -> Option<String> {
+    let origin = row
+        .get("origin")
+        .and_then(|origin| origin.get("id"))
+        .and_then(Value::as_str);
+    match origin {
+        Some(id) => Some(format!("origin:{id}")),
+        None => row.get("input").map(|input| format!("input:{input}")),
+    }
+}
+
+/// Rows by case key, leaving out keys shared by several rows; also returns those keys.
+fn index_cases(rows: &[Map<String, Value>]) -> (HashMap<String, &Map<String, Value>>, Vec<String>) {
+    let mut index = HashMap::new();
+    let mut duplicates = Vec::new();
+    for row in rows {
+        let Some(key) = case_key(row) else { continue };
+        if index.insert(key.clone(), row).is_some() {
+            duplicates.push(key);
+        }
+    }
+    for key in &duplicates {
+        index.remove(key);
+    }
+    duplicates.sort();
+    duplicates.dedup();
+    (index, duplicates)
+}
+
+/// Pair root spans by case and list every score that differs between them,
+/// with the number of cases skipped because their key wasn't unique.
+fn case_changes(
+    before: &[Map<String, Value>],
+    after: &[Map<String, Value>],
+) -> (Vec<CaseChange>, usize) {
+    let (before, before_dups) = index_cases(before);
+    let (after_index, after_dups) = index_cases(after);
+    let mut ambiguous = before_dups;
+    ambiguous.extend(after_dups);
+    ambiguous.sort();
+    ambiguous.dedup();
+
+    let mut changes = Vec::new();
+    for row in after {
+        let Some(key) = case_key(row) else { continue };
+        if !after_index.contains_key(&key) {
+            continue;
+        }
+        let Some(old) = before.get(&key) else {
+            continue;
+        };
+        let input = row.get("input").map(Value::to_string).unwrap_or_default();
+        let (Some(new_scores), Some(old_scores)) = (
+            row.get("scores").and_then(Value::as_object),
+            old.get("scores").and_then(Value::as_object),
+        ) else {
+            continue;
+        };
+        for (name, after) in new_scores {
+            let (Some(after), Some(before)) =
+                (after.as_f64(), old_scores.get(name).and_then(Value::as_f64))
+            else {
+                continue;
+            };
+            if after != before {
+                changes.push(CaseChange {
+                    input: input.clone(),
+                    score: name.clone(),
+                    before,
+                    after,
+                });
+            }
+        }
+    }
+    (changes, ambiguous.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(input: Value, scores: Value) -> Map<String, Value> {
+        json!({ "input": input, "scores": scores })
+            .as_object()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn case_changes_matches_rows_by_input() {
+        let before = vec![
+            row(json!({ "q": "a" }), json!({ "acc": 1.0, "f": 0.5 })),
+            row(json!({ "q": "b" }), json!({ "acc": 0.0 })),
+        ];
+        let after = vec![
+            row(json!({ "q": "b" }), json!({ "acc": 1.0 })),
+            row(json!({ "q": "a" }), json!({ "acc": 0.0, "f": 0.5 })),
+            row(json!({ "q": "c" }), json!({ "acc": 1.0 })),
+        ];
+        let (changes, ambiguous) = case_changes(&before, &after);
+        assert_eq!(ambiguous, 0);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].input, r#"{"q":"b"}"#);
+        assert_eq!(changes[0].delta(), 1.0);
+        assert_eq!(changes[1].score, "acc");
+        assert_eq!(changes[1].delta(), -1.0);
+    }
+
+    #[test]
+    fn case_changes_prefers_origin_and_skips_duplicates() {
+        let with_origin = |id: &str, input: Value, acc: f64| {
+            let mut row = row(input, json!({ "acc": acc }));
+            row.insert("origin".to_string(), json!({ "id": id }));
+            row
+        };
+        let before = vec![
+            with_origin("r1", json!("same"), 0.0),
+            with_origin("r2", json!("same"), 1.0),
+            row(json!("dup"), json!({ "acc": 0.0 })),
+            row(json!("dup"), json!({ "acc": 1.0 })),
+        ];
+        let after = vec![
+            with_origin("r1", json!("same"), 1.0),
+            with_origin("r2", json!("same"), 1.0),
+            row(json!("dup"), json!({ "acc": 1.0 })),
+        ];
+        let (changes, ambiguous) = case_changes(&before, &after);
+        assert_eq!(ambiguous, 1);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].delta(), 1.0);
+    }
+}
@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+
+use crate::filter::{is_older_than, NameFilter};
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::{self, print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Experiment};
+
+/// Which experiments to delete: explicit names, or every experiment matching the filters.
+pub struct Selection<'a> {
+    pub names: &'a [String],
+    pub filter: Option<&'a NameFilter>,
+    pub older_than: Option<Duration>,
+}
+
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    selection: Selection<'_>,
+    yes: bool,
+) -> Result<()> {
+    let bulk = selection.filter.is_some() || selection.older_than.is_some();
+    let experiments = select_experiments(client, project, &selection).await?;
+    if experiments.is_empty() {
+        if bulk {
+            bail!("no experiments match the filters");
+        }
+        return Ok(());
+    }
+
+    if !yes {
+        if !ui::is_interactive() {
+            bail!(
+                "refusing to delete without confirmation; pass --yes to delete non-interactively"
+            );
+        }
+        let prompt = match experiments.as_slice() {
+            [experiment] => format!("Delete experiment '{}'?", experiment.name),
+            _ => {
+                let names: Vec<&str> = experiments.iter().map(|e| e.name.as_str()).collect();
+                format!("Delete {} experiments ({})?", names.len(), names.join(", "))
+            }
+        };
+        if !ui::confirm(&prompt, false)? {
+            return Ok(());
+        }
+    }
+
+    let mut failed = 0;
+    for experiment in &experiments {
+        match with_spinner(
+            &format!("Deleting '{}'...", experiment.name),
+            api::delete_experiment(client, &experiment.id),
+        )
+        .await
+        {
+            Ok(()) => print_command_status(
+                CommandStatus::Success,
+                &format!("Deleted '{}'", experiment.name),
+            ),
+            Err(e) => {
+                failed += 1;
+                print_command_status(
+                    CommandStatus::Error,
+                    &format!("Failed to delete '{}': {e}", experiment.name),
+                );
+            }
+        }
+    }
+    if failed > 0 {
+        bail!(
+            "failed to delete {failed} of {} experiments",
+            experiments.len()
+        );
+    }
+    Ok(())
+}
+
+async fn select_experiments(
+    client: &ApiClient,
+    project: &Project,
+    selection: &Selection<'_>,
+) -> Result<Vec<Experiment>> {
+    if !selection.names.is_empty() {
+        let mut experiments = Vec::with_capacity(selection.names.len());
+        for name in selection.names {
+            experiments.push(super::find_experiment(client, project, name).await?);
+        }
+        return Ok(experiments);
+    }
+
+    if selection.filter.is_none() && selection.older_than.is_none() {
+        return Ok(vec![
+            super::resolve_experiment(client, project, None).await?,
+        ]);
+    }
+
+    let mut experiments = with_spinner(
+        "Loading experiments...",
+        api::list_experiments(client, &project.id, None),
+    )
+    .await?;
+    if let Some(filter) = selection.filter {
+        experiments.retain(|e| filter.matches(&e.name));
+    }
+    if let Some(age) = selection.older_than {
+        experiments.retain(|e| is_older_than(e.created.as_deref(), age));
+    }
+    Ok(experiments)
+}
@@ -0,0 +1,54 @@
+use std::io::{self, Write};
+
+use anyhow::Result;
+use dialoguer::console;
+
+use crate::http::ApiClient;
+
+use super::api::{self, Experiment};
+
+const PAGE_SIZE: usize = 1000;
+
+/// Stream raw experiment events to stdout as JSONL, starting at `cursor` and stopping after
+/// `limit` events. The cursor for the next page is printed to stderr so a later call can resume.
+pub async fn run(
+    client: &ApiClient,
+    experiment: &Experiment,
+    limit: Option<usize>,
+    cursor: Option<String>,
+) -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    let mut cursor = cursor;
+    let mut written = 0;
+    loop {
+        let remaining = limit.map_or(PAGE_SIZE, |limit| limit - written);
+        if remaining == 0 {
+            break;
+        }
+        let page_size = remaining.min(PAGE_SIZE);
+        let (events, next) =
+            api::fetch_events(client, &experiment.id, page_size, cursor.as_deref()).await?;
+        for event in &events {
+            serde_json::to_writer(&mut stdout, event)?;
+            stdout.write_all(b"\n")?;
+        }
+        stdout.flush()?;
+        written += events.len();
+        cursor = next;
+        if events.is_empty() || cursor.is_none() {
+            cursor = None;
+            break;
+        }
+    }
+
+    if let Some(cursor) = cursor {
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "More events available. Continue with --cursor {cursor}"
+            ))
+            .dim()
+        );
+    }
+    Ok(())
+}
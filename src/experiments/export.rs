@@ -0,0 +1,184 @@
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+use crate::http::ApiClient;
+use crate::sql::{csv_escape, AtomicFile};
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Experiment};
+
+const PAGE_SIZE: usize = 1000;
+const FIELDS: &[&str] = &["id", "input", "output", "expected", "scores", "metadata"];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum ExportFormat {
+    /// One JSON object per row
+    Jsonl,
+    /// Comma-separated values with one column per score
+    Csv,
+}
+
+impl ExportFormat {
+    /// The format implied by an output path's extension, defaulting to JSONL.
+    pub fn for_path(path: Option<&Path>) -> Self {
+        match path.and_then(|p| p.extension()).and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => ExportFormat::Csv,
+            _ => ExportFormat::Jsonl,
+        }
+    }
+}
+
+pub async fn run(
+    client: &ApiClient,
+    experiment: &Experiment,
+    output: Option<&Path>,
+    format: ExportFormat,
+    all_spans: bool,
+) -> Result<()> {
+    let rows = with_spinner(
+        "Fetching events...",
+        fetch_rows(client, &experiment.id, all_spans),
+    )
+    .await?;
+    let contents = match format {
+        ExportFormat::Jsonl => to_jsonl(&rows)?,
+        ExportFormat::Csv => to_csv(&rows),
+    };
+
+    match output {
+        Some(path) => {
+            let mut file = AtomicFile::create(path)?;
+            file.write_all(contents.as_bytes())?;
+            file.commit()?;
+            print_command_status(
+                CommandStatus::Success,
+                &format!("Wrote {} rows to {}", rows.len(), path.display()),
+            );
+        }
+        None => print!("{contents}"),
+    }
+    Ok(())
+}
+
+/// Every event of the experiment (root spans only unless `all_spans`), trimmed to `FIELDS`.
+async fn fetch_rows(
+    client: &ApiClient,
+    experiment_id: &str,
+    all_spans: bool,
+) -> Result<Vec<Map<String, Value>>> {
+    let mut rows = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let (events, next) =
+            api::fetch_events(client, experiment_id, PAGE_SIZE, cursor.as_deref()).await?;
+        if events.is_empty() {
+            break;
+        }
+        rows.extend(
+            events
+                .iter()
+                .filter(|event| all_spans || is_root(event))
+                .map(|event| {
+                    FIELDS
+                        .iter()
+                        .filter_map(|f| event.get(*f).map(|v| (f.to_string(), v.clone())))
+                        .collect()
+                }),
+        );
+        match next {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    Ok(rows)
+}
+
+fn is_root(event: &Value) -> bool {
+    event
+        .get("span_parents")
+        .and_then(Value::as_array)
+        .is_none_or(|parents| parents.is_empty())
+}
+
+fn to_jsonl(rows: &[Map<String, Value>]) -> Result<String> {
+    let mut out = String::new();
+    for row in rows {
+        out.push_str(&serde_json::to_string(row)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// CSV with JSON-encoded input/output/expected/metadata and a `scores.<name>` column per score.
+fn to_csv(rows: &[Map<String, Value>]) -> String {
+    let score_names: BTreeSet<&str> = rows
+        .iter()
+        .filter_map(|row| row.get("scores").and_then(Value::as_object))
+        .flat_map(|scores| scores.keys().map(String::as_str))
+        .collect();
+    let columns = ["id", "input", "output", "expected", "metadata"];
+
+    let mut header: Vec<String> = columns.iter().map(|c| c.to_string()).collect();
+    header.extend(score_names.iter().map(|name| format!("scores.{name}")));
+    let mut out = join_csv(&header);
+
+    for row in rows {
+        let mut cells: Vec<String> = columns.iter().map(|c| cell(row.get(*c))).collect();
+        let scores = row.get("scores").and_then(Value::as_object);
+        cells.extend(
+            score_names
+                .iter()
+                .map(|name| cell(scores.and_then(|s| s.get(*name)))),
+        );
+        out.push_str(&join_csv(&cells));
+    }
+    out
+}
+
+fn cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn join_csv(cells: &[String]) -> String {
+    let mut line = cells
+        .iter()
+        .map(|c| csv_escape(c))
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn csv_has_a_column_per_score() {
+        let rows: Vec<Map<String, Value>> = vec![
+            json!({ "id": "1", "input": { "q": "a,b" }, "output": "x", "scores": { "acc": 1 } }),
+            json!({ "id": "2", "input": "plain", "scores": { "f1": 0.5 } }),
+        ]
+        .into_iter()
+        .map(|v| v.as_object().unwrap().clone())
+        .collect();
+        assert_eq!(
+            to_csv(&rows),
+            "id,input,output,expected,metadata,scores.acc,scores.f1\n\
+             1,\"{\"\"q\"\":\"\"a,b\"\"}\",x,,,1,\n\
+             2,plain,,,,,0.5\n"
+        );
+        assert!(is_root(&json!({ "span_parents": [] })));
+        assert!(!is_root(&json!({ "span_parents": ["p"] })));
+    }
+}
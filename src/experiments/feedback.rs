@@ -0,0 +1,90 @@
+use anyhow::{bail, Result};
+use serde_json::{json, Map, Value};
+
+use crate::http::ApiClient;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Experiment};
+
+/// Parse a `--score name=value` argument, where value is between 0 and 1.
+pub fn parse_score(raw: &str) -> Result<(String, f64), String> {
+    let (name, value) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got '{raw}'"))?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("missing score name in '{raw}'"));
+    }
+    let value: f64 = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid score value '{value}'"))?;
+    if !(0.0..=1.0).contains(&value) {
+        return Err(format!("score '{name}' must be between 0 and 1"));
+    }
+    Ok((name.to_string(), value))
+}
+
+pub async fn run(
+    client: &ApiClient,
+    experiment: &Experiment,
+    id: &str,
+    scores: &[(String, f64)],
+    comment: Option<&str>,
+    expected: Option<&str>,
+) -> Result<()> {
+    let feedback = feedback_event(id, scores, comment, expected)?;
+    with_spinner(
+        "Recording feedback...",
+        api::log_feedback(client, &experiment.id, &[feedback]),
+    )
+    .await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Recorded feedback on {id} in '{}'", experiment.name),
+    );
+    Ok(())
+}
+
+/// The feedback payload for the event with `id`.
+pub fn feedback_event(
+    id: &str,
+    scores: &[(String, f64)],
+    comment: Option<&str>,
+    expected: Option<&str>,
+) -> Result<Value> {
+    if scores.is_empty() && comment.is_none() && expected.is_none() {
+        bail!("nothing to record; pass --score, --comment, or --expected");
+    }
+
+    let mut feedback = json!({ "id": id, "source": "api" });
+    if !scores.is_empty() {
+        let scores: Map<String, Value> = scores
+            .iter()
+            .map(|(name, value)| (name.clone(), json!(value)))
+            .collect();
+        feedback["scores"] = Value::Object(scores);
+    }
+    if let Some(comment) = comment {
+        feedback["comment"] = json!(comment);
+    }
+    if let Some(expected) = expected {
+        // Accept JSON for structured expected values, falling back to a plain string.
+        feedback["expected"] = serde_json::from_str(expected).unwrap_or_else(|_| json!(expected));
+    }
+    Ok(feedback)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_score_validates_range() {
+        assert_eq!(parse_score("accuracy=1"), Ok(("accuracy".to_string(), 1.0)));
+        assert_eq!(parse_score(" tone = 0.25"), Ok(("tone".to_string(), 0.25)));
+        assert!(parse_score("accuracy=1.5").is_err());
+        assert!(parse_score("accuracy").is_err());
+        assert!(parse_score("=1").is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use dialoguer::console;
+use serde::Serialize;
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr;
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::{query_rows, quote_literal};
+use crate::ui::with_spinner;
+
+use super::api;
+use super::list::format_created;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Entry {
+    rank: usize,
+    experiment: String,
+    score: f64,
+    cases: u64,
+    created: Option<String>,
+}
+
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    score: &str,
+    top: usize,
+    json: bool,
+) -> Result<()> {
+    let (experiments, rows) = with_spinner("Ranking experiments...", async {
+        let experiments = api::list_experiments(client, &project.id, None).await?;
+        if experiments.is_empty() {
+            return anyhow::Ok((experiments, Vec::new()));
+        }
+        let ids: Vec<&str> = experiments.iter().map(|e| e.id.as_str()).collect();
+        let rows = query_rows(client, &leaderboard_query(&ids, score)).await?;
+        anyhow::Ok((experiments, rows))
+    })
+    .await?;
+
+    let by_id: HashMap<&str, &api::Experiment> =
+        experiments.iter().map(|e| (e.id.as_str(), e)).collect();
+    let mut ranked: Vec<(&api::Experiment, f64, u64)> = rows
+        .iter()
+        .filter_map(|row| {
+            let experiment = by_id.get(row.get("experiment_id")?.as_str()?)?;
+            let value = row.get("score")?.as_f64()?;
+            let cases = row.get("cases").and_then(Value::as_u64).unwrap_or(0);
+            Some((*experiment, value, cases))
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let entries: Vec<Entry> = ranked
+        .into_iter()
+        .take(top)
+        .enumerate()
+        .map(|(idx, (experiment, score, cases))| Entry {
+            rank: idx + 1,
+            experiment: experiment.name.clone(),
+            score,
+            cases,
+            created: experiment.created.clone(),
+        })
+        .collect();
+
+    if json {
+        println!("{}", serde_json::to_string(&entries)?);
+        return Ok(());
+    }
+    if entries.is_empty() {
+        bail!(
+            "no experiments in '{}' have a '{score}' score",
+            project.name
+        );
+    }
+
+    println!(
+        "Top {} experiments in {} by {}\n",
+        console::style(entries.len()),
+        console::style(&project.name).bold(),
+        console::style(score).bold()
+    );
+    let name_width = entries
+        .iter()
+        .map(|e| e.experiment.width())
+        .max()
+        .unwrap_or(0)
+        .max(10);
+    println!(
+        "{}",
+        console::style(format!(
+            "{:>4}  {:name_width$}  {:>8}  {:>6}  {}",
+            "#", "Experiment", "Score", "Cases", "Created"
+        ))
+        .dim()
+        .bold()
+    );
+    for entry in &entries {
+        let padding = name_width - entry.experiment.width();
+        println!(
+            "{:>4}  {}{:padding$}  {:>7.2}%  {:>6}  {}",
+            entry.rank,
+            entry.experiment,
+            "",
+            entry.score * 100.0,
+            entry.cases,
+            format_created(entry.created.as_deref()),
+        );
+    }
+    Ok(())
+}
+
+/// Average of `score` over each experiment's root spans, one row per experiment.
+fn leaderboard_query(experiment_ids: &[&str], score: &str) -> String {
+    let ids: Vec<String> = experiment_ids.iter().map(|id| quote_literal(id)).collect();
+    let field = format!("scores.`{}`", score.replace('`', "``"));
+    format!(
+        "SELECT experiment_id, AVG({field}) AS score, COUNT({field}) AS cases \
+         FROM experiment({}) WHERE is_root AND {field} IS NOT NULL GROUP BY experiment_id",
+        ids.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaderboard_query_quotes_ids_and_score() {
+        assert_eq!(
+            leaderboard_query(&["a", "b'c"], "exact`match"),
+            "SELECT experiment_id, AVG(scores.`exact``match`) AS score, \
+             COUNT(scores.`exact``match`) AS cases FROM experiment('a', 'b''c') \
+             WHERE is_root AND scores.`exact``match` IS NOT NULL GROUP BY experiment_id"
+        );
+    }
+}
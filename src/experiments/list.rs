@@ -0,0 +1,172 @@
+use anyhow::Result;
+use dialoguer::console;
+use serde_json::json;
+use unicode_width::UnicodeWidthStr;
+
+use crate::filter::{is_older_than, is_within};
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::with_spinner;
+
+use super::api::{self, Summary};
+use super::ListArgs;
+
+/// Experiments requested per page while searching for filter matches.
+const PAGE_SIZE: usize = 100;
+
+pub async fn run(client: &ApiClient, project: &Project, args: &ListArgs, json: bool) -> Result<()> {
+    let (experiments, summaries) = with_spinner("Loading experiments...", async {
+        let filtered = args.filter.is_some() || args.since.is_some() || args.tag.is_some();
+        let experiments = if filtered {
+            find_matching(client, project, args).await?
+        } else {
+            api::list_experiments(client, &project.id, Some(args.limit)).await?
+        };
+        let summaries = api::summarize_all(client, &experiments).await?;
+        anyhow::Ok((experiments, summaries))
+    })
+    .await?;
+
+    if json {
+        let rows: Vec<_> = experiments
+            .iter()
+            .zip(&summaries)
+            .map(|(experiment, summary)| {
+                let mut value = serde_json::to_value(experiment)?;
+                value["scores"] = json!(summary.as_ref().map(|s| &s.scores));
+                Ok(value)
+            })
+            .collect::<Result<_>>()?;
+        println!("{}", serde_json::to_string(&rows)?);
+        return Ok(());
+    }
+
+    println!(
+        "{} experiments found in {}\n",
+        console::style(experiments.len()),
+        console::style(&project.name).bold()
+    );
+    let name_width = experiments
+        .iter()
+        .map(|e| e.name.width())
+        .max()
+        .unwrap_or(0)
+        .max(15);
+    println!(
+        "{}  {}  {}",
+        console::style(format!("{:name_width$}", "Experiment"))
+            .dim()
+            .bold(),
+        console::style(format!("{:20}", "Created")).dim().bold(),
+        console::style("Scores").dim().bold()
+    );
+    for (experiment, summary) in experiments.iter().zip(&summaries) {
+        let padding = name_width - experiment.name.width();
+        println!(
+            "{}{:padding$}  {:20}  {}",
+            experiment.name,
+            "",
+            format_created(experiment.created.as_deref()),
+            summary.as_ref().map_or_else(|| "-".to_string(), score_line),
+        );
+    }
+    Ok(())
+}
+
+/// Experiments matching the filters, newest first, paging only until `--limit` of them
+/// are found. The API can't filter experiments, so the filters apply client-side.
+async fn find_matching(
+    client: &ApiClient,
+    project: &Project,
+    args: &ListArgs,
+) -> Result<Vec<api::Experiment>> {
+    let mut found = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page =
+            api::list_experiments_page(client, &project.id, PAGE_SIZE, cursor.as_deref()).await?;
+        let last_page = page.len() < PAGE_SIZE;
+        cursor = page.last().map(|e| e.id.clone());
+        for experiment in page {
+            // Newest first, so everything after one older than --since is older too.
+            if args
+                .since
+                .is_some_and(|age| is_older_than(experiment.created.as_deref(), age))
+            {
+                return Ok(found);
+            }
+            if matches(&experiment, args) {
+                found.push(experiment);
+                if found.len() == args.limit {
+                    return Ok(found);
+                }
+            }
+        }
+        if last_page {
+            return Ok(found);
+        }
+    }
+}
+
+fn matches(experiment: &api::Experiment, args: &ListArgs) -> bool {
+    if let Some(tag) = &args.tag {
+        if !experiment.tags.iter().flatten().any(|t| t == tag) {
+            return false;
+        }
+    }
+    if let Some(age) = args.since {
+        if !is_within(experiment.created.as_deref(), age) {
+            return false;
+        }
+    }
+    match &args.filter {
+        Some(filter) => serde_json::to_value(experiment).is_ok_and(|value| filter.matches(&value)),
+        None => true,
+    }
+}
+
+/// `2024-05-01T12:34:56.789Z` as `2024-05-01 12:34:56`.
+pub fn format_created(created: Option<&str>) -> String {
+    match created {
+        Some(created) => created.get(..19).unwrap_or(created).replacen('T', " ", 1),
+        None => "-".to_string(),
+    }
+}
+
+/// Score averages as percentages, e.g. `accuracy 85.0%  factuality 72.3%`.
+pub fn score_line(summary: &Summary) -> String {
+    if summary.scores.is_empty() {
+        return "-".to_string();
+    }
+    summary
+        .scores
+        .values()
+        .map(|s| format!("{} {:.1}%", s.name, s.score * 100.0))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_created_and_scores() {
+        assert_eq!(
+            format_created(Some("2024-05-01T12:34:56.789Z")),
+            "2024-05-01 12:34:56"
+        );
+        assert_eq!(format_created(None), "-");
+
+        let summary: Summary = serde_json::from_value(json!({
+            "project_name": "p",
+            "experiment_name": "e",
+            "scores": {
+                "accuracy": { "name": "accuracy", "score": 0.85 },
+                "factuality": { "name": "factuality", "score": 0.7234 },
+            },
+        }))
+        .unwrap();
+        assert_eq!(score_line(&summary), "accuracy 85.0%  factuality 72.3%");
+    }
+}
@@ -0,0 +1,82 @@
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+
+use crate::git;
+use crate::http::ApiClient;
+use crate::ingest::{insert_batches, read_input, RowIds};
+use crate::projects::api::Project;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api;
+
+/// Log events from a JSONL file (or stdin for `-`) to an experiment, creating it if needed.
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    name: &str,
+    file: &Path,
+    no_git: bool,
+) -> Result<()> {
+    let contents = read_input(file, "events")?;
+    let mut events = parse_events(&contents)?;
+    if events.is_empty() {
+        bail!("no events to log");
+    }
+    // Retried batches must overwrite, not duplicate, events the server already stored.
+    RowIds::random().assign_all(&mut events);
+
+    let repo_info = if no_git {
+        None
+    } else {
+        git::collect(Path::new("."))
+    };
+    let experiment = with_spinner(
+        "Preparing experiment...",
+        api::get_or_create_experiment(client, &project.id, name, repo_info.as_ref()),
+    )
+    .await?;
+
+    insert_batches(&events, "events", |batch| {
+        api::insert_events(client, &experiment.id, batch)
+    })
+    .await?;
+
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Logged {} events to '{}'", events.len(), experiment.name),
+    );
+    Ok(())
+}
+
+/// One JSON object per non-blank line.
+pub fn parse_events(contents: &str) -> Result<Vec<Value>> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            let event: Value = serde_json::from_str(line)
+                .with_context(|| format!("invalid JSON on line {}", idx + 1))?;
+            if !event.is_object() {
+                bail!("line {} is not a JSON object", idx + 1);
+            }
+            Ok(event)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_events_skips_blank_lines_and_reports_bad_ones() {
+        let events = parse_events("{\"input\": 1}\n\n{\"input\": 2}\n").unwrap();
+        assert_eq!(events.len(), 2);
+
+        let err = parse_events("{\"input\": 1}\n[1]\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2 is not a JSON object");
+    }
+}
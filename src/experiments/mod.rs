@@ -0,0 +1,477 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use clap::{Args, Subcommand};
+
+use crate::args::BaseArgs;
+use crate::filter::{parse_age, NameFilter, WhereClause};
+use crate::http::ApiClient;
+use crate::login::login;
+use crate::projects::api::Project;
+use crate::projects::resolve_project;
+use crate::ui::{self, with_spinner};
+
+pub(crate) mod api;
+mod browse;
+mod check;
+mod compare;
+mod delete;
+mod events;
+mod export;
+mod feedback;
+mod leaderboard;
+mod list;
+mod log;
+mod scores;
+mod tags;
+mod update;
+mod view;
+mod wait;
+
+pub(crate) use feedback::{feedback_event, parse_score};
+pub(crate) use list::format_created;
+pub(crate) use log::parse_events;
+
+#[derive(Debug, Clone, Args)]
+pub struct ExperimentsArgs {
+    #[command(subcommand)]
+    command: Option<ExperimentsCommands>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum ExperimentsCommands {
+    /// List experiments in the active project
+    List(ListArgs),
+    /// Show an experiment's scores, metadata, and git info
+    View(ViewArgs),
+    /// Compare scores between two experiments
+    Compare(CompareArgs),
+    /// Delete one or more experiments
+    Delete(DeleteArgs),
+    /// Export an experiment's rows to JSONL or CSV
+    Export(ExportArgs),
+    /// Stream an experiment's raw events as JSONL
+    Events(EventsArgs),
+    /// Log events from a JSONL file, creating the experiment if needed
+    Log(LogArgs),
+    /// Add scores or a comment to a logged event
+    Feedback(FeedbackArgs),
+    /// Browse experiments and their cases in an interactive table
+    Browse,
+    /// Update an experiment's description or metadata
+    Update(UpdateArgs),
+    /// Rank the project's experiments by a score
+    Leaderboard(LeaderboardArgs),
+    /// Fail when an experiment misses score thresholds or regresses from a baseline
+    Check(CheckArgs),
+    /// Show score averages across recent experiments
+    Scores(ScoresArgs),
+    /// Wait until an experiment stops receiving events
+    Wait(WaitArgs),
+    /// Manage experiment tags
+    #[command(subcommand)]
+    Tag(TagCommands),
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum TagCommands {
+    /// Add tags to an experiment
+    Add(TagArgs),
+    /// Remove tags from an experiment
+    Remove(TagArgs),
+    /// List an experiment's tags, or every tag in the project and its experiments
+    List(TagListArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+struct TagArgs {
+    /// Experiment name
+    experiment: String,
+
+    /// Tags to add or remove
+    #[arg(required = true)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct TagListArgs {
+    /// Experiment name (lists all tags when omitted)
+    experiment: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct ListArgs {
+    /// Maximum number of experiments to show, newest first
+    #[arg(long, default_value_t = 50, value_name = "N")]
+    limit: usize,
+
+    /// Only show experiments matching a filter, e.g. 'metadata.model = "gpt-4o"'
+    #[arg(long = "where", value_name = "FILTER", value_parser = WhereClause::parse)]
+    filter: Option<WhereClause>,
+
+    /// Only show experiments created within this long, e.g. 7d
+    #[arg(long, value_name = "AGE", value_parser = parse_age)]
+    since: Option<Duration>,
+
+    /// Only show experiments with this tag
+    #[arg(long)]
+    tag: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct ViewArgs {
+    /// Experiment name (pick interactively when omitted)
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct CompareArgs {
+    /// Baseline experiment
+    base: String,
+
+    /// Experiment to compare against the baseline
+    candidate: String,
+
+    /// Number of regressed and improved cases to show
+    #[arg(long, default_value_t = 10, value_name = "N")]
+    cases: usize,
+
+    /// Exit with an error if any score average regressed
+    #[arg(long)]
+    fail_on_regression: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct DeleteArgs {
+    /// Names of the experiments to delete (pick interactively when omitted)
+    #[arg(conflicts_with_all = ["filter", "older_than"])]
+    names: Vec<String>,
+
+    /// Delete every experiment whose name contains this text or matches this glob
+    #[arg(long, value_name = "PATTERN")]
+    filter: Option<String>,
+
+    /// Treat --filter as a regular expression
+    #[arg(long, requires = "filter")]
+    regex: bool,
+
+    /// Delete every experiment created more than this long ago, e.g. 30d
+    #[arg(long, value_name = "AGE", value_parser = parse_age)]
+    older_than: Option<Duration>,
+
+    /// Skip the confirmation prompt (required when not running interactively)
+    #[arg(long, short = 'y', visible_alias = "force")]
+    yes: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct ExportArgs {
+    /// Experiment name (pick interactively when omitted)
+    name: Option<String>,
+
+    /// File to write (defaults to stdout)
+    #[arg(short = 'o', long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Output format (defaults to the output file's extension, or jsonl)
+    #[arg(long, value_enum)]
+    format: Option<export::ExportFormat>,
+
+    /// Include child spans, not just one row per test case
+    #[arg(long)]
+    all_spans: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct EventsArgs {
+    /// Experiment name (pick interactively when omitted)
+    name: Option<String>,
+
+    /// Stop after this many events
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    limit: Option<u64>,
+
+    /// Resume from a cursor printed by a previous call
+    #[arg(long)]
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct LogArgs {
+    /// Experiment name
+    name: String,
+
+    /// JSONL file of events, one object per line (use `-` for stdin)
+    #[arg(long, short = 'f', value_name = "PATH")]
+    file: PathBuf,
+
+    /// Don't attach git commit, branch, and author metadata to a new experiment
+    #[arg(long)]
+    no_git: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct FeedbackArgs {
+    /// Experiment name
+    name: String,
+
+    /// ID of the event (span) to annotate
+    #[arg(long)]
+    id: String,
+
+    /// Score to record, e.g. accuracy=1 (repeatable)
+    #[arg(long = "score", value_name = "NAME=VALUE", value_parser = feedback::parse_score)]
+    scores: Vec<(String, f64)>,
+
+    /// Free-form comment
+    #[arg(long)]
+    comment: Option<String>,
+
+    /// Corrected expected output (JSON or plain text)
+    #[arg(long)]
+    expected: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct UpdateArgs {
+    /// Experiment name
+    name: String,
+
+    /// Metadata to set, e.g. build=123 (repeatable; JSON values allowed, null removes)
+    #[arg(long, value_name = "KEY=VALUE", value_parser = update::parse_metadata)]
+    metadata: Vec<(String, serde_json::Value)>,
+
+    /// New description
+    #[arg(long)]
+    description: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct LeaderboardArgs {
+    /// Score to rank by, e.g. accuracy
+    #[arg(long)]
+    score: String,
+
+    /// Number of experiments to show
+    #[arg(long, default_value_t = 10, value_name = "N")]
+    top: usize,
+}
+
+#[derive(Debug, Clone, Args)]
+struct CheckArgs {
+    /// Experiment to check
+    candidate: String,
+
+    /// Experiment to compare against, or `latest-main` for the newest run on main
+    #[arg(long, value_name = "NAME")]
+    baseline: Option<String>,
+
+    /// Minimum average for a score, e.g. accuracy=0.9 (repeatable)
+    #[arg(long = "min-score", value_name = "NAME=VALUE", value_parser = feedback::parse_score)]
+    min_scores: Vec<(String, f64)>,
+
+    /// Largest allowed drop in any score average versus the baseline, e.g. 0.02
+    #[arg(long, value_name = "DELTA", requires = "baseline")]
+    max_regression: Option<f64>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct ScoresArgs {
+    /// Number of most recent experiments to include
+    #[arg(long, default_value_t = 10, value_name = "N")]
+    last: usize,
+}
+
+#[derive(Debug, Clone, Args)]
+struct WaitArgs {
+    /// Experiment name
+    name: String,
+
+    /// Give up (with a non-zero exit) after this long, e.g. 30m
+    #[arg(long, default_value = "30m", value_name = "DURATION", value_parser = parse_age)]
+    timeout: Duration,
+
+    /// Treat the experiment as finished once no events arrive for this long
+    #[arg(long, default_value = "1m", value_name = "DURATION", value_parser = parse_age)]
+    idle: Duration,
+
+    /// Time between checks
+    #[arg(long, default_value = "10s", value_name = "DURATION", value_parser = parse_age)]
+    interval: Duration,
+}
+
+impl Default for ListArgs {
+    fn default() -> Self {
+        Self {
+            limit: 50,
+            filter: None,
+            since: None,
+            tag: None,
+        }
+    }
+}
+
+pub async fn run(base: BaseArgs, args: ExperimentsArgs) -> Result<()> {
+    let ctx = login(&base).await?;
+    let client = ApiClient::new(&ctx)?;
+    let project = resolve_project(&client, base.project.as_deref()).await?;
+
+    match args.command {
+        None => list::run(&client, &project, &ListArgs::default(), base.json).await,
+        Some(ExperimentsCommands::List(a)) => list::run(&client, &project, &a, base.json).await,
+        Some(ExperimentsCommands::View(a)) => {
+            let experiment = resolve_experiment(&client, &project, a.name.as_deref()).await?;
+            view::run(&client, &experiment, base.json).await
+        }
+        Some(ExperimentsCommands::Compare(a)) => {
+            let baseline = find_experiment(&client, &project, &a.base).await?;
+            let candidate = find_experiment(&client, &project, &a.candidate).await?;
+            compare::run(
+                &client,
+                &baseline,
+                &candidate,
+                a.cases,
+                a.fail_on_regression,
+                base.json,
+            )
+            .await
+        }
+        Some(ExperimentsCommands::Delete(a)) => {
+            let filter = a
+                .filter
+                .as_deref()
+                .map(|pattern| NameFilter::new(pattern, a.regex))
+                .transpose()?;
+            let selection = delete::Selection {
+                names: &a.names,
+                filter: filter.as_ref(),
+                older_than: a.older_than,
+            };
+            delete::run(&client, &project, selection, a.yes).await
+        }
+        Some(ExperimentsCommands::Export(a)) => {
+            let experiment = resolve_experiment(&client, &project, a.name.as_deref()).await?;
+            let format = a
+                .format
+                .unwrap_or_else(|| export::ExportFormat::for_path(a.output.as_deref()));
+            export::run(
+                &client,
+                &experiment,
+                a.output.as_deref(),
+                format,
+                a.all_spans,
+            )
+            .await
+        }
+        Some(ExperimentsCommands::Events(a)) => {
+            let experiment = resolve_experiment(&client, &project, a.name.as_deref()).await?;
+            let limit = a.limit.map(|n| n as usize);
+            events::run(&client, &experiment, limit, a.cursor).await
+        }
+        Some(ExperimentsCommands::Log(a)) => {
+            log::run(&client, &project, &a.name, &a.file, a.no_git).await
+        }
+        Some(ExperimentsCommands::Browse) => browse::run(&client, &project, &ctx.app_url).await,
+        Some(ExperimentsCommands::Update(a)) => {
+            let experiment = find_experiment(&client, &project, &a.name).await?;
+            update::run(&client, &experiment, &a.metadata, a.description.as_deref()).await
+        }
+        Some(ExperimentsCommands::Leaderboard(a)) => {
+            leaderboard::run(&client, &project, &a.score, a.top, base.json).await
+        }
+        Some(ExperimentsCommands::Check(a)) => {
+            let candidate = find_experiment(&client, &project, &a.candidate).await?;
+            let thresholds = check::Thresholds {
+                min_scores: &a.min_scores,
+                max_regression: a.max_regression,
+            };
+            check::run(
+                &client,
+                &project,
+                &candidate,
+                a.baseline.as_deref(),
+                thresholds,
+                base.json,
+            )
+            .await
+        }
+        Some(ExperimentsCommands::Scores(a)) => {
+            scores::run(&client, &project, a.last, base.json).await
+        }
+        Some(ExperimentsCommands::Wait(a)) => {
+            let experiment = find_experiment(&client, &project, &a.name).await?;
+            let opts = wait::WaitOptions {
+                timeout: a.timeout,
+                idle: a.idle,
+                interval: a.interval,
+            };
+            wait::run(&client, &experiment, opts).await
+        }
+        Some(ExperimentsCommands::Tag(TagCommands::Add(a))) => {
+            let experiment = find_experiment(&client, &project, &a.experiment).await?;
+            tags::add(&client, &experiment, &a.tags).await
+        }
+        Some(ExperimentsCommands::Tag(TagCommands::Remove(a))) => {
+            let experiment = find_experiment(&client, &project, &a.experiment).await?;
+            tags::remove(&client, &experiment, &a.tags).await
+        }
+        Some(ExperimentsCommands::Tag(TagCommands::List(a))) => {
+            let experiment = match a.experiment.as_deref() {
+                Some(name) => Some(find_experiment(&client, &project, name).await?),
+                None => None,
+            };
+            tags::list(&client, &project, experiment.as_ref(), base.json).await
+        }
+        Some(ExperimentsCommands::Feedback(a)) => {
+            let experiment = find_experiment(&client, &project, &a.name).await?;
+            feedback::run(
+                &client,
+                &experiment,
+                &a.id,
+                &a.scores,
+                a.comment.as_deref(),
+                a.expected.as_deref(),
+            )
+            .await
+        }
+    }
+}
+
+async fn find_experiment(
+    client: &ApiClient,
+    project: &Project,
+    name: &str,
+) -> Result<api::Experiment> {
+    with_spinner(
+        "Loading experiment...",
+        api::get_experiment_by_name(client, &project.id, name),
+    )
+    .await?
+    .ok_or_else(|| anyhow!("experiment '{name}' not found in '{}'", project.name))
+}
+
+/// The named experiment, or one picked interactively from the project's experiments.
+async fn resolve_experiment(
+    client: &ApiClient,
+    project: &Project,
+    name: Option<&str>,
+) -> Result<api::Experiment> {
+    if let Some(name) = name {
+        return find_experiment(client, project, name).await;
+    }
+    if !ui::is_interactive() {
+        bail!("experiment name required");
+    }
+    let mut experiments = with_spinner(
+        "Loading experiments...",
+        api::list_experiments(client, &project.id, None),
+    )
+    .await?;
+    if experiments.is_empty() {
+        bail!("no experiments found in '{}'", project.name);
+    }
+    let names: Vec<&str> = experiments.iter().map(|e| e.name.as_str()).collect();
+    let selection = ui::fuzzy_select("Select experiment", &names)?;
+    Ok(experiments.swap_remove(selection))
+}
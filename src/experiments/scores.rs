@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use dialoguer::console;
+use unicode_width::UnicodeWidthStr;
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::with_spinner;
+
+use super::api::{self, Summary};
+
+/// Print the score averages of the last `last` experiments, oldest first, one row each.
+pub async fn run(client: &ApiClient, project: &Project, last: usize, json: bool) -> Result<()> {
+    let rows = with_spinner("Loading scores...", async {
+        let experiments = api::list_experiments(client, &project.id, Some(last)).await?;
+        let summaries = api::summarize_all(client, &experiments).await?;
+        let rows: Vec<_> = experiments
+            .into_iter()
+            .map(|experiment| experiment.name)
+            .zip(summaries)
+            .rev()
+            .collect();
+        anyhow::Ok(rows)
+    })
+    .await?;
+    let (names, matrix) = score_matrix(&rows);
+
+    if json {
+        let value: Vec<_> = rows
+            .iter()
+            .zip(&matrix)
+            .map(|((experiment, _), scores)| {
+                let scores: BTreeMap<&str, Option<f64>> = names
+                    .iter()
+                    .map(String::as_str)
+                    .zip(scores.clone())
+                    .collect();
+                serde_json::json!({ "experiment": experiment, "scores": scores })
+            })
+            .collect();
+        println!("{}", serde_json::to_string(&value)?);
+        return Ok(());
+    }
+    if names.is_empty() {
+        println!(
+            "No scores found in {}",
+            console::style(&project.name).bold()
+        );
+        return Ok(());
+    }
+
+    let name_width = rows
+        .iter()
+        .map(|(name, _)| name.width())
+        .max()
+        .unwrap_or(0)
+        .max(10);
+    let widths: Vec<usize> = names.iter().map(|n| n.width().max(7)).collect();
+    let header: String = names
+        .iter()
+        .zip(&widths)
+        .map(|(name, width)| format!("  {name:>width$}"))
+        .collect();
+    println!(
+        "{}",
+        console::style(format!("{:name_width$}{header}", "Experiment"))
+            .dim()
+            .bold()
+    );
+    for ((experiment, _), scores) in rows.iter().zip(&matrix) {
+        let padding = name_width - experiment.width();
+        let cells: String = scores
+            .iter()
+            .zip(&widths)
+            .map(|(score, width)| match score {
+                Some(score) => format!("  {:>width$}", format!("{:.1}%", score * 100.0)),
+                None => format!("  {:>width$}", "-"),
+            })
+            .collect();
+        println!("{experiment}{:padding$}{cells}", "");
+    }
+    Ok(())
+}
+
+/// Sorted score names and, per experiment, the average for each name (if it has one).
+fn score_matrix(rows: &[(String, Option<Summary>)]) -> (Vec<String>, Vec<Vec<Option<f64>>>) {
+    let mut names: Vec<String> = rows
+        .iter()
+        .filter_map(|(_, summary)| summary.as_ref())
+        .flat_map(|summary| summary.scores.keys().cloned())
+        .collect();
+    names.sort();
+    names.dedup();
+    let matrix = rows
+        .iter()
+        .map(|(_, summary)| {
+            names
+                .iter()
+                .map(|name| summary.as_ref()?.scores.get(name).map(|s| s.score))
+                .collect()
+        })
+        .collect();
+    (names, matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn score_matrix_fills_missing_scores() {
+        let summary = |scores: serde_json::Value| -> Option<Summary> {
+            serde_json::from_value(json!({
+                "project_name": "p",
+                "experiment_name": "e",
+                "scores": scores,
+            }))
+            .ok()
+        };
+        let rows = vec![
+            (
+                "a".to_string(),
+                summary(json!({ "accuracy": { "name": "accuracy", "score": 0.5 } })),
+            ),
+            (
+                "b".to_string(),
+                summary(json!({ "tone": { "name": "tone", "score": 1.0 } })),
+            ),
+            ("c".to_string(), None),
+        ];
+        let (names, matrix) = score_matrix(&rows);
+        assert_eq!(names, vec!["accuracy", "tone"]);
+        assert_eq!(
+            matrix,
+            vec![
+                vec![Some(0.5), None],
+                vec![None, Some(1.0)],
+                vec![None, None]
+            ]
+        );
+    }
+}
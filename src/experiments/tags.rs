@@ -0,0 +1,91 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use dialoguer::console;
+use serde_json::json;
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::projects::tags::validate_tag;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Experiment};
+
+pub async fn add(client: &ApiClient, experiment: &Experiment, tags: &[String]) -> Result<()> {
+    tags.iter().try_for_each(|tag| validate_tag(tag))?;
+    let mut updated = current(experiment);
+    updated.extend(tags.iter().cloned());
+    save(client, experiment, updated).await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Tagged '{}' with {}", experiment.name, tags.join(", ")),
+    );
+    Ok(())
+}
+
+pub async fn remove(client: &ApiClient, experiment: &Experiment, tags: &[String]) -> Result<()> {
+    let mut updated = current(experiment);
+    for tag in tags {
+        updated.remove(tag);
+    }
+    save(client, experiment, updated).await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Removed {} from '{}'", tags.join(", "), experiment.name),
+    );
+    Ok(())
+}
+
+fn current(experiment: &Experiment) -> BTreeSet<String> {
+    experiment.tags.iter().flatten().cloned().collect()
+}
+
+async fn save(client: &ApiClient, experiment: &Experiment, tags: BTreeSet<String>) -> Result<()> {
+    let patch = json!({ "tags": tags });
+    with_spinner(
+        "Updating tags...",
+        api::update_experiment(client, &experiment.id, &patch),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Print an experiment's tags, or every tag in the project with the experiments carrying it.
+pub async fn list(
+    client: &ApiClient,
+    project: &Project,
+    experiment: Option<&Experiment>,
+    json: bool,
+) -> Result<()> {
+    if let Some(experiment) = experiment {
+        let tags: Vec<String> = current(experiment).into_iter().collect();
+        if json {
+            println!("{}", serde_json::to_string(&tags)?);
+        } else {
+            for tag in tags {
+                println!("{tag}");
+            }
+        }
+        return Ok(());
+    }
+
+    let experiments = with_spinner(
+        "Loading experiments...",
+        api::list_experiments(client, &project.id, None),
+    )
+    .await?;
+    let mut by_tag: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for experiment in &experiments {
+        for tag in current(experiment) {
+            by_tag.entry(tag).or_default().push(experiment.name.clone());
+        }
+    }
+    if json {
+        println!("{}", serde_json::to_string(&by_tag)?);
+    } else {
+        for (tag, names) in &by_tag {
+            println!("{}  {}", console::style(tag).bold(), names.join(", "));
+        }
+    }
+    Ok(())
+}
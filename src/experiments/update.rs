@@ -0,0 +1,85 @@
+use anyhow::{bail, Result};
+use serde_json::{json, Map, Value};
+
+use crate::http::ApiClient;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Experiment};
+
+/// Parse a `--metadata key=value` argument; the value is JSON when it parses, otherwise a string.
+pub fn parse_metadata(raw: &str) -> Result<(String, Value), String> {
+    let (key, value) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{raw}'"))?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(format!("missing metadata key in '{raw}'"));
+    }
+    let value = serde_json::from_str(value).unwrap_or_else(|_| json!(value));
+    Ok((key.to_string(), value))
+}
+
+/// Merge `entries` into existing metadata; a `null` value removes the key.
+fn merge_metadata(existing: Option<&Value>, entries: &[(String, Value)]) -> Map<String, Value> {
+    let mut metadata = existing
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+    for (key, value) in entries {
+        match value {
+            Value::Null => metadata.remove(key),
+            value => metadata.insert(key.clone(), value.clone()),
+        };
+    }
+    metadata
+}
+
+pub async fn run(
+    client: &ApiClient,
+    experiment: &Experiment,
+    metadata: &[(String, Value)],
+    description: Option<&str>,
+) -> Result<()> {
+    if metadata.is_empty() && description.is_none() {
+        bail!("nothing to update; pass --metadata or --description");
+    }
+
+    let mut patch = json!({});
+    if !metadata.is_empty() {
+        patch["metadata"] = Value::Object(merge_metadata(experiment.metadata.as_ref(), metadata));
+    }
+    if let Some(description) = description {
+        patch["description"] = json!(description);
+    }
+
+    with_spinner(
+        "Updating experiment...",
+        api::update_experiment(client, &experiment.id, &patch),
+    )
+    .await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Updated '{}'", experiment.name),
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_metadata_parses_values_and_removes_null() {
+        let existing = json!({ "build": 1, "branch": "main" });
+        let entries = [
+            parse_metadata("build=42").unwrap(),
+            parse_metadata("ticket=https://example.com/T-1").unwrap(),
+            parse_metadata("branch=null").unwrap(),
+        ];
+        assert_eq!(
+            Value::Object(merge_metadata(Some(&existing), &entries)),
+            json!({ "build": 42, "ticket": "https://example.com/T-1" })
+        );
+        assert!(parse_metadata("build").is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use anyhow::Result;
+use dialoguer::console;
+use serde_json::{json, Value};
+
+use crate::http::ApiClient;
+use crate::sql::{query_rows, quote_literal};
+use crate::ui::with_spinner;
+
+use super::api::{self, Experiment, Summary};
+use super::list::format_created;
+
+pub async fn run(client: &ApiClient, experiment: &Experiment, json: bool) -> Result<()> {
+    let (summary, duration) = with_spinner("Summarizing experiment...", async {
+        let summary = api::summarize(client, &experiment.id, None).await?;
+        // Duration is informational; an experiment without spans simply has none.
+        let duration = span_duration(client, &experiment.id).await.ok().flatten();
+        anyhow::Ok((summary, duration))
+    })
+    .await?;
+    let dataset = match experiment.dataset_id.as_deref() {
+        Some(id) => Some(
+            api::get_dataset_name(client, id)
+                .await
+                .unwrap_or_else(|_| id.to_string()),
+        ),
+        None => None,
+    };
+
+    if json {
+        let mut value = serde_json::to_value(experiment)?;
+        value["summary"] = serde_json::to_value(&summary)?;
+        value["duration_seconds"] = json!(duration);
+        value["dataset_name"] = json!(dataset);
+        println!("{}", serde_json::to_string(&value)?);
+        return Ok(());
+    }
+
+    let mut rows = vec![
+        ("Name".to_string(), experiment.name.clone()),
+        ("ID".to_string(), experiment.id.clone()),
+        (
+            "Created".to_string(),
+            format_created(experiment.created.as_deref()),
+        ),
+        (
+            "Description".to_string(),
+            or_dash(experiment.description.as_deref()),
+        ),
+        ("Dataset".to_string(), or_dash(dataset.as_deref())),
+        (
+            "Duration".to_string(),
+            duration.map_or_else(|| "-".to_string(), format_duration),
+        ),
+    ];
+    rows.extend(git_rows(experiment.repo_info.as_ref()));
+    rows.extend(object_rows("metadata", experiment.metadata.as_ref()));
+    if let Some(url) = &summary.experiment_url {
+        rows.push(("URL".to_string(), url.clone()));
+    }
+
+    for (label, value) in &rows {
+        println!(
+            "{}  {value}",
+            console::style(format!("{label:16}")).dim().bold()
+        );
+    }
+    print_scores(&summary);
+    Ok(())
+}
+
+fn print_scores(summary: &Summary) {
+    if summary.scores.is_empty() && summary.metrics.is_empty() {
+        return;
+    }
+    let baseline = summary
+        .comparison_experiment_name
+        .as_deref()
+        .map(|name| format!(" (vs {name})"))
+        .unwrap_or_default();
+    println!("\n{}", console::style(format!("Scores{baseline}")).bold());
+    for score in summary.scores.values() {
+        println!(
+            "  {:24} {:>7.2}%{}",
+            score.name,
+            score.score * 100.0,
+            change(
+                score.diff.map(|d| d * 100.0),
+                "%",
+                score.improvements,
+                score.regressions
+            )
+        );
+    }
+    for metric in summary.metrics.values() {
+        println!(
+            "  {:24} {:>7.2}{}{}",
+            metric.name,
+            metric.metric,
+            metric.unit,
+            change(
+                metric.diff,
+                &metric.unit,
+                metric.improvements,
+                metric.regressions
+            )
+        );
+    }
+}
+
+/// ` (+1.20%, 3 improvements, 1 regression)` or nothing without a comparison.
+fn change(
+    diff: Option<f64>,
+    unit: &str,
+    improvements: Option<u64>,
+    regressions: Option<u64>,
+) -> String {
+    let Some(diff) = diff else {
+        return String::new();
+    };
+    let plural = |n: u64, word: &str| format!("{n} {word}{}", if n == 1 { "" } else { "s" });
+    let styled = format!("{diff:+.2}{unit}");
+    let styled = if diff > 0.0 {
+        console::style(styled).green()
+    } else if diff < 0.0 {
+        console::style(styled).red()
+    } else {
+        console::style(styled).dim()
+    };
+    format!(
+        "  ({styled}, {}, {})",
+        plural(improvements.unwrap_or(0), "improvement"),
+        plural(regressions.unwrap_or(0), "regression")
+    )
+}
+
+fn git_rows(repo_info: Option<&Value>) -> Vec<(String, String)> {
+    let Some(info) = repo_info.and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    let fields = [
+        ("Commit", "commit"),
+        ("Branch", "branch"),
+        ("Author", "author_name"),
+        ("Message", "commit_message"),
+        ("Dirty", "dirty"),
+    ];
+    fields
+        .iter()
+        .filter_map(|(label, key)| {
+            let value = info.get(*key).filter(|v| !v.is_null())?;
+            let text = match value {
+                Value::String(s) => s.lines().next().unwrap_or_default().to_string(),
+                other => other.to_string(),
+            };
+            Some((format!("Git {}", label.to_lowercase()), text))
+        })
+        .collect()
+}
+
+fn object_rows(prefix: &str, value: Option<&Value>) -> Vec<(String, String)> {
+    let Some(map) = value.and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    map.iter()
+        .map(|(key, value)| {
+            let text = match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            (format!("{prefix}.{key}"), text)
+        })
+        .collect()
+}
+
+/// Wall-clock seconds from the first span's start to the last span's end.
+async fn span_duration(client: &ApiClient, experiment_id: &str) -> Result<Option<f64>> {
+    let query = format!(
+        "SELECT MIN(metrics.start) AS started, MAX(metrics.end) AS ended FROM experiment({})",
+        quote_literal(experiment_id)
+    );
+    let rows = query_rows(client, &query).await?;
+    let row = rows.first();
+    let get = |key: &str| row.and_then(|r| r.get(key)).and_then(Value::as_f64);
+    Ok(match (get("started"), get("ended")) {
+        (Some(started), Some(ended)) if ended >= started => Some(ended - started),
+        _ => None,
+    })
+}
+
+pub fn format_duration(seconds: f64) -> String {
+    let total = seconds.round() as u64;
+    match total {
+        0..=59 => format!("{seconds:.1}s"),
+        60..=3599 => format!("{}m {}s", total / 60, total % 60),
+        _ => format!("{}h {}m", total / 3600, (total % 3600) / 60),
+    }
+}
+
+fn or_dash(value: Option<&str>) -> String {
+    value.filter(|s| !s.is_empty()).unwrap_or("-").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_durations_and_git_info() {
+        assert_eq!(format_duration(4.25), "4.2s");
+        assert_eq!(format_duration(125.0), "2m 5s");
+        assert_eq!(format_duration(7260.0), "2h 1m");
+
+        let info = json!({ "commit": "abc123", "branch": "main", "dirty": false, "tag": null });
+        assert_eq!(
+            git_rows(Some(&info)),
+            vec![
+                ("Git commit".to_string(), "abc123".to_string()),
+                ("Git branch".to_string(), "main".to_string()),
+                ("Git dirty".to_string(), "false".to_string()),
+            ]
+        );
+    }
+}
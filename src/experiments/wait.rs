@@ -0,0 +1,104 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use dialoguer::console;
+use serde_json::Value;
+
+use crate::http::ApiClient;
+use crate::sql::{query_rows, quote_literal};
+use crate::ui::{print_command_status, CommandStatus};
+
+use super::api::Experiment;
+
+pub struct WaitOptions {
+    pub timeout: Duration,
+    pub idle: Duration,
+    pub interval: Duration,
+}
+
+/// Tracks the event count between polls to decide when an experiment has finished.
+struct Tracker {
+    events: u64,
+    changed_at: Instant,
+}
+
+impl Tracker {
+    fn new(now: Instant) -> Self {
+        Self {
+            events: 0,
+            changed_at: now,
+        }
+    }
+
+    /// Record a poll; true once there are events and none arrived for `idle`.
+    fn observe(&mut self, events: u64, now: Instant, idle: Duration) -> bool {
+        if events != self.events {
+            self.events = events;
+            self.changed_at = now;
+        }
+        self.events > 0 && now.duration_since(self.changed_at) >= idle
+    }
+}
+
+/// Poll until the experiment stops receiving events for `idle`, or fail after `timeout`.
+pub async fn run(client: &ApiClient, experiment: &Experiment, opts: WaitOptions) -> Result<()> {
+    let query = format!(
+        "SELECT COUNT(1) AS events FROM experiment({})",
+        quote_literal(&experiment.id)
+    );
+    let started = Instant::now();
+    let mut tracker = Tracker::new(started);
+    eprintln!(
+        "{}",
+        console::style(format!(
+            "Waiting for '{}' to finish (no new events for {})",
+            experiment.name,
+            humantime::format_duration(opts.idle)
+        ))
+        .dim()
+    );
+
+    loop {
+        let rows = query_rows(client, &query).await?;
+        let events = rows
+            .first()
+            .and_then(|row| row.get("events"))
+            .and_then(Value::as_u64)
+            .unwrap_or(0);
+        let now = Instant::now();
+        if tracker.observe(events, now, opts.idle) {
+            print_command_status(
+                CommandStatus::Success,
+                &format!("'{}' finished with {events} events", experiment.name),
+            );
+            return Ok(());
+        }
+        if now.duration_since(started) >= opts.timeout {
+            bail!(
+                "timed out after {} waiting for '{}' ({events} events so far)",
+                humantime::format_duration(opts.timeout),
+                experiment.name
+            );
+        }
+        tokio::time::sleep(opts.interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracker_settles_after_idle_period_with_events() {
+        let start = Instant::now();
+        let idle = Duration::from_secs(30);
+        let at = |secs| start + Duration::from_secs(secs);
+        let mut tracker = Tracker::new(start);
+
+        assert!(!tracker.observe(0, at(60), idle));
+        assert!(!tracker.observe(10, at(70), idle));
+        assert!(!tracker.observe(20, at(90), idle));
+        assert!(!tracker.observe(20, at(110), idle));
+        assert!(tracker.observe(20, at(120), idle));
+    }
+}
@@ -0,0 +1,490 @@
+use std::cmp::Ordering;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use regex::{Regex, RegexBuilder};
+use serde_json::Value;
+
+use crate::sql::quote_literal;
+
+/// Case-insensitive name filter: a substring, a glob (`*`, `?`), or a regex.
+pub struct NameFilter(Regex);
+
+impl NameFilter {
+    pub fn new(pattern: &str, regex: bool) -> Result<Self> {
+        let source = if regex {
+            pattern.to_string()
+        } else if pattern.contains(['*', '?']) {
+            let escaped = regex::escape(pattern)
+                .replace(r"\*", ".*")
+                .replace(r"\?", ".");
+            format!("^{escaped}$")
+        } else {
+            regex::escape(pattern)
+        };
+        let re = RegexBuilder::new(&source)
+            .case_insensitive(true)
+            .build()
+            .with_context(|| format!("invalid filter '{pattern}'"))?;
+        Ok(Self(re))
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        self.0.is_match(name)
+    }
+}
+
+/// Parse an age such as `30d`, `12h`, or `2w` for clap.
+pub fn parse_age(raw: &str) -> Result<Duration, String> {
+    humantime::parse_duration(raw).map_err(|err| format!("invalid age '{raw}': {err}"))
+}
+
+/// True when an RFC 3339 `created` timestamp is more than `age` in the past.
+/// Timestamps that can't be parsed never match.
+pub fn is_older_than(created: Option<&str>, age: Duration) -> bool {
+    let Some(created) = created.and_then(parse_timestamp) else {
+        return false;
+    };
+    SystemTime::now()
+        .duration_since(created)
+        .is_ok_and(|elapsed| elapsed > age)
+}
+
+/// True when an RFC 3339 `created` timestamp is within the last `age`.
+pub fn is_within(created: Option<&str>, age: Duration) -> bool {
+    let Some(created) = created.and_then(parse_timestamp) else {
+        return false;
+    };
+    SystemTime::now()
+        .duration_since(created)
+        .map_or(true, |elapsed| elapsed <= age)
+}
+
+pub fn parse_timestamp(raw: &str) -> Option<SystemTime> {
+    humantime::parse_rfc3339_weak(raw.trim_end_matches('Z'))
+        .ok()
+        .or_else(|| humantime::parse_rfc3339(raw).ok())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Condition {
+    path: Vec<String>,
+    op: Op,
+    value: Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Condition(Condition),
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+}
+
+/// A BTQL-style filter such as `metadata.model = "gpt-4o" AND (score < 0.5 OR error IS NOT NULL)`,
+/// evaluated against JSON objects or rendered as a BTQL predicate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhereClause(Expr);
+
+impl WhereClause {
+    /// Parse a clause for clap.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let tokens = tokenize(raw)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            pos: 0,
+            raw,
+        };
+        let expr = parser.parse_or()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(Self(expr)),
+            Some(token) => Err(format!("unexpected {token} in '{raw}'")),
+        }
+    }
+
+    /// Render the clause as a BTQL predicate for server-side filtering.
+    pub fn to_btql(&self) -> String {
+        btql(&self.0)
+    }
+
+    pub fn matches(&self, object: &Value) -> bool {
+        eval(&self.0, object)
+    }
+}
+
+fn btql(expr: &Expr) -> String {
+    match expr {
+        Expr::Condition(condition) => condition_btql(condition),
+        Expr::And(exprs) => exprs.iter().map(btql).collect::<Vec<_>>().join(" AND "),
+        Expr::Or(exprs) => format!(
+            "({})",
+            exprs.iter().map(btql).collect::<Vec<_>>().join(" OR ")
+        ),
+    }
+}
+
+fn condition_btql(condition: &Condition) -> String {
+    let field = condition
+        .path
+        .iter()
+        .map(|key| btql_ident(key))
+        .collect::<Vec<_>>()
+        .join(".");
+    let op = match condition.op {
+        Op::Eq if condition.value.is_null() => return format!("{field} IS NULL"),
+        Op::Ne if condition.value.is_null() => return format!("{field} IS NOT NULL"),
+        Op::Eq => "=",
+        Op::Ne => "!=",
+        Op::Lt => "<",
+        Op::Le => "<=",
+        Op::Gt => ">",
+        Op::Ge => ">=",
+    };
+    let value = match &condition.value {
+        Value::String(s) => quote_literal(s),
+        other => other.to_string(),
+    };
+    format!("{field} {op} {value}")
+}
+
+fn eval(expr: &Expr, object: &Value) -> bool {
+    match expr {
+        Expr::Condition(condition) => {
+            let actual = condition
+                .path
+                .iter()
+                .try_fold(object, |value, key| value.get(key))
+                .unwrap_or(&Value::Null);
+            let ordering = compare(actual, &condition.value);
+            match condition.op {
+                Op::Eq => ordering == Some(Ordering::Equal),
+                Op::Ne => ordering != Some(Ordering::Equal),
+                Op::Lt => ordering == Some(Ordering::Less),
+                Op::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+                Op::Gt => ordering == Some(Ordering::Greater),
+                Op::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+            }
+        }
+        Expr::And(exprs) => exprs.iter().all(|expr| eval(expr, object)),
+        Expr::Or(exprs) => exprs.iter().any(|expr| eval(expr, object)),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    Op(Op),
+    /// A bare word: a field path, keyword, number, or unquoted value.
+    Word(String),
+    /// A single- or double-quoted string.
+    Quoted(String),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::LParen => write!(f, "'('"),
+            Token::RParen => write!(f, "')'"),
+            Token::Op(_) => write!(f, "operator"),
+            Token::Word(word) => write!(f, "'{word}'"),
+            Token::Quoted(text) => write!(f, "string '{text}'"),
+        }
+    }
+}
+
+fn tokenize(raw: &str) -> Result<Vec<Token>, String> {
+    const OPS: [(&str, Op); 7] = [
+        ("!=", Op::Ne),
+        ("<>", Op::Ne),
+        (">=", Op::Ge),
+        ("<=", Op::Le),
+        ("=", Op::Eq),
+        (">", Op::Gt),
+        ("<", Op::Lt),
+    ];
+    let mut tokens = Vec::new();
+    let mut rest = raw.trim_start();
+    while let Some(ch) = rest.chars().next() {
+        if ch == '(' || ch == ')' {
+            tokens.push(if ch == '(' {
+                Token::LParen
+            } else {
+                Token::RParen
+            });
+            rest = &rest[1..];
+        } else if ch == '\'' {
+            // SQL-style: a doubled quote stands for one quote.
+            let mut text = String::new();
+            let mut chars = rest[1..].char_indices();
+            let end = loop {
+                match chars.next() {
+                    Some((idx, '\'')) if rest[1 + idx + 1..].starts_with('\'') => {
+                        text.push('\'');
+                        chars.next();
+                    }
+                    Some((idx, '\'')) => break 1 + idx + 1,
+                    Some((_, c)) => text.push(c),
+                    None => return Err(format!("unterminated string in '{raw}'")),
+                }
+            };
+            tokens.push(Token::Quoted(text));
+            rest = &rest[end..];
+        } else if ch == '"' {
+            let mut escaped = false;
+            let end = rest[1..]
+                .char_indices()
+                .find(|&(_, c)| {
+                    let close = c == '"' && !escaped;
+                    escaped = c == '\\' && !escaped;
+                    close
+                })
+                .map(|(idx, _)| 1 + idx + 1)
+                .ok_or_else(|| format!("unterminated string in '{raw}'"))?;
+            let text = serde_json::from_str(&rest[..end])
+                .map_err(|err| format!("invalid string in '{raw}': {err}"))?;
+            tokens.push(Token::Quoted(text));
+            rest = &rest[end..];
+        } else if let Some((token, op)) = OPS.iter().find(|(token, _)| rest.starts_with(token)) {
+            tokens.push(Token::Op(*op));
+            rest = &rest[token.len()..];
+        } else {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || "()'\"=!<>".contains(c))
+                .unwrap_or(rest.len());
+            if end == 0 {
+                return Err(format!("unexpected '{ch}' in '{raw}'"));
+            }
+            tokens.push(Token::Word(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+    }
+    Ok(tokens)
+}
+
+/// Recursive-descent parser where AND binds tighter than OR.
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    raw: &'a str,
+}
+
+impl Parser<'_> {
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.tokens.get(self.pos), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), String> {
+        if !self.peek_keyword(keyword) {
+            return Err(format!(
+                "expected {} in '{}'",
+                keyword.to_ascii_uppercase(),
+                self.raw
+            ));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, String> {
+        let mut exprs = vec![self.parse_and()?];
+        while self.peek_keyword("or") {
+            self.pos += 1;
+            exprs.push(self.parse_and()?);
+        }
+        Ok(if exprs.len() == 1 {
+            exprs.remove(0)
+        } else {
+            Expr::Or(exprs)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, String> {
+        let mut exprs = vec![self.parse_atom()?];
+        while self.peek_keyword("and") {
+            self.pos += 1;
+            exprs.push(self.parse_atom()?);
+        }
+        Ok(if exprs.len() == 1 {
+            exprs.remove(0)
+        } else {
+            Expr::And(exprs)
+        })
+    }
+
+    fn parse_atom(&mut self) -> Result<Expr, String> {
+        let raw = self.raw;
+        let field = match self.tokens.get(self.pos) {
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let expr = self.parse_or()?;
+                if self.tokens.get(self.pos) != Some(&Token::RParen) {
+                    return Err(format!("missing ')' in '{raw}'"));
+                }
+                self.pos += 1;
+                return Ok(expr);
+            }
+            Some(Token::Word(field)) => field,
+            _ => {
+                return Err(format!(
+                    "expected a comparison such as field = value, got '{raw}'"
+                ))
+            }
+        };
+        if field.split('.').any(str::is_empty) {
+            return Err(format!("invalid field name in '{raw}'"));
+        }
+        let path = field.split('.').map(str::to_string).collect();
+        self.pos += 1;
+
+        if self.peek_keyword("is") {
+            self.pos += 1;
+            let negated = self.peek_keyword("not");
+            if negated {
+                self.pos += 1;
+            }
+            self.expect_keyword("null")?;
+            return Ok(Expr::Condition(Condition {
+                path,
+                op: if negated { Op::Ne } else { Op::Eq },
+                value: Value::Null,
+            }));
+        }
+
+        let Some(Token::Op(op)) = self.tokens.get(self.pos) else {
+            return Err(format!(
+                "expected a comparison such as field = value, got '{raw}'"
+            ));
+        };
+        let value = match self.tokens.get(self.pos + 1) {
+            Some(Token::Quoted(text)) => Value::String(text.clone()),
+            Some(Token::Word(word)) => parse_literal(word),
+            _ => return Err(format!("missing value in '{raw}'")),
+        };
+        self.pos += 2;
+        Ok(Expr::Condition(Condition {
+            path,
+            op: *op,
+            value,
+        }))
+    }
+}
+
+/// A field name as a BTQL identifier, backtick-quoted unless it is a plain word.
+fn btql_ident(key: &str) -> String {
+    let plain = key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        key.to_string()
+    } else {
+        format!("`{}`", key.replace('`', "``"))
+    }
+}
+
+/// A JSON number, boolean, or null, or else the bare word as a string.
+fn parse_literal(raw: &str) -> Value {
+    serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))
+}
+
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (a, b) if a == b => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_filter_supports_substring_glob_and_regex() {
+        let substring = NameFilter::new("Prod", false).unwrap();
+        assert!(substring.matches("my-production-app"));
+        assert!(!substring.matches("staging"));
+
+        let glob = NameFilter::new("eval-*-v?", false).unwrap();
+        assert!(glob.matches("Eval-summary-v2"));
+        assert!(!glob.matches("eval-summary-v10"));
+
+        let regex = NameFilter::new(r"^team-(a|b)$", true).unwrap();
+        assert!(regex.matches("team-b"));
+        assert!(!regex.matches("team-c"));
+        assert!(NameFilter::new("(", true).is_err());
+    }
+
+    #[test]
+    fn ages_compare_against_created_timestamps() {
+        let day = parse_age("1d").unwrap();
+        assert_eq!(day, Duration::from_secs(86_400));
+        assert!(parse_age("soon").is_err());
+
+        assert!(is_older_than(Some("2020-01-01T00:00:00.123Z"), day));
+        assert!(!is_older_than(Some("not a date"), day));
+        assert!(!is_older_than(None, day));
+        assert!(!is_within(Some("2020-01-01T00:00:00Z"), day));
+    }
+
+    #[test]
+    fn where_clause_compares_nested_fields() {
+        let experiment = serde_json::json!({
+            "name": "run-1",
+            "metadata": { "model": "gpt-4o", "temperature": 0.2 },
+        });
+        let matches = |raw: &str| WhereClause::parse(raw).unwrap().matches(&experiment);
+        assert!(matches(r#"metadata.model = "gpt-4o""#));
+        assert!(matches(
+            "metadata.model = 'gpt-4o' AND metadata.temperature <= 0.5"
+        ));
+        assert!(matches("metadata.missing != 1"));
+        assert!(!matches("metadata.temperature > 0.5"));
+        assert!(!matches("metadata.model >= 1"));
+        assert!(WhereClause::parse("metadata.model").is_err());
+        assert!(matches("metadata.missing IS NULL AND name IS NOT NULL"));
+    }
+
+    #[test]
+    fn where_clause_renders_as_btql() {
+        let clause = WhereClause::parse(
+            "metrics.tokens > 1000 and metadata.user-id = 'o''neil' AND error is not null",
+        )
+        .unwrap();
+        assert_eq!(
+            clause.to_btql(),
+            "metrics.tokens > 1000 AND metadata.`user-id` = 'o''neil' AND error IS NOT NULL"
+        );
+    }
+
+    #[test]
+    fn where_clause_handles_quotes_or_and_parentheses() {
+        let clause =
+            WhereClause::parse(r#"input = 'salt and pepper' AND (tag = "a or b" OR score < 0.5)"#)
+                .unwrap();
+        assert_eq!(
+            clause.to_btql(),
+            "input = 'salt and pepper' AND (tag = 'a or b' OR score < 0.5)"
+        );
+        let row = |tag: &str, score: f64| serde_json::json!({ "input": "salt and pepper", "tag": tag, "score": score });
+        assert!(clause.matches(&row("a or b", 0.9)));
+        assert!(clause.matches(&row("c", 0.1)));
+        assert!(!clause.matches(&row("c", 0.9)));
+
+        let or = WhereClause::parse("a = 1 or b = 2").unwrap();
+        assert_eq!(or.to_btql(), "(a = 1 OR b = 2)");
+        assert!(WhereClause::parse("(a = 1").is_err());
+        assert!(WhereClause::parse("a = 'open").is_err());
+        assert!(WhereClause::parse("a = 1 b = 2").is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::Result;
+use reqwest::Response;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use urlencoding::encode;
+
+use crate::http::ApiClient;
+use crate::projects::api::ObjectList;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Function {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub project_id: String,
+    #[serde(default)]
+    pub function_type: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default)]
+    pub function_data: Option<Value>,
+    /// The prompt behind prompt-based functions such as LLM scorers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_data: Option<Value>,
+    /// Transaction id of this version of the function.
+    #[serde(default, rename = "_xact_id", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+impl Function {
+    /// The function's role (`scorer`, `tool`, `task`, `llm`), or how it is implemented when untyped.
+    pub fn kind(&self) -> &str {
+        self.function_type.as_deref().unwrap_or_else(|| {
+            self.function_data
+                .as_ref()
+                .and_then(|data| data.get("type"))
+                .and_then(Value::as_str)
+                .unwrap_or("function")
+        })
+    }
+}
+
+pub async fn list_functions(client: &ApiClient, project_id: &str) -> Result<Vec<Function>> {
+    let path = format!("/v1/function?project_id={}", encode(project_id));
+    let list: ObjectList<Function> = client.get(&path).await?;
+    Ok(list.objects)
+}
+
+pub async fn get_function_by_slug(
+    client: &ApiClient,
+    project_id: &str,
+    slug: &str,
+) -> Result<Option<Function>> {
+    let path = format!(
+        "/v1/function?project_id={}&slug={}",
+        encode(project_id),
+        encode(slug)
+    );
+    let list: ObjectList<Function> = client.get(&path).await?;
+    Ok(list.objects.into_iter().next())
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CodeUpload {
+    pub url: String,
+    #[serde(rename = "bundleId")]
+    pub bundle_id: String,
+}
+
+/// Reserve a presigned URL to upload a code bundle to.
+pub async fn request_code_upload(
+    client: &ApiClient,
+    org_id: &str,
+    runtime_context: &Value,
+) -> Result<CodeUpload> {
+    let body = serde_json::json!({ "org_id": org_id, "runtime_context": runtime_context });
+    client.post("/function/code", &body).await
+}
+
+/// Create the function, or replace the one with the same slug in the project.
+pub async fn upsert_function(client: &ApiClient, body: &Value) -> Result<Function> {
+    client.put("/v1/function", body).await
+}
+
+/// Invoke a function (prompts are functions too) with `input`, returning its output.
+pub async fn invoke(client: &ApiClient, function_id: &str, input: &Value) -> Result<Value> {
+    let path = format!("/v1/function/{}/invoke", encode(function_id));
+    client
+        .post(&path, &serde_json::json!({ "input": input }))
+        .await
+}
+
+/// Like `invoke`, but asks for the output as a server-sent event stream.
+pub async fn invoke_stream(
+    client: &ApiClient,
+    function_id: &str,
+    input: &Value,
+) -> Result<Response> {
+    let path = format!("/v1/function/{}/invoke", encode(function_id));
+    client
+        .post_stream(
+            &path,
+            &serde_json::json!({ "input": input, "stream": true }),
+        )
+        .await
+}
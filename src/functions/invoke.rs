@@ -0,0 +1,174 @@
+use std::io::Write;
+
+use anyhow::{bail, Context, Result};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Response;
+use serde_json::Value;
+
+use crate::http::ApiClient;
+use crate::ui::with_spinner;
+
+use super::api;
+
+/// Parse an `--input` argument as JSON, treating anything that isn't JSON as a string.
+pub fn parse_input(raw: &str) -> Result<Value, String> {
+    Ok(serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string())))
+}
+
+/// Invoke a function and print its output, streaming it when `stream` is set and `json` isn't.
+pub(crate) async fn invoke_and_print(
+    client: &ApiClient,
+    function_id: &str,
+    label: &str,
+    input: &Value,
+    stream: bool,
+    json: bool,
+) -> Result<()> {
+    if stream && !json {
+        let response = api::invoke_stream(client, function_id, input).await?;
+        return print_stream(response).await;
+    }
+
+    let output = with_spinner(label, api::invoke(client, function_id, input)).await?;
+    if json {
+        println!("{}", serde_json::to_string(&output)?);
+        return Ok(());
+    }
+    print_output(&output)
+}
+
+fn print_output(output: &Value) -> Result<()> {
+    println!("{}", output_text(output)?);
+    Ok(())
+}
+
+/// Strings as-is; anything else as pretty JSON.
+fn output_text(output: &Value) -> Result<String> {
+    Ok(match output {
+        Value::String(text) => text.clone(),
+        other => serde_json::to_string_pretty(other)?,
+    })
+}
+
+/// Print a streamed invocation's output as it arrives.
+async fn print_stream(response: Response) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    let mut ends_with_newline = true;
+    read_stream(response, |text| {
+        if let Some(last) = text.chars().last() {
+            ends_with_newline = last == '\n';
+        }
+        stdout.write_all(text.as_bytes())?;
+        stdout.flush()?;
+        Ok(())
+    })
+    .await?;
+    if !ends_with_newline {
+        println!();
+    }
+    Ok(())
+}
+
+/// Pass a streamed invocation's output to `on_text` as it arrives, or all at once when the
+/// function answered with plain JSON instead of an event stream.
+pub(crate) async fn read_stream(
+    mut response: Response,
+    mut on_text: impl FnMut(&str) -> Result<()>,
+) -> Result<()> {
+    let is_stream = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/event-stream"));
+    if !is_stream {
+        let output: Value = response.json().await.context("failed to parse response")?;
+        return on_text(&output_text(&output)?);
+    }
+
+    let mut parser = SseParser::default();
+    while let Some(chunk) = response.chunk().await.context("failed to read response")? {
+        for event in parser.push(&chunk) {
+            let text = match event.event.as_str() {
+                // Deltas are JSON-encoded strings; JSON outputs arrive as raw fragments.
+                "text_delta" => serde_json::from_str::<String>(&event.data)?,
+                "json_delta" => event.data,
+                "error" => {
+                    let message = serde_json::from_str::<String>(&event.data).unwrap_or(event.data);
+                    bail!("invocation failed: {message}");
+                }
+                "done" => return Ok(()),
+                _ => continue,
+            };
+            on_text(&text)?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+struct SseEvent {
+    event: String,
+    data: String,
+}
+
+/// Splits a server-sent event stream into events as bytes arrive.
+#[derive(Default)]
+struct SseParser {
+    buf: Vec<u8>,
+}
+
+impl SseParser {
+    fn push(&mut self, bytes: &[u8]) -> Vec<SseEvent> {
+        self.buf.extend(bytes.iter().filter(|b| **b != b'\r'));
+        let mut events = Vec::new();
+        while let Some(end) = self.buf.windows(2).position(|w| w == b"\n\n") {
+            let block: Vec<u8> = self.buf.drain(..end + 2).collect();
+            let block = String::from_utf8_lossy(&block);
+            let mut event = SseEvent {
+                event: "message".to_string(),
+                data: String::new(),
+            };
+            let mut data = Vec::new();
+            for line in block.lines() {
+                let (field, value) = line.split_once(':').unwrap_or((line, ""));
+                let value = value.strip_prefix(' ').unwrap_or(value);
+                match field {
+                    "event" => event.event = value.to_string(),
+                    "data" => data.push(value),
+                    _ => {}
+                }
+            }
+            if data.is_empty() {
+                continue;
+            }
+            event.data = data.join("\n");
+            events.push(event);
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sse_parser_handles_split_chunks() {
+        let mut parser = SseParser::default();
+        assert!(parser.push(b"event: text_delta\r\ndata: \"Hel").is_empty());
+        let events = parser.push(b"lo\"\r\n\r\nevent: done\ndata: \n\n: ping\n\n");
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event: "text_delta".to_string(),
+                    data: "\"Hello\"".to_string(),
+                },
+                SseEvent {
+                    event: "done".to_string(),
+                    data: String::new(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::Result;
+use dialoguer::console;
+use unicode_width::UnicodeWidthStr;
+
+use crate::experiments::format_created;
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::with_spinner;
+
+use super::api;
+
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    kind: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let mut functions = with_spinner(
+        "Loading functions...",
+        api::list_functions(client, &project.id),
+    )
+    .await?;
+    if let Some(kind) = kind {
+        functions.retain(|f| f.kind().eq_ignore_ascii_case(kind));
+    }
+
+    if json {
+        println!("{}", serde_json::to_string(&functions)?);
+        return Ok(());
+    }
+
+    println!(
+        "{} functions found in {}\n",
+        console::style(functions.len()),
+        console::style(&project.name).bold()
+    );
+    let slug_width = functions
+        .iter()
+        .map(|f| f.slug.width())
+        .max()
+        .unwrap_or(0)
+        .max(15);
+    let type_width = functions
+        .iter()
+        .map(|f| f.kind().width())
+        .max()
+        .unwrap_or(0)
+        .max(8);
+    println!(
+        "{}  {}  {}  {}",
+        console::style(format!("{:slug_width$}", "Slug"))
+            .dim()
+            .bold(),
+        console::style(format!("{:type_width$}", "Type"))
+            .dim()
+            .bold(),
+        console::style(format!("{:20}", "Created")).dim().bold(),
+        console::style("Name").dim().bold()
+    );
+    for function in &functions {
+        println!(
+            "{}{:slug_padding$}  {:type_width$}  {:20}  {}",
+            function.slug,
+            "",
+            function.kind(),
+            format_created(function.created.as_deref()),
+            function.name,
+            slug_padding = slug_width - function.slug.width(),
+        );
+    }
+    Ok(())
+}
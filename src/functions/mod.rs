@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Result};
+use clap::{Args, Subcommand};
+use serde_json::Value;
+
+use crate::args::BaseArgs;
+use crate::http::ApiClient;
+use crate::login::login;
+use crate::projects::api::Project;
+use crate::projects::resolve_project;
+use crate::ui::{self, with_spinner};
+
+pub(crate) mod api;
+mod invoke;
+mod list;
+pub(crate) mod push;
+
+pub(crate) use invoke::{invoke_and_print, read_stream};
+
+#[derive(Debug, Clone, Args)]
+pub struct FunctionsArgs {
+    #[command(subcommand)]
+    command: Option<FunctionsCommands>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum FunctionsCommands {
+    /// List functions, tools, and scorers in the active project
+    List(ListArgs),
+    /// Call a function and print its output
+    Invoke(InvokeArgs),
+    /// Bundle a TypeScript or JavaScript file and upload it as a code function
+    Push(PushArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+struct ListArgs {
+    /// Only show functions of this type, e.g. scorer or tool
+    #[arg(long = "type", value_name = "TYPE")]
+    kind: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct InvokeArgs {
+    /// Function slug (pick interactively when omitted)
+    slug: Option<String>,
+
+    /// Input to pass, as JSON (anything else is sent as a string)
+    #[arg(long, value_name = "JSON", value_parser = invoke::parse_input)]
+    input: Option<Value>,
+
+    /// Print the output as it is generated, when the function supports streaming
+    #[arg(long)]
+    stream: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct PushArgs {
+    /// File that registers the function, e.g. with project.scorers.create
+    file: PathBuf,
+
+    /// Slug to push the function as (replaces an existing function with this slug)
+    #[arg(long)]
+    slug: String,
+
+    /// Display name (defaults to the slug)
+    #[arg(long)]
+    name: Option<String>,
+
+    /// Function description
+    #[arg(long)]
+    description: Option<String>,
+
+    /// Function type, e.g. scorer, tool, or task
+    #[arg(long = "type", value_name = "TYPE")]
+    kind: Option<String>,
+}
+
+pub async fn run(base: BaseArgs, args: FunctionsArgs) -> Result<()> {
+    let ctx = login(&base).await?;
+    let client = ApiClient::new(&ctx)?;
+    let project = resolve_project(&client, base.project.as_deref()).await?;
+
+    match args.command {
+        None => list::run(&client, &project, None, base.json).await,
+        Some(FunctionsCommands::List(a)) => {
+            list::run(&client, &project, a.kind.as_deref(), base.json).await
+        }
+        Some(FunctionsCommands::Invoke(a)) => {
+            let function = resolve_function(&client, &project, a.slug.as_deref()).await?;
+            let input = a.input.unwrap_or(Value::Null);
+            let label = format!("Invoking '{}'...", function.slug);
+            invoke_and_print(&client, &function.id, &label, &input, a.stream, base.json).await
+        }
+        Some(FunctionsCommands::Push(a)) => {
+            let opts = push::PushOptions {
+                slug: &a.slug,
+                name: a.name.as_deref(),
+                description: a.description.as_deref(),
+                kind: a.kind.as_deref(),
+            };
+            push::run(&client, &project, &a.file, &opts, base.json).await
+        }
+    }
+}
+
+async fn find_function(client: &ApiClient, project: &Project, slug: &str) -> Result<api::Function> {
+    with_spinner(
+        "Loading function...",
+        api::get_function_by_slug(client, &project.id, slug),
+    )
+    .await?
+    .ok_or_else(|| anyhow!("function '{slug}' not found in '{}'", project.name))
+}
+
+/// The function with this slug, or one picked interactively from the project's functions.
+async fn resolve_function(
+    client: &ApiClient,
+    project: &Project,
+    slug: Option<&str>,
+) -> Result<api::Function> {
+    if let Some(slug) = slug {
+        return find_function(client, project, slug).await;
+    }
+    if !ui::is_interactive() {
+        bail!("function slug required");
+    }
+    let mut functions = with_spinner(
+        "Loading functions...",
+        api::list_functions(client, &project.id),
+    )
+    .await?;
+    if functions.is_empty() {
+        bail!("no functions found in '{}'", project.name);
+    }
+    let labels: Vec<String> = functions
+        .iter()
+        .map(|f| format!("{} ({})", f.slug, f.kind()))
+        .collect();
+    let selection = ui::fuzzy_select("Select function", &labels)?;
+    Ok(functions.swap_remove(selection))
+}
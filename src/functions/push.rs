@@ -0,0 +1,183 @@
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::{bail, Context, Result};
+use dialoguer::console;
+use serde_json::{json, Value};
+use tokio::process::Command;
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api;
+
+/// Node major version to declare when `node` isn't installed locally.
+const DEFAULT_NODE_VERSION: &str = "20";
+
+pub struct PushOptions<'a> {
+    pub slug: &'a str,
+    pub name: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub kind: Option<&'a str>,
+}
+
+/// Bundle a TypeScript or JavaScript file with esbuild and upload it as a code function.
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    file: &Path,
+    opts: &PushOptions<'_>,
+    json: bool,
+) -> Result<()> {
+    let ext = file.extension().and_then(|e| e.to_str()).unwrap_or("");
+    if !matches!(ext, "ts" | "tsx" | "js" | "mjs" | "cjs") {
+        bail!(
+            "only TypeScript and JavaScript functions can be pushed (got {})",
+            file.display()
+        );
+    }
+    let source = std::fs::read_to_string(file)
+        .with_context(|| format!("failed to read {}", file.display()))?;
+
+    let bundle = with_spinner("Bundling...", bundle(file)).await?;
+    let runtime_context = json!({ "runtime": "node", "version": node_version().await });
+    let function = with_spinner(&format!("Pushing '{}'...", opts.slug), async {
+        let upload = api::request_code_upload(client, &project.org_id, &runtime_context).await?;
+        client.upload(&upload.url, bundle).await?;
+        let body = function_body(project, opts, &upload.bundle_id, runtime_context, &source);
+        api::upsert_function(client, &body).await
+    })
+    .await?;
+
+    if json {
+        println!("{}", serde_json::to_string(&function)?);
+        return Ok(());
+    }
+    print_command_status(
+        CommandStatus::Success,
+        &format!(
+            "Pushed '{}' to {} (version {})",
+            function.slug,
+            console::style(&project.name).bold(),
+            function.version.as_deref().unwrap_or("unknown")
+        ),
+    );
+    Ok(())
+}
+
+fn function_body(
+    project: &Project,
+    opts: &PushOptions<'_>,
+    bundle_id: &str,
+    runtime_context: Value,
+    source: &str,
+) -> Value {
+    let mut body = json!({
+        "project_id": project.id,
+        "slug": opts.slug,
+        "name": opts.name.unwrap_or(opts.slug),
+        "function_data": {
+            "type": "code",
+            "data": {
+                "type": "bundle",
+                "runtime_context": runtime_context,
+                // The bundle registers its functions in order; push the first one.
+                "location": { "type": "function", "index": 0 },
+                "bundle_id": bundle_id,
+                "preview": source,
+            },
+        },
+    });
+    if let Some(description) = opts.description {
+        body["description"] = json!(description);
+    }
+    if let Some(kind) = opts.kind {
+        body["function_type"] = json!(kind);
+    }
+    body
+}
+
+/// Bundle `file` and its imports into one CommonJS file for Node.
+async fn bundle(file: &Path) -> Result<Vec<u8>> {
+    let out = std::env::temp_dir().join(format!("bt-bundle-{}.js", std::process::id()));
+    let mut command = match find_esbuild(file) {
+        Some(esbuild) => Command::new(esbuild),
+        None => {
+            let mut command = Command::new("npx");
+            command.arg("--yes").arg("esbuild");
+            command
+        }
+    };
+    let output = command
+        .arg(file)
+        .args(esbuild_args(&out))
+        .stdin(Stdio::null())
+        .output()
+        .await
+        .context("failed to run esbuild; install it with `npm install --save-dev esbuild`")?;
+    if !output.status.success() {
+        bail!(
+            "esbuild failed:\n{}",
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        );
+    }
+    let bundle = std::fs::read(&out).with_context(|| format!("failed to read {}", out.display()));
+    let _ = std::fs::remove_file(&out);
+    bundle
+}
+
+fn esbuild_args(out: &Path) -> Vec<String> {
+    vec![
+        "--bundle".to_string(),
+        "--platform=node".to_string(),
+        "--format=cjs".to_string(),
+        "--log-level=error".to_string(),
+        format!("--outfile={}", out.display()),
+    ]
+}
+
+/// esbuild from the nearest `node_modules/.bin` above the file or the working directory.
+fn find_esbuild(file: &Path) -> Option<PathBuf> {
+    let cwd = std::env::current_dir().ok()?;
+    let start = cwd.join(file);
+    for root in [start.parent()?, cwd.as_path()] {
+        for dir in root.ancestors() {
+            let bin = dir.join("node_modules").join(".bin").join("esbuild");
+            if bin.is_file() {
+                return Some(bin);
+            }
+            if cfg!(windows) && bin.with_extension("cmd").is_file() {
+                return Some(bin.with_extension("cmd"));
+            }
+        }
+    }
+    None
+}
+
+/// The local Node major version, which the function will run under remotely.
+async fn node_version() -> String {
+    let output = Command::new("node").arg("--version").output().await;
+    output
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| node_major(&String::from_utf8_lossy(&o.stdout)))
+        .unwrap_or_else(|| DEFAULT_NODE_VERSION.to_string())
+}
+
+fn node_major(version: &str) -> Option<String> {
+    let major = version.trim().trim_start_matches('v').split('.').next()?;
+    (!major.is_empty() && major.chars().all(|c| c.is_ascii_digit())).then(|| major.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn node_major_parses_node_version_output() {
+        assert_eq!(node_major("v20.11.1\n"), Some("20".to_string()));
+        assert_eq!(node_major("18.0.0"), Some("18".to_string()));
+        assert_eq!(node_major("unknown"), None);
+    }
+}
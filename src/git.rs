@@ -0,0 +1,84 @@
+use std::path::Path;
+use std::process::Command;
+
+use serde::Serialize;
+
+/// Git state of a working directory, serialized in the shape of an experiment's `repo_info`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct GitMetadata {
+    pub commit: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    pub dirty: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_time: Option<String>,
+}
+
+/// Git metadata for `dir`, or `None` when it isn't inside a repository with commits
+/// (or git isn't installed).
+pub fn collect(dir: &Path) -> Option<GitMetadata> {
+    let commit = git(dir, &["rev-parse", "HEAD"])?;
+    // A detached HEAD reports the literal branch name `HEAD`.
+    let branch = git(dir, &["rev-parse", "--abbrev-ref", "HEAD"]).filter(|b| b != "HEAD");
+    let dirty = git(dir, &["status", "--porcelain"]).is_some_and(|s| !s.is_empty());
+    let log = git(dir, &["log", "-1", "--format=%an%n%ae%n%cI%n%s"]).unwrap_or_default();
+    let mut metadata = parse_log(&log);
+    metadata.commit = commit;
+    metadata.branch = branch;
+    metadata.dirty = dirty;
+    Some(metadata)
+}
+
+/// Author, commit time, and subject from `git log --format=%an%n%ae%n%cI%n%s`.
+fn parse_log(log: &str) -> GitMetadata {
+    let mut lines = log.lines().map(|line| {
+        Some(line.trim())
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+    });
+    let mut next = || lines.next().flatten();
+    GitMetadata {
+        author_name: next(),
+        author_email: next(),
+        commit_time: next(),
+        commit_message: next(),
+        ..GitMetadata::default()
+    }
+}
+
+/// Trimmed stdout of a successful git command.
+fn git(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_log_reads_author_time_and_subject() {
+        let metadata = parse_log("Ada\nada@example.com\n2024-05-01T12:00:00+00:00\nFix scorer\n");
+        assert_eq!(metadata.author_name.as_deref(), Some("Ada"));
+        assert_eq!(metadata.author_email.as_deref(), Some("ada@example.com"));
+        assert_eq!(
+            metadata.commit_time.as_deref(),
+            Some("2024-05-01T12:00:00+00:00")
+        );
+        assert_eq!(metadata.commit_message.as_deref(), Some("Fix scorer"));
+        assert_eq!(parse_log(""), GitMetadata::default());
+    }
+}
@@ -1,10 +1,36 @@
 use anyhow::{Context, Result};
-use reqwest::Client;
+use reqwest::{Client, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::login::LoginContext;
 
+/// A non-success response from the API.
+#[derive(Debug)]
+pub struct HttpError {
+    pub status: StatusCode,
+    pub body: String,
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "request failed ({}): {}", self.status, self.body)
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+/// True for failures worth retrying: 5xx responses and connection or timeout errors.
+pub fn is_retryable(err: &anyhow::Error) -> bool {
+    err.chain()
+        .any(|cause| match cause.downcast_ref::<HttpError>() {
+            Some(http) => http.status.is_server_error(),
+            None => cause
+                .downcast_ref::<reqwest::Error>()
+                .is_some_and(|err| err.is_connect() || err.is_timeout() || err.is_request()),
+        })
+}
+
 pub struct ApiClient {
     http: Client,
     base_url: String,
@@ -48,7 +74,7 @@ impl ApiClient {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("request failed ({status}): {body}");
+            return Err(HttpError { status, body }.into());
         }
 
         response.json().await.context("failed to parse response")
@@ -68,7 +94,31 @@ impl ApiClient {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("request failed ({status}): {body}");
+            return Err(HttpError { status, body }.into());
+        }
+
+        response.json().await.context("failed to parse response")
+    }
+
+    pub async fn patch<T: DeserializeOwned, B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        let url = self.url(path);
+        let response = self
+            .http
+            .patch(&url)
+            .bearer_auth(&self.api_key)
+            .json(body)
+            .send()
+            .await
+            .context("request failed")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(HttpError { status, body }.into());
         }
 
         response.json().await.context("failed to parse response")
@@ -96,12 +146,72 @@ impl ApiClient {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("request failed ({status}): {body}");
+            return Err(HttpError { status, body }.into());
         }
 
         response.json().await.context("failed to parse response")
     }
 
+    pub async fn put<T: DeserializeOwned, B: Serialize>(&self, path: &str, body: &B) -> Result<T> {
+        let url = self.url(path);
+        let response = self
+            .http
+            .put(&url)
+            .bearer_auth(&self.api_key)
+            .json(body)
+            .send()
+            .await
+            .context("request failed")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(HttpError { status, body }.into());
+        }
+
+        response.json().await.context("failed to parse response")
+    }
+
+    /// PUT raw bytes to a presigned URL, which carries its own credentials.
+    pub async fn upload(&self, url: &str, body: Vec<u8>) -> Result<()> {
+        let response = self
+            .http
+            .put(url)
+            .body(body)
+            .send()
+            .await
+            .context("upload failed")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            anyhow::bail!("upload failed ({status}): {body}");
+        }
+
+        Ok(())
+    }
+
+    /// POST and return the successful response unread, for streaming bodies.
+    pub async fn post_stream<B: Serialize>(&self, path: &str, body: &B) -> Result<Response> {
+        let url = self.url(path);
+        let response = self
+            .http
+            .post(&url)
+            .bearer_auth(&self.api_key)
+            .json(body)
+            .send()
+            .await
+            .context("request failed")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(HttpError { status, body }.into());
+        }
+
+        Ok(response)
+    }
+
     pub async fn delete(&self, path: &str) -> Result<()> {
         let url = self.url(path);
         let response = self
@@ -115,9 +225,30 @@ impl ApiClient {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("request failed ({status}): {body}");
+            return Err(HttpError { status, body }.into());
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_server_errors_are_retryable() {
+        let error = |status| {
+            anyhow::Error::new(HttpError {
+                status,
+                body: String::new(),
+            })
+        };
+        assert!(is_retryable(&error(StatusCode::BAD_GATEWAY)));
+        assert!(!is_retryable(&error(StatusCode::BAD_REQUEST)));
+        assert!(is_retryable(
+            &error(StatusCode::SERVICE_UNAVAILABLE).context("failed to insert")
+        ));
+        assert!(!is_retryable(&anyhow::anyhow!("invalid JSON")));
+    }
+}
@@ -0,0 +1,166 @@
+use std::future::Future;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::http::is_retryable;
+use crate::ui::progress_bar;
+
+/// Rows sent per insert request.
+pub const BATCH_SIZE: usize = 500;
+const MAX_ATTEMPTS: u32 = 3;
+
+/// The contents of `file`, or of stdin when it is `-`; `what` names them in errors.
+pub fn read_input(file: &Path, what: &str) -> Result<String> {
+    if file == Path::new("-") {
+        let mut buf = String::new();
+        io::stdin()
+            .read_to_string(&mut buf)
+            .with_context(|| format!("failed to read {what} from stdin"))?;
+        Ok(buf)
+    } else {
+        std::fs::read_to_string(file).with_context(|| format!("failed to read {}", file.display()))
+    }
+}
+
+/// Where progress for a resumable transfer of `file` is kept: `<file>.bt-checkpoint`.
+pub fn checkpoint_path(file: &Path) -> PathBuf {
+    let mut name = file.as_os_str().to_owned();
+    name.push(".bt-checkpoint");
+    PathBuf::from(name)
+}
+
+/// The checkpoint saved at `path`, or `None` when there is none.
+pub fn load_checkpoint<T: DeserializeOwned>(path: &Path) -> Result<Option<T>> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .map(Some)
+            .with_context(|| format!("failed to parse {}", path.display())),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+/// Write `checkpoint` to `path` through a temporary file, so an interruption never
+/// leaves a half-written one behind.
+pub fn save_checkpoint<T: Serialize>(path: &Path, checkpoint: &T) -> Result<()> {
+    let temp = path.with_extension("bt-checkpoint.tmp");
+    std::fs::write(&temp, serde_json::to_string(checkpoint)?)
+        .with_context(|| format!("failed to write {}", temp.display()))?;
+    std::fs::rename(&temp, path).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Ids for rows that arrive without one: a random per-upload nonce plus the row's position.
+/// A retried batch overwrites its own rows, while separate uploads, even of the same file
+/// or of stdin, never collide.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RowIds(String);
+
+impl RowIds {
+    pub fn random() -> Self {
+        Self(format!("{:016x}", fastrand::u64(..)))
+    }
+
+    /// Give `row` the id for position `index` unless it already has one.
+    pub fn assign(&self, index: u64, row: &mut Map<String, Value>) {
+        row.entry("id")
+            .or_insert_with(|| Value::String(format!("{}-{index}", self.0)));
+    }
+
+    /// [`assign`](Self::assign) ids to every object in `rows`, numbering them from 1.
+    pub fn assign_all(&self, rows: &mut [Value]) {
+        for (idx, row) in rows.iter_mut().enumerate() {
+            if let Value::Object(row) = row {
+                self.assign(idx as u64 + 1, row);
+            }
+        }
+    }
+}
+
+/// Insert `rows` in batches behind a progress bar, retrying each batch through
+/// [`with_retries`]. `what` names the rows in the progress bar and errors.
+pub async fn insert_batches<'a, F, Fut>(rows: &'a [Value], what: &str, mut insert: F) -> Result<()>
+where
+    F: FnMut(&'a [Value]) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let bar = progress_bar(rows.len() as u64, &format!("Inserting {what}"));
+    for batch in rows.chunks(BATCH_SIZE) {
+        if let Err(err) = with_retries(|| insert(batch)).await {
+            bar.abandon();
+            return Err(err.context(format!(
+                "failed after inserting {} of {} {what}",
+                bar.position(),
+                rows.len()
+            )));
+        }
+        bar.inc(batch.len() as u64);
+    }
+    bar.finish_and_clear();
+    Ok(())
+}
+
+/// Run `request`, retrying server and network failures with exponential backoff.
+pub async fn with_retries<F, Fut>(mut request: F) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let mut attempt = 1;
+    loop {
+        match request().await {
+            Ok(()) => return Ok(()),
+            Err(err) if attempt < MAX_ATTEMPTS && is_retryable(&err) => {
+                tokio::time::sleep(Duration::from_millis(500 << attempt)).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use crate::http::HttpError;
+    use reqwest::StatusCode;
+
+    #[test]
+    fn checkpoints_round_trip() {
+        let file = std::env::temp_dir().join(format!("bt-ingest-{}.jsonl", fastrand::u64(..)));
+        let path = checkpoint_path(&file);
+        assert!(path.to_string_lossy().ends_with(".jsonl.bt-checkpoint"));
+        assert_eq!(load_checkpoint::<Vec<u64>>(&path).unwrap(), None);
+
+        save_checkpoint(&path, &vec![1u64, 2]).unwrap();
+        assert_eq!(
+            load_checkpoint::<Vec<u64>>(&path).unwrap(),
+            Some(vec![1, 2])
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let calls = AtomicU32::new(0);
+        let result = with_retries(|| async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(HttpError {
+                status: StatusCode::BAD_REQUEST,
+                body: String::new(),
+            }
+            .into())
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
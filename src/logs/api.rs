@@ -0,0 +1,22 @@
+use anyhow::Result;
+use serde_json::Value;
+use urlencoding::encode;
+
+use crate::http::ApiClient;
+
+/// Attach feedback (scores, comments, expected values) to logged spans.
+pub async fn log_feedback(client: &ApiClient, project_id: &str, feedback: &[Value]) -> Result<()> {
+    let path = format!("/v1/project_logs/{}/feedback", encode(project_id));
+    let _: Value = client
+        .post(&path, &serde_json::json!({ "feedback": feedback }))
+        .await?;
+    Ok(())
+}
+
+pub async fn insert_logs(client: &ApiClient, project_id: &str, events: &[Value]) -> Result<()> {
+    let path = format!("/v1/project_logs/{}/insert", encode(project_id));
+    let _: Value = client
+        .post(&path, &serde_json::json!({ "events": events }))
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,296 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use dialoguer::console;
+use serde_json::{json, Map, Value};
+use unicode_width::UnicodeWidthStr;
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::{query_all_rows, quote_literal};
+use crate::ui::with_spinner;
+
+use super::list::cutoff;
+
+/// USD per million prompt and completion tokens, matched by longest model-name prefix.
+const PRICES: [(&str, f64, f64); 14] = [
+    ("gpt-4o-mini", 0.15, 0.60),
+    ("gpt-4o", 2.50, 10.00),
+    ("gpt-4.1-nano", 0.10, 0.40),
+    ("gpt-4.1-mini", 0.40, 1.60),
+    ("gpt-4.1", 2.00, 8.00),
+    ("gpt-4-turbo", 10.00, 30.00),
+    ("gpt-3.5-turbo", 0.50, 1.50),
+    ("o1-mini", 1.10, 4.40),
+    ("o1", 15.00, 60.00),
+    ("o3-mini", 1.10, 4.40),
+    ("claude-3-5-haiku", 0.80, 4.00),
+    ("claude-3-5-sonnet", 3.00, 15.00),
+    ("claude-3-opus", 15.00, 75.00),
+    ("claude-sonnet-4", 3.00, 15.00),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    /// metadata.model
+    Model,
+    /// Calendar day (UTC) the span was created
+    Day,
+}
+
+impl GroupBy {
+    fn column(self) -> &'static str {
+        match self {
+            GroupBy::Model => "model",
+            GroupBy::Day => "day",
+        }
+    }
+}
+
+/// Parse a `--price MODEL=PROMPT:COMPLETION` override (USD per million tokens).
+pub fn parse_price(raw: &str) -> Result<(String, f64, f64), String> {
+    let invalid = || format!("expected MODEL=PROMPT:COMPLETION, got '{raw}'");
+    let (model, prices) = raw.split_once('=').ok_or_else(invalid)?;
+    let (prompt, completion) = prices.split_once(':').ok_or_else(invalid)?;
+    let prompt = prompt.trim().parse().map_err(|_| invalid())?;
+    let completion = completion.trim().parse().map_err(|_| invalid())?;
+    let model = model.trim();
+    if model.is_empty() {
+        return Err(invalid());
+    }
+    Ok((model.to_string(), prompt, completion))
+}
+
+pub struct CostOptions<'a> {
+    pub since: Duration,
+    pub group_by: &'a [GroupBy],
+    pub prices: &'a [(String, f64, f64)],
+}
+
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    opts: &CostOptions<'_>,
+    json: bool,
+) -> Result<()> {
+    let query = cost_query(&project.id, &cutoff(opts.since));
+    let rows = with_spinner("Summing token usage...", query_all_rows(client, &query)).await?;
+    let groups = group(&rows, opts.group_by, opts.prices);
+    let columns: Vec<&str> = opts.group_by.iter().map(|g| g.column()).collect();
+
+    if json {
+        let rows: Vec<Value> = groups
+            .iter()
+            .map(|(keys, totals)| {
+                let mut row: Map<String, Value> = columns
+                    .iter()
+                    .zip(keys)
+                    .map(|(c, k)| (c.to_string(), json!(k)))
+                    .collect();
+                row.insert("spans".to_string(), json!(totals.spans));
+                row.insert("prompt_tokens".to_string(), json!(totals.prompt_tokens));
+                row.insert(
+                    "completion_tokens".to_string(),
+                    json!(totals.completion_tokens),
+                );
+                row.insert("cost".to_string(), json!(totals.cost));
+                Value::Object(row)
+            })
+            .collect();
+        println!("{}", serde_json::to_string(&rows)?);
+        return Ok(());
+    }
+
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(idx, c)| {
+            groups
+                .keys()
+                .map(|keys| keys[idx].width())
+                .chain([c.width(), 5])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let key_header: Vec<String> = columns
+        .iter()
+        .zip(&widths)
+        .map(|(c, w)| format!("{:w$}", capitalize(c)))
+        .collect();
+    println!(
+        "{}  {}",
+        console::style(key_header.join("  ")).dim().bold(),
+        console::style(format!(
+            "{:>8}  {:>14}  {:>14}  {:>10}",
+            "Spans", "Prompt tokens", "Completion", "Cost"
+        ))
+        .dim()
+        .bold()
+    );
+    let mut total = Totals::default();
+    for (keys, totals) in &groups {
+        let keys: Vec<String> = keys
+            .iter()
+            .zip(&widths)
+            .map(|(k, w)| format!("{k:w$}"))
+            .collect();
+        println!("{}  {}", keys.join("  "), totals.format());
+        total.add(totals);
+    }
+    let key_width = widths.iter().sum::<usize>() + 2 * (widths.len() - 1);
+    println!(
+        "{}  {}",
+        console::style(format!("{:key_width$}", "Total")).bold(),
+        console::style(total.format()).bold()
+    );
+    Ok(())
+}
+
+/// Token sums per model and day; costs are estimated per model before any regrouping.
+fn cost_query(project_id: &str, since: &str) -> String {
+    format!(
+        "SELECT metadata.model AS model, day(created) AS day, COUNT(1) AS spans, \
+         SUM(metrics.prompt_tokens) AS prompt_tokens, \
+         SUM(metrics.completion_tokens) AS completion_tokens FROM project_logs({}) \
+         WHERE created >= {} AND (metrics.prompt_tokens IS NOT NULL \
+         OR metrics.completion_tokens IS NOT NULL) GROUP BY 1, 2",
+        quote_literal(project_id),
+        quote_literal(since)
+    )
+}
+
+/// Roll per-model, per-day rows up into the requested groups, sorted by key.
+fn group(
+    rows: &[Map<String, Value>],
+    group_by: &[GroupBy],
+    prices: &[(String, f64, f64)],
+) -> BTreeMap<Vec<String>, Totals> {
+    let mut groups: BTreeMap<Vec<String>, Totals> = BTreeMap::new();
+    for row in rows {
+        let keys = group_by
+            .iter()
+            .map(|g| key(g, row.get(g.column())))
+            .collect();
+        let mut totals = Totals::from_row(row);
+        totals.cost = estimate(row, prices);
+        groups.entry(keys).or_default().add(&totals);
+    }
+    groups
+}
+
+fn key(group_by: &GroupBy, value: Option<&Value>) -> String {
+    match (group_by, value) {
+        (_, None | Some(Value::Null)) => "-".to_string(),
+        // Days come back as midnight timestamps; the date is enough.
+        (GroupBy::Day, Some(Value::String(s))) => s.get(..10).unwrap_or(s).to_string(),
+        (_, Some(Value::String(s))) => s.clone(),
+        (_, Some(other)) => other.to_string(),
+    }
+}
+
+/// Estimated USD cost of a row, using `overrides` before the built-in prices.
+/// None when the row has no model or the model has no known price.
+fn estimate(row: &Map<String, Value>, overrides: &[(String, f64, f64)]) -> Option<f64> {
+    let model = row.get("model")?.as_str()?;
+    let (prompt_price, completion_price) = overrides
+        .iter()
+        .find(|(name, _, _)| name == model)
+        .map(|(_, p, c)| (*p, *c))
+        .or_else(|| {
+            PRICES
+                .iter()
+                .filter(|(prefix, _, _)| model.starts_with(prefix))
+                .max_by_key(|(prefix, _, _)| prefix.len())
+                .map(|(_, p, c)| (*p, *c))
+        })?;
+    let tokens = |key: &str| row.get(key).and_then(Value::as_f64).unwrap_or(0.0);
+    Some(
+        (tokens("prompt_tokens") * prompt_price + tokens("completion_tokens") * completion_price)
+            / 1_000_000.0,
+    )
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Totals {
+    spans: u64,
+    prompt_tokens: u64,
+    completion_tokens: u64,
+    cost: Option<f64>,
+}
+
+impl Totals {
+    fn from_row(row: &Map<String, Value>) -> Self {
+        let count = |key: &str| row.get(key).and_then(Value::as_u64).unwrap_or(0);
+        Self {
+            spans: count("spans"),
+            prompt_tokens: count("prompt_tokens"),
+            completion_tokens: count("completion_tokens"),
+            cost: None,
+        }
+    }
+
+    fn add(&mut self, other: &Totals) {
+        self.spans += other.spans;
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        if let Some(cost) = other.cost {
+            self.cost = Some(self.cost.unwrap_or(0.0) + cost);
+        }
+    }
+
+    fn format(&self) -> String {
+        format!(
+            "{:>8}  {:>14}  {:>14}  {:>10}",
+            self.spans,
+            self.prompt_tokens,
+            self.completion_tokens,
+            self.cost
+                .map_or_else(|| "-".to_string(), |c| format!("${c:.2}"))
+        )
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates_use_longest_prefix_and_overrides() {
+        let row = |model: &str| -> Map<String, Value> {
+            serde_json::from_value(json!({
+                "model": model,
+                "prompt_tokens": 1_000_000,
+                "completion_tokens": 500_000,
+            }))
+            .unwrap()
+        };
+        assert_eq!(estimate(&row("gpt-4o-mini-2024-07-18"), &[]), Some(0.45));
+        assert_eq!(estimate(&row("gpt-4o"), &[]), Some(7.5));
+        assert_eq!(estimate(&row("my-model"), &[]), None);
+        let overrides = [parse_price("my-model=1:2").unwrap()];
+        assert_eq!(estimate(&row("my-model"), &overrides), Some(2.0));
+        assert!(parse_price("my-model=1").is_err());
+
+        let rows: Vec<Map<String, Value>> = serde_json::from_value(json!([
+            { "model": "gpt-4o", "day": "2024-01-01T00:00:00Z", "spans": 2, "prompt_tokens": 1_000_000 },
+            { "model": "gpt-4o-mini", "day": "2024-01-01T00:00:00Z", "spans": 1, "prompt_tokens": 1_000_000 },
+            { "model": "gpt-4o", "day": "2024-01-02T00:00:00Z", "spans": 1, "prompt_tokens": 0 },
+        ]))
+        .unwrap();
+        let by_day = group(&rows, &[GroupBy::Day], &[]);
+        let first = &by_day[&vec!["2024-01-01".to_string()]];
+        assert_eq!((first.spans, first.cost), (3, Some(2.65)));
+        assert_eq!(by_day.len(), 2);
+    }
+}
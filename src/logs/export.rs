@@ -0,0 +1,229 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::filter::WhereClause;
+use crate::http::ApiClient;
+use crate::ingest::{checkpoint_path, load_checkpoint, save_checkpoint};
+use crate::projects::api::Project;
+use crate::sql::{query_all_rows, query_page, query_rows, quote_literal, AtomicFile};
+use crate::ui::{print_command_status, progress_bar, with_spinner, CommandStatus};
+
+use super::list::cutoff;
+use super::otlp;
+
+/// Spans requested per page when following cursors.
+const PAGE_SIZE: usize = 1000;
+
+pub enum Target<'a> {
+    /// Append spans to a JSONL file page by page, optionally resuming an earlier export.
+    Jsonl { path: &'a Path, resume: bool },
+    /// Write a single OTLP `ExportTraceServiceRequest` protobuf.
+    Otlp(&'a Path),
+}
+
+/// Export the project's spans created within `since` and matching `filter`.
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    since: Option<Duration>,
+    filter: Option<&WhereClause>,
+    target: Target<'_>,
+) -> Result<()> {
+    let predicate = predicate(since.map(cutoff).as_deref(), filter);
+    match target {
+        Target::Jsonl { path, resume } => {
+            export_jsonl(client, project, predicate, path, resume).await
+        }
+        Target::Otlp(path) => export_otlp(client, project, predicate.as_deref(), path).await,
+    }
+}
+
+async fn export_otlp(
+    client: &ApiClient,
+    project: &Project,
+    predicate: Option<&str>,
+    output: &Path,
+) -> Result<()> {
+    let query = export_query(&project.id, predicate);
+    let rows = with_spinner("Fetching spans...", query_all_rows(client, &query)).await?;
+    if rows.is_empty() {
+        bail!("no spans found in '{}'", project.name);
+    }
+
+    let request = otlp::export_request(&project.name, &rows);
+    let mut file = AtomicFile::create(output)?;
+    file.write_all(&request.encode_to_vec())?;
+    file.commit()?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Wrote {} spans to {}", rows.len(), output.display()),
+    );
+    Ok(())
+}
+
+/// Progress of a JSONL export: the next page's cursor and how much of the file is complete.
+#[derive(Debug, Serialize, Deserialize)]
+struct Checkpoint {
+    project_id: String,
+    /// The filter the export started with, reused on resume so `--since` doesn't drift.
+    predicate: Option<String>,
+    cursor: Option<String>,
+    rows: u64,
+    bytes: u64,
+}
+
+async fn export_jsonl(
+    client: &ApiClient,
+    project: &Project,
+    predicate: Option<String>,
+    output: &Path,
+    resume: bool,
+) -> Result<()> {
+    let checkpoint_path = checkpoint_path(output);
+    let mut checkpoint = match load_checkpoint::<Checkpoint>(&checkpoint_path)? {
+        Some(saved) if resume => {
+            if saved.project_id != project.id {
+                bail!(
+                    "{} belongs to a different project; delete it to start over",
+                    checkpoint_path.display()
+                );
+            }
+            saved
+        }
+        _ if resume => bail!(
+            "no checkpoint at {} to resume from",
+            checkpoint_path.display()
+        ),
+        _ => Checkpoint {
+            project_id: project.id.clone(),
+            predicate,
+            cursor: None,
+            rows: 0,
+            bytes: 0,
+        },
+    };
+
+    let total = query_rows(
+        client,
+        &count_query(&project.id, checkpoint.predicate.as_deref()),
+    )
+    .await?
+    .first()
+    .and_then(|row| row.get("spans"))
+    .and_then(Value::as_u64)
+    .unwrap_or(0);
+    let file = if resume {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .open(output)
+            .with_context(|| format!("failed to open {}", output.display()))?;
+        // Drop anything written after the last checkpoint, such as a partial page.
+        file.set_len(checkpoint.bytes)?;
+        file.seek(SeekFrom::End(0))?;
+        file
+    } else {
+        File::create(output).with_context(|| format!("failed to create {}", output.display()))?
+    };
+    let mut out = BufWriter::new(file);
+
+    let query = export_query(&project.id, checkpoint.predicate.as_deref());
+    let bar = progress_bar(total.max(checkpoint.rows), "Exporting spans");
+    bar.set_position(checkpoint.rows);
+    loop {
+        let (rows, next) = match query_page(client, &query, checkpoint.cursor.as_deref()).await {
+            Ok(page) => page,
+            Err(err) => {
+                bar.abandon();
+                eprintln!(
+                    "Exported {} spans; rerun with --resume to continue",
+                    checkpoint.rows
+                );
+                return Err(err);
+            }
+        };
+        let mut buf = Vec::new();
+        for row in &rows {
+            serde_json::to_writer(&mut buf, row)?;
+            buf.push(b'\n');
+        }
+        out.write_all(&buf)?;
+        out.flush()?;
+        checkpoint.bytes += buf.len() as u64;
+        checkpoint.rows += rows.len() as u64;
+        checkpoint.cursor = next;
+        save_checkpoint(&checkpoint_path, &checkpoint)?;
+        bar.set_length(bar.length().unwrap_or(0).max(checkpoint.rows));
+        bar.set_position(checkpoint.rows);
+        if rows.is_empty() || checkpoint.cursor.is_none() {
+            break;
+        }
+    }
+    bar.finish_and_clear();
+    let _ = std::fs::remove_file(&checkpoint_path);
+
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Wrote {} spans to {}", checkpoint.rows, output.display()),
+    );
+    Ok(())
+}
+
+/// BTQL predicate for spans created after `since` and matching `filter`.
+fn predicate(since: Option<&str>, filter: Option<&WhereClause>) -> Option<String> {
+    let mut predicates = Vec::new();
+    if let Some(since) = since {
+        predicates.push(format!("created >= {}", quote_literal(since)));
+    }
+    if let Some(filter) = filter {
+        predicates.push(filter.to_btql());
+    }
+    (!predicates.is_empty()).then(|| predicates.join(" AND "))
+}
+
+fn where_clause(predicate: Option<&str>) -> String {
+    predicate.map_or_else(String::new, |p| format!(" WHERE {p}"))
+}
+
+/// Unordered, so the server can page through results with cursors.
+fn export_query(project_id: &str, predicate: Option<&str>) -> String {
+    format!(
+        "SELECT * FROM project_logs({}){} LIMIT {PAGE_SIZE}",
+        quote_literal(project_id),
+        where_clause(predicate)
+    )
+}
+
+fn count_query(project_id: &str, predicate: Option<&str>) -> String {
+    format!(
+        "SELECT COUNT(1) AS spans FROM project_logs({}){}",
+        quote_literal(project_id),
+        where_clause(predicate)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_queries_share_the_predicate() {
+        let clause = WhereClause::parse("metadata.env = 'prod'").unwrap();
+        let predicate = predicate(Some("2024-01-01T00:00:00Z"), Some(&clause));
+        assert_eq!(
+            export_query("p1", predicate.as_deref()),
+            "SELECT * FROM project_logs('p1') WHERE created >= '2024-01-01T00:00:00Z' \
+             AND metadata.env = 'prod' LIMIT 1000"
+        );
+        assert_eq!(
+            count_query("p1", None),
+            "SELECT COUNT(1) AS spans FROM project_logs('p1')"
+        );
+    }
+}
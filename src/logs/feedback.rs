@@ -0,0 +1,58 @@
+use anyhow::{anyhow, Result};
+use serde_json::Value;
+
+use crate::experiments::feedback_event;
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::{query_rows, quote_literal};
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api;
+
+pub struct Feedback<'a> {
+    pub scores: &'a [(String, f64)],
+    pub comment: Option<&'a str>,
+    pub expected: Option<&'a str>,
+}
+
+/// Record feedback on the logged span with `id` (a row id or span id).
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    id: &str,
+    feedback: &Feedback<'_>,
+) -> Result<()> {
+    // Validate before the lookup so an empty request fails fast.
+    feedback_event(id, feedback.scores, feedback.comment, feedback.expected)?;
+    with_spinner("Recording feedback...", async {
+        let row_id = resolve_row_id(client, project, id).await?;
+        let event = feedback_event(
+            &row_id,
+            feedback.scores,
+            feedback.comment,
+            feedback.expected,
+        )?;
+        api::log_feedback(client, &project.id, &[event]).await
+    })
+    .await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Recorded feedback on {id} in '{}'", project.name),
+    );
+    Ok(())
+}
+
+/// Feedback is keyed by row id; accept a span id too and look up its row.
+async fn resolve_row_id(client: &ApiClient, project: &Project, id: &str) -> Result<String> {
+    let id_literal = quote_literal(id);
+    let query = format!(
+        "SELECT id FROM project_logs({}) WHERE id = {id_literal} OR span_id = {id_literal} LIMIT 1",
+        quote_literal(&project.id)
+    );
+    let rows = query_rows(client, &query).await?;
+    rows.first()
+        .and_then(|row| row.get("id"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("no span '{id}' found in '{}'", project.name))
+}
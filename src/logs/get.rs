@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use dialoguer::console;
+use serde_json::{Map, Value};
+
+use crate::experiments::format_created;
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::{query_rows, quote_literal};
+use crate::ui::with_spinner;
+
+use super::span;
+
+const FIELDS: [&str; 5] = ["input", "output", "metadata", "metrics", "error"];
+
+/// Print the span with `id`, or every span of the trace rooted at it.
+pub async fn run(client: &ApiClient, project: &Project, id: &str, json: bool) -> Result<()> {
+    let rows = with_spinner(
+        "Loading spans...",
+        query_rows(client, &get_query(&project.id, id)),
+    )
+    .await?;
+    if rows.is_empty() {
+        bail!("no span or trace '{id}' found in '{}'", project.name);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string(&rows)?);
+        return Ok(());
+    }
+
+    let depths = depths(&rows);
+    for (idx, row) in rows.iter().enumerate() {
+        if idx > 0 {
+            println!();
+        }
+        let indent = "  ".repeat(depths[idx]);
+        let error = if span::has_error(row) {
+            format!("  {}", console::style("error").red())
+        } else {
+            String::new()
+        };
+        println!(
+            "{indent}{}  {}  {}{error}",
+            console::style(span::name(row)).bold(),
+            console::style(row.get("span_id").and_then(Value::as_str).unwrap_or("-")).dim(),
+            span::format_duration(row),
+        );
+        println!(
+            "{indent}{}  {}",
+            console::style(format!("{:10}", "created")).dim().bold(),
+            format_created(row.get("created").and_then(Value::as_str))
+        );
+        for field in FIELDS {
+            let Some(value) = row.get(field).filter(|v| !v.is_null()) else {
+                continue;
+            };
+            let pretty = match value {
+                Value::String(s) => s.clone(),
+                other => serde_json::to_string_pretty(other)?,
+            };
+            let mut lines = pretty.lines();
+            println!(
+                "{indent}{}  {}",
+                console::style(format!("{field:10}")).dim().bold(),
+                lines.next().unwrap_or_default()
+            );
+            for line in lines {
+                println!("{indent}{:10}  {line}", "");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Spans whose id, span id, or root span id is `id`, oldest first.
+pub(super) fn get_query(project_id: &str, id: &str) -> String {
+    let id = quote_literal(id);
+    format!(
+        "SELECT * FROM project_logs({}) WHERE id = {id} OR span_id = {id} \
+         OR root_span_id = {id} ORDER BY created ASC",
+        quote_literal(project_id)
+    )
+}
+
+/// Nesting depth of each span under its parents; spans whose parent isn't in `rows` are roots.
+fn depths(rows: &[Map<String, Value>]) -> Vec<usize> {
+    let parents: HashMap<&str, &str> = rows
+        .iter()
+        .filter_map(|row| {
+            let span_id = row.get("span_id")?.as_str()?;
+            let parent = row.get("span_parents")?.as_array()?.first()?.as_str()?;
+            Some((span_id, parent))
+        })
+        .collect();
+    rows.iter()
+        .map(|row| {
+            let mut depth = 0;
+            let mut current = row.get("span_id").and_then(Value::as_str);
+            while let Some(parent) = current.and_then(|id| parents.get(id)) {
+                depth += 1;
+                // Guard against malformed cycles.
+                if depth > rows.len() {
+                    break;
+                }
+                current = Some(parent);
+            }
+            depth
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn depths_follow_span_parents() {
+        let rows: Vec<Map<String, Value>> = serde_json::from_value(json!([
+            { "span_id": "root" },
+            { "span_id": "llm", "span_parents": ["root"] },
+            { "span_id": "tool", "span_parents": ["llm"] },
+            { "span_id": "orphan", "span_parents": ["gone"] },
+        ]))
+        .unwrap();
+        assert_eq!(depths(&rows), vec![0, 1, 2, 1]);
+        assert!(get_query("p1", "s'1").contains("span_id = 's''1'"));
+    }
+}
@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use anyhow::{bail, Result};
+
+use crate::experiments::parse_events;
+use crate::http::ApiClient;
+use crate::ingest::{insert_batches, read_input, RowIds};
+use crate::projects::api::Project;
+use crate::ui::{print_command_status, CommandStatus};
+
+use super::api;
+
+/// Insert events from a JSONL file (or stdin for `-`) into the project's logs.
+pub async fn run(client: &ApiClient, project: &Project, file: &Path) -> Result<()> {
+    let contents = read_input(file, "events")?;
+    let mut events = parse_events(&contents)?;
+    if events.is_empty() {
+        bail!("no events to insert");
+    }
+    // Retried batches must overwrite, not duplicate, events the server already stored.
+    RowIds::random().assign_all(&mut events);
+
+    insert_batches(&events, "events", |batch| {
+        api::insert_logs(client, &project.id, batch)
+    })
+    .await?;
+
+    print_command_status(
+        CommandStatus::Success,
+        &format!(
+            "Inserted {} events into '{}' logs",
+            events.len(),
+            project.name
+        ),
+    );
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use std::time::{Duration, SystemTime};
+
+use anyhow::Result;
+use dialoguer::console;
+use serde_json::{Map, Value};
+use unicode_width::UnicodeWidthStr;
+
+use crate::experiments::format_created;
+use crate::filter::WhereClause;
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::{query_rows, quote_literal, truncate_cell};
+use crate::ui::with_spinner;
+
+use super::span;
+
+pub struct ListOptions<'a> {
+    pub since: Option<Duration>,
+    pub filter: Option<&'a WhereClause>,
+    pub limit: usize,
+}
+
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    opts: &ListOptions<'_>,
+    json: bool,
+) -> Result<()> {
+    let since = opts.since.map(cutoff);
+    let query = list_query(&project.id, since.as_deref(), opts.filter, Some(opts.limit));
+    let rows = with_spinner("Loading logs...", query_rows(client, &query)).await?;
+
+    if json {
+        println!("{}", serde_json::to_string(&rows)?);
+        return Ok(());
+    }
+
+    println!(
+        "{} spans found in {}\n",
+        console::style(rows.len()),
+        console::style(&project.name).bold()
+    );
+    let name_width = rows
+        .iter()
+        .map(|row| span::name(row).width())
+        .max()
+        .unwrap_or(0)
+        .max(10);
+    println!(
+        "{}  {}  {}  {}  {}",
+        console::style(format!("{:20}", "Timestamp")).dim().bold(),
+        console::style(format!("{:name_width$}", "Span"))
+            .dim()
+            .bold(),
+        console::style(format!("{:>8}", "Duration")).dim().bold(),
+        console::style(format!("{:>8}", "Tokens")).dim().bold(),
+        console::style("Error").dim().bold()
+    );
+    for row in &rows {
+        let name = span::name(row);
+        let padding = name_width - name.width();
+        println!(
+            "{:20}  {name}{:padding$}  {:>8}  {:>8}  {}",
+            format_created(row.get("created").and_then(Value::as_str)),
+            "",
+            span::format_duration(row),
+            span::tokens(row).map_or_else(|| "-".to_string(), |t| t.to_string()),
+            error_summary(row),
+        );
+    }
+    Ok(())
+}
+
+/// The RFC 3339 timestamp `age` ago.
+pub(super) fn cutoff(age: Duration) -> String {
+    humantime::format_rfc3339_seconds(SystemTime::now() - age).to_string()
+}
+
+/// Newest spans first, created after `since` and matching `filter`.
+pub(super) fn list_query(
+    project_id: &str,
+    since: Option<&str>,
+    filter: Option<&WhereClause>,
+    limit: Option<usize>,
+) -> String {
+    let mut predicates = Vec::new();
+    if let Some(since) = since {
+        predicates.push(format!("created >= {}", quote_literal(since)));
+    }
+    if let Some(filter) = filter {
+        predicates.push(filter.to_btql());
+    }
+    let mut query = format!("SELECT * FROM project_logs({})", quote_literal(project_id));
+    if !predicates.is_empty() {
+        query.push_str(" WHERE ");
+        query.push_str(&predicates.join(" AND "));
+    }
+    query.push_str(" ORDER BY created DESC");
+    if let Some(limit) = limit {
+        query.push_str(&format!(" LIMIT {limit}"));
+    }
+    query
+}
+
+/// The first line of the span's error, or `-`.
+fn error_summary(row: &Map<String, Value>) -> String {
+    if !span::has_error(row) {
+        return "-".to_string();
+    }
+    let message = match &row["error"] {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    let first = message.lines().next().unwrap_or_default();
+    console::style(truncate_cell(first, 60)).red().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_query_combines_since_and_where() {
+        let clause = WhereClause::parse("metrics.tokens > 1000").unwrap();
+        assert_eq!(
+            list_query("p1", Some("2024-01-01T00:00:00Z"), Some(&clause), Some(100)),
+            "SELECT * FROM project_logs('p1') WHERE created >= '2024-01-01T00:00:00Z' \
+             AND metrics.tokens > 1000 ORDER BY created DESC LIMIT 100"
+        );
+        assert_eq!(
+            list_query("p1", None, None, None),
+            "SELECT * FROM project_logs('p1') ORDER BY created DESC"
+        );
+    }
+}
@@ -0,0 +1,333 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+
+use crate::args::BaseArgs;
+use crate::experiments::parse_score;
+use crate::filter::{parse_age, WhereClause};
+use crate::http::ApiClient;
+use crate::login::login;
+use crate::projects::resolve_project;
+
+mod api;
+mod cost;
+mod export;
+mod feedback;
+mod get;
+mod insert;
+mod list;
+mod otlp;
+mod purge;
+mod sample;
+mod search;
+mod span;
+mod tail;
+mod trace;
+mod watch;
+
+#[derive(Debug, Clone, Args)]
+pub struct LogsArgs {
+    #[command(subcommand)]
+    command: LogsCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum LogsCommands {
+    /// List recent spans matching a filter
+    List(ListArgs),
+    /// Show a span, or every span of a trace, by ID
+    Get(GetArgs),
+    /// Insert events from a JSONL file into the project's logs
+    Insert(InsertArgs),
+    /// Export spans to JSONL, or as OpenTelemetry (OTLP) traces
+    Export(ExportArgs),
+    /// Explore a trace's span tree in an interactive viewer
+    Trace(TraceArgs),
+    /// Summarize token usage and estimated cost
+    Cost(CostArgs),
+    /// Add scores or a comment to a logged span
+    Feedback(FeedbackArgs),
+    /// Delete log events older than an age and/or matching a filter
+    Purge(PurgeArgs),
+    /// Print a random sample of recent spans
+    Sample(SampleArgs),
+    /// Search span inputs and outputs for text
+    Search(SearchArgs),
+    /// Print the project's latest spans, optionally following new ones
+    Tail(TailArgs),
+    /// Alert when new spans match a filter
+    Watch(WatchArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+struct ListArgs {
+    /// Only spans created within this long, e.g. 1h or 7d
+    #[arg(long, value_name = "AGE", value_parser = parse_age)]
+    since: Option<Duration>,
+
+    /// Only spans matching a filter, e.g. "metrics.tokens > 1000"
+    #[arg(long = "where", value_name = "FILTER", value_parser = WhereClause::parse)]
+    filter: Option<WhereClause>,
+
+    /// Maximum number of spans to show, newest first
+    #[arg(long, default_value_t = 50, value_name = "N")]
+    limit: usize,
+}
+
+#[derive(Debug, Clone, Args)]
+struct InsertArgs {
+    /// JSONL file of events (use `-` for stdin)
+    #[arg(long, short = 'f', value_name = "PATH")]
+    file: PathBuf,
+}
+
+#[derive(Debug, Clone, Args)]
+#[command(group(clap::ArgGroup::new("target").required(true).args(["output", "otlp"])))]
+struct ExportArgs {
+    /// JSONL file to write spans to
+    #[arg(short = 'o', long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// File to write an OTLP protobuf (ExportTraceServiceRequest) to instead
+    #[arg(long, value_name = "PATH")]
+    otlp: Option<PathBuf>,
+
+    /// Continue an interrupted JSONL export from its checkpoint file
+    #[arg(long, requires = "output")]
+    resume: bool,
+
+    /// Only spans created within this long, e.g. 24h
+    #[arg(long, value_name = "AGE", value_parser = parse_age)]
+    since: Option<Duration>,
+
+    /// Only spans matching a filter, e.g. "span_attributes.type = 'llm'"
+    #[arg(long = "where", value_name = "FILTER", value_parser = WhereClause::parse)]
+    filter: Option<WhereClause>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct GetArgs {
+    /// Span ID, or a root span ID to show the whole trace
+    id: String,
+}
+
+#[derive(Debug, Clone, Args)]
+struct TraceArgs {
+    /// Trace (root span) ID, or any span ID within it
+    id: String,
+}
+
+#[derive(Debug, Clone, Args)]
+struct CostArgs {
+    /// Only spans created within this long
+    #[arg(long, default_value = "7d", value_name = "AGE", value_parser = parse_age)]
+    since: Duration,
+
+    /// Columns to group by
+    #[arg(long, value_enum, value_delimiter = ',', default_value = "model")]
+    group_by: Vec<cost::GroupBy>,
+
+    /// Price for a model in USD per million prompt and completion tokens, e.g. my-model=1.5:6
+    #[arg(long, value_name = "MODEL=PROMPT:COMPLETION", value_parser = cost::parse_price)]
+    price: Vec<(String, f64, f64)>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct FeedbackArgs {
+    /// Span ID (or row ID) to annotate
+    id: String,
+
+    /// Score to record, e.g. quality=0 (repeatable)
+    #[arg(long = "score", value_name = "NAME=VALUE", value_parser = parse_score)]
+    scores: Vec<(String, f64)>,
+
+    /// Free-form comment
+    #[arg(long)]
+    comment: Option<String>,
+
+    /// Corrected expected output (JSON or plain text)
+    #[arg(long)]
+    expected: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct PurgeArgs {
+    /// Only events created more than this long ago, e.g. 90d
+    #[arg(long, value_name = "AGE", value_parser = parse_age)]
+    before: Option<Duration>,
+
+    /// Only events matching a filter, e.g. 'metadata.env = "dev"'
+    #[arg(long = "where", value_name = "FILTER", value_parser = WhereClause::parse)]
+    filter: Option<WhereClause>,
+
+    /// Report how many events would be deleted without deleting them
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Skip the confirmation prompt (required when not running interactively)
+    #[arg(long, short = 'y')]
+    yes: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct SampleArgs {
+    /// Number of spans to show
+    #[arg(short = 'n', long = "count", default_value_t = 20, value_name = "N")]
+    count: usize,
+
+    /// Only spans created within this long, e.g. 1h
+    #[arg(long, value_name = "AGE", value_parser = parse_age)]
+    since: Option<Duration>,
+
+    /// Show the newest spans instead of a random sample
+    #[arg(long, conflicts_with = "seed")]
+    recent: bool,
+
+    /// Seed for a reproducible sample
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct SearchArgs {
+    /// Text to look for
+    text: String,
+
+    /// Only spans created within this long, e.g. 3d
+    #[arg(long, value_name = "AGE", value_parser = parse_age)]
+    since: Option<Duration>,
+
+    /// Maximum number of spans to show, newest first
+    #[arg(long, default_value_t = 50, value_name = "N")]
+    limit: usize,
+}
+
+#[derive(Debug, Clone, Args)]
+struct TailArgs {
+    /// Number of recent spans to print first
+    #[arg(short = 'n', long, default_value_t = 20, value_name = "N")]
+    lines: usize,
+
+    /// Keep polling and print new spans as they arrive
+    #[arg(short = 'f', long)]
+    follow: bool,
+
+    /// Only spans matching a filter, e.g. "span_attributes.type = 'llm'"
+    #[arg(
+        long = "where",
+        alias = "filter",
+        value_name = "FILTER",
+        value_parser = WhereClause::parse
+    )]
+    filter: Option<WhereClause>,
+
+    /// Time between polls when following
+    #[arg(long, default_value = "2s", value_name = "DURATION", value_parser = parse_age)]
+    interval: Duration,
+}
+
+#[derive(Debug, Clone, Args)]
+struct WatchArgs {
+    /// Spans to alert on, e.g. "error IS NOT NULL"
+    #[arg(long = "where", value_name = "FILTER", value_parser = WhereClause::parse)]
+    filter: WhereClause,
+
+    /// Shell command to run on matches, given them as JSONL on stdin (default: ring the bell)
+    #[arg(long, value_name = "COMMAND")]
+    notify_command: Option<String>,
+
+    /// Time between polls
+    #[arg(long, default_value = "5s", value_name = "DURATION", value_parser = parse_age)]
+    interval: Duration,
+}
+
+pub async fn run(base: BaseArgs, args: LogsArgs) -> Result<()> {
+    let ctx = login(&base).await?;
+    let client = ApiClient::new(&ctx)?;
+    let project = resolve_project(&client, base.project.as_deref()).await?;
+
+    match args.command {
+        LogsCommands::List(a) => {
+            let opts = list::ListOptions {
+                since: a.since,
+                filter: a.filter.as_ref(),
+                limit: a.limit,
+            };
+            list::run(&client, &project, &opts, base.json).await
+        }
+        LogsCommands::Insert(a) => insert::run(&client, &project, &a.file).await,
+        LogsCommands::Export(a) => {
+            let target = match (&a.output, &a.otlp) {
+                (Some(path), _) => export::Target::Jsonl {
+                    path,
+                    resume: a.resume,
+                },
+                (None, Some(path)) => export::Target::Otlp(path),
+                (None, None) => unreachable!("clap requires --output or --otlp"),
+            };
+            export::run(&client, &project, a.since, a.filter.as_ref(), target).await
+        }
+        LogsCommands::Get(a) => get::run(&client, &project, &a.id, base.json).await,
+        LogsCommands::Trace(a) => trace::run(&client, &project, &ctx.app_url, &a.id).await,
+        LogsCommands::Cost(a) => {
+            let opts = cost::CostOptions {
+                since: a.since,
+                group_by: &a.group_by,
+                prices: &a.price,
+            };
+            cost::run(&client, &project, &opts, base.json).await
+        }
+        LogsCommands::Feedback(a) => {
+            let feedback = feedback::Feedback {
+                scores: &a.scores,
+                comment: a.comment.as_deref(),
+                expected: a.expected.as_deref(),
+            };
+            feedback::run(&client, &project, &a.id, &feedback).await
+        }
+        LogsCommands::Purge(a) => {
+            let opts = purge::PurgeOptions {
+                before: a.before,
+                filter: a.filter.as_ref(),
+                dry_run: a.dry_run,
+                yes: a.yes,
+            };
+            purge::run(&client, &project, &opts).await
+        }
+        LogsCommands::Sample(a) => {
+            let opts = sample::SampleOptions {
+                count: a.count,
+                since: a.since,
+                recent: a.recent,
+                seed: a.seed,
+            };
+            sample::run(&client, &project, &opts, base.json).await
+        }
+        LogsCommands::Search(a) => {
+            let opts = search::SearchOptions {
+                since: a.since,
+                limit: a.limit,
+            };
+            search::run(&client, &project, &a.text, &opts, base.json).await
+        }
+        LogsCommands::Tail(a) => {
+            let opts = tail::TailOptions {
+                lines: a.lines,
+                follow: a.follow,
+                filter: a.filter.as_ref(),
+                interval: a.interval,
+            };
+            tail::run(&client, &project, &opts, base.json).await
+        }
+        LogsCommands::Watch(a) => {
+            let opts = watch::WatchOptions {
+                filter: &a.filter,
+                notify_command: a.notify_command.as_deref(),
+                interval: a.interval,
+            };
+            watch::run(&client, &project, &opts, base.json).await
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! The subset of the OTLP trace protobuf (opentelemetry/proto/trace/v1) needed to export spans.
+
+use serde_json::{Map, Value};
+
+use super::span;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ExportTraceServiceRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub resource_spans: Vec<ResourceSpans>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ResourceSpans {
+    #[prost(message, optional, tag = "1")]
+    pub resource: Option<Resource>,
+    #[prost(message, repeated, tag = "2")]
+    pub scope_spans: Vec<ScopeSpans>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Resource {
+    #[prost(message, repeated, tag = "1")]
+    pub attributes: Vec<KeyValue>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ScopeSpans {
+    #[prost(message, optional, tag = "1")]
+    pub scope: Option<InstrumentationScope>,
+    #[prost(message, repeated, tag = "2")]
+    pub spans: Vec<Span>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct InstrumentationScope {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(string, tag = "2")]
+    pub version: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Span {
+    #[prost(bytes = "vec", tag = "1")]
+    pub trace_id: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub span_id: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub parent_span_id: Vec<u8>,
+    #[prost(string, tag = "5")]
+    pub name: String,
+    #[prost(int32, tag = "6")]
+    pub kind: i32,
+    #[prost(fixed64, tag = "7")]
+    pub start_time_unix_nano: u64,
+    #[prost(fixed64, tag = "8")]
+    pub end_time_unix_nano: u64,
+    #[prost(message, repeated, tag = "9")]
+    pub attributes: Vec<KeyValue>,
+    #[prost(message, optional, tag = "15")]
+    pub status: Option<Status>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Status {
+    #[prost(string, tag = "2")]
+    pub message: String,
+    #[prost(int32, tag = "3")]
+    pub code: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct KeyValue {
+    #[prost(string, tag = "1")]
+    pub key: String,
+    #[prost(message, optional, tag = "2")]
+    pub value: Option<AnyValue>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AnyValue {
+    #[prost(oneof = "any_value::Value", tags = "1, 2, 3, 4")]
+    pub value: Option<any_value::Value>,
+}
+
+pub mod any_value {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Value {
+        #[prost(string, tag = "1")]
+        String(String),
+        #[prost(bool, tag = "2")]
+        Bool(bool),
+        #[prost(int64, tag = "3")]
+        Int(i64),
+        #[prost(double, tag = "4")]
+        Double(f64),
+    }
+}
+
+const SPAN_KIND_INTERNAL: i32 = 1;
+const STATUS_CODE_ERROR: i32 = 2;
+
+/// Wrap Braintrust spans in a single OTLP export request for `service_name`.
+pub fn export_request(
+    service_name: &str,
+    rows: &[Map<String, Value>],
+) -> ExportTraceServiceRequest {
+    ExportTraceServiceRequest {
+        resource_spans: vec![ResourceSpans {
+            resource: Some(Resource {
+                attributes: vec![string_attr("service.name", service_name)],
+            }),
+            scope_spans: vec![ScopeSpans {
+                scope: Some(InstrumentationScope {
+                    name: "bt".to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                }),
+                spans: rows.iter().map(to_span).collect(),
+            }],
+        }],
+    }
+}
+
+fn to_span(row: &Map<String, Value>) -> Span {
+    let str_field = |key: &str| row.get(key).and_then(Value::as_str).unwrap_or_default();
+    let parent = row
+        .get("span_parents")
+        .and_then(|parents| parents.get(0))
+        .and_then(Value::as_str);
+    let metrics = row.get("metrics").and_then(Value::as_object);
+    let time = |key: &str| {
+        metrics
+            .and_then(|m| m.get(key))
+            .and_then(Value::as_f64)
+            .map_or(0, |secs| (secs * 1e9) as u64)
+    };
+
+    let mut attributes = vec![string_attr("braintrust.id", str_field("id"))];
+    if let Some(kind) = row
+        .get("span_attributes")
+        .and_then(|attrs| attrs.get("type"))
+        .and_then(Value::as_str)
+    {
+        attributes.push(string_attr("braintrust.span_type", kind));
+    }
+    for field in ["input", "output", "expected", "metadata", "scores"] {
+        if let Some(value) = row.get(field).filter(|v| !v.is_null()) {
+            attributes.push(json_attr(&format!("braintrust.{field}"), value));
+        }
+    }
+    for (key, value) in metrics.into_iter().flatten() {
+        if key == "start" || key == "end" {
+            continue;
+        }
+        if let Some(number) = value.as_f64() {
+            attributes.push(KeyValue {
+                key: format!("braintrust.metrics.{key}"),
+                value: Some(AnyValue {
+                    value: Some(any_value::Value::Double(number)),
+                }),
+            });
+        }
+    }
+
+    let status = span::has_error(row).then(|| Status {
+        message: match &row["error"] {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        },
+        code: STATUS_CODE_ERROR,
+    });
+
+    Span {
+        trace_id: id_bytes(str_field("root_span_id"), 16),
+        span_id: id_bytes(str_field("span_id"), 8),
+        parent_span_id: parent.map(|p| id_bytes(p, 8)).unwrap_or_default(),
+        name: span::name(row).to_string(),
+        kind: SPAN_KIND_INTERNAL,
+        start_time_unix_nano: time("start"),
+        end_time_unix_nano: time("end"),
+        attributes,
+        status,
+    }
+}
+
+/// A `len`-byte OTLP id for a Braintrust id. Hex ids (including UUIDs) of exactly `len`
+/// bytes are used as-is; anything else, including longer hex ids that would collide on a
+/// shared prefix, is hashed so the same id always maps to the same bytes.
+fn id_bytes(raw: &str, len: usize) -> Vec<u8> {
+    let hex: String = raw.chars().filter(|c| *c != '-').collect();
+    if hex.len() == len * 2 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return (0..len)
+            .map(|i| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).expect("hex digit"))
+            .collect();
+    }
+    // FNV-1a, extended with a counter for ids longer than one hash.
+    (0..len.div_ceil(8))
+        .flat_map(|round| {
+            let mut hash: u64 = 0xcbf2_9ce4_8422_2325 ^ round as u64;
+            for byte in raw.bytes() {
+                hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+            hash.to_be_bytes()
+        })
+        .take(len)
+        .collect()
+}
+
+fn string_attr(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.to_string(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::String(value.to_string())),
+        }),
+    }
+}
+
+/// Strings stay as-is; structured values are encoded as JSON strings.
+fn json_attr(key: &str, value: &Value) -> KeyValue {
+    match value {
+        Value::String(s) => string_attr(key, s),
+        other => string_attr(key, &other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use serde_json::json;
+
+    #[test]
+    fn spans_convert_to_otlp() {
+        let rows: Vec<Map<String, Value>> = serde_json::from_value(json!([{
+            "id": "row-1",
+            "span_id": "5f1c2a7e-0000-4000-8000-000000000002",
+            "root_span_id": "5f1c2a7e-0000-4000-8000-000000000001",
+            "span_parents": ["5f1c2a7e-0000-4000-8000-000000000001"],
+            "span_attributes": { "name": "llm", "type": "llm" },
+            "input": { "q": "hi" },
+            "error": "boom",
+            "metrics": { "start": 1.5, "end": 2.0, "tokens": 12 },
+        }]))
+        .unwrap();
+        let request = export_request("my-project", &rows);
+        let span = &request.resource_spans[0].scope_spans[0].spans[0];
+        assert_eq!(span.name, "llm");
+        assert_eq!(span.trace_id[..4], [0x5f, 0x1c, 0x2a, 0x7e]);
+        assert_eq!(span.span_id.len(), 8);
+        assert_eq!(span.parent_span_id.len(), 8);
+        assert_ne!(span.parent_span_id, span.span_id);
+        assert_eq!(span.start_time_unix_nano, 1_500_000_000);
+        assert_eq!(span.status.as_ref().unwrap().code, STATUS_CODE_ERROR);
+        assert!(span.attributes.iter().any(|kv| kv.key == "braintrust.input"
+            && kv.value
+                == Some(AnyValue {
+                    value: Some(any_value::Value::String("{\"q\":\"hi\"}".into()))
+                })));
+        assert_eq!(id_bytes("not-hex", 16), id_bytes("not-hex", 16));
+        assert_eq!(id_bytes("not-hex", 16).len(), 16);
+
+        let decoded = ExportTraceServiceRequest::decode(&*request.encode_to_vec()).unwrap();
+        assert_eq!(decoded, request);
+    }
+}
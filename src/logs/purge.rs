@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+
+use crate::filter::WhereClause;
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::{query_all_rows, quote_literal};
+use crate::ui::{self, print_command_status, progress_bar, with_spinner, CommandStatus};
+
+use super::api;
+use super::list::cutoff;
+
+const BATCH_SIZE: usize = 500;
+
+pub struct PurgeOptions<'a> {
+    pub before: Option<Duration>,
+    pub filter: Option<&'a WhereClause>,
+    pub dry_run: bool,
+    pub yes: bool,
+}
+
+/// Delete the project's log events created more than `before` ago and matching `filter`.
+pub async fn run(client: &ApiClient, project: &Project, opts: &PurgeOptions<'_>) -> Result<()> {
+    if opts.before.is_none() && opts.filter.is_none() {
+        bail!("refusing to purge every log event; pass --before and/or --where");
+    }
+    let before = opts.before.map(cutoff);
+    let query = purge_query(&project.id, before.as_deref(), opts.filter);
+    let rows = with_spinner("Finding matching events...", query_all_rows(client, &query)).await?;
+    let ids: Vec<&str> = rows
+        .iter()
+        .filter_map(|row| row.get("id").and_then(Value::as_str))
+        .collect();
+
+    if ids.is_empty() {
+        print_command_status(CommandStatus::Success, "No matching log events");
+        return Ok(());
+    }
+    if opts.dry_run {
+        print_command_status(
+            CommandStatus::Warning,
+            &format!(
+                "Would delete {} log events from '{}' (dry run)",
+                ids.len(),
+                project.name
+            ),
+        );
+        return Ok(());
+    }
+    if !opts.yes {
+        if !ui::is_interactive() {
+            bail!("refusing to purge without confirmation; pass --yes to purge non-interactively");
+        }
+        let prompt = format!("Delete {} log events from '{}'?", ids.len(), project.name);
+        if !ui::confirm(&prompt, false)? {
+            return Ok(());
+        }
+    }
+
+    let bar = progress_bar(ids.len() as u64, "Deleting events");
+    for batch in ids.chunks(BATCH_SIZE) {
+        let events: Vec<Value> = batch
+            .iter()
+            .map(|id| json!({ "id": id, "_object_delete": true }))
+            .collect();
+        if let Err(err) = api::insert_logs(client, &project.id, &events).await {
+            bar.abandon();
+            return Err(err.context(format!(
+                "failed after deleting {} of {} events",
+                bar.position(),
+                ids.len()
+            )));
+        }
+        bar.inc(batch.len() as u64);
+    }
+    bar.finish_and_clear();
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Deleted {} log events from '{}'", ids.len(), project.name),
+    );
+    Ok(())
+}
+
+fn purge_query(project_id: &str, before: Option<&str>, filter: Option<&WhereClause>) -> String {
+    let mut predicates = Vec::new();
+    if let Some(before) = before {
+        predicates.push(format!("created < {}", quote_literal(before)));
+    }
+    if let Some(filter) = filter {
+        predicates.push(filter.to_btql());
+    }
+    format!(
+        "SELECT id FROM project_logs({}) WHERE {}",
+        quote_literal(project_id),
+        predicates.join(" AND ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn purge_query_selects_ids_before_cutoff() {
+        let clause = WhereClause::parse(r#"metadata.env = "dev""#).unwrap();
+        assert_eq!(
+            purge_query("p1", Some("2024-01-01T00:00:00Z"), Some(&clause)),
+            "SELECT id FROM project_logs('p1') WHERE created < '2024-01-01T00:00:00Z' \
+             AND metadata.env = 'dev'"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use dialoguer::console;
+use serde_json::{Map, Value};
+use unicode_width::UnicodeWidthStr;
+
+use crate::datasets::sample;
+use crate::experiments::format_created;
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::{query_rows, truncate_cell};
+use crate::ui::with_spinner;
+
+use super::list::{cutoff, list_query};
+use super::span;
+
+/// How many of the newest spans a random sample is drawn from.
+const POOL_SIZE: usize = 1000;
+/// Display width of the input and output columns.
+const CELL_WIDTH: usize = 40;
+
+pub struct SampleOptions {
+    pub count: usize,
+    pub since: Option<Duration>,
+    pub recent: bool,
+    pub seed: Option<u64>,
+}
+
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    opts: &SampleOptions,
+    json: bool,
+) -> Result<()> {
+    let since = opts.since.map(cutoff);
+    let limit = if opts.recent { opts.count } else { POOL_SIZE };
+    let query = list_query(&project.id, since.as_deref(), None, Some(limit));
+    let rows = with_spinner("Sampling logs...", query_rows(client, &query)).await?;
+    let rows = if opts.recent {
+        rows
+    } else {
+        let mut rng = match opts.seed {
+            Some(seed) => fastrand::Rng::with_seed(seed),
+            None => fastrand::Rng::new(),
+        };
+        let mut rows = sample(rows, opts.count, &mut rng);
+        rows.sort_by(|a, b| created(b).cmp(created(a)));
+        rows
+    };
+
+    if json {
+        println!("{}", serde_json::to_string(&rows)?);
+        return Ok(());
+    }
+
+    println!(
+        "{} spans sampled from {}\n",
+        console::style(rows.len()),
+        console::style(&project.name).bold()
+    );
+    let name_width = rows
+        .iter()
+        .map(|row| span::name(row).width())
+        .max()
+        .unwrap_or(0)
+        .max(10);
+    println!(
+        "{}  {}  {}  {}",
+        console::style(format!("{:20}", "Timestamp")).dim().bold(),
+        console::style(format!("{:name_width$}", "Span"))
+            .dim()
+            .bold(),
+        console::style(format!("{:CELL_WIDTH$}", "Input"))
+            .dim()
+            .bold(),
+        console::style("Output").dim().bold()
+    );
+    for row in &rows {
+        let name = span::name(row);
+        let input = preview(row.get("input"));
+        println!(
+            "{:20}  {name}{:padding$}  {input}{:input_padding$}  {}",
+            format_created(row.get("created").and_then(Value::as_str)),
+            "",
+            "",
+            preview(row.get("output")),
+            padding = name_width - name.width(),
+            input_padding = CELL_WIDTH - input.width(),
+        );
+    }
+    Ok(())
+}
+
+fn created(row: &Map<String, Value>) -> &str {
+    row.get("created")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+}
+
+/// A value squeezed onto one line of at most `CELL_WIDTH` columns, or `-` when absent.
+fn preview(value: Option<&Value>) -> String {
+    let text = match value {
+        None | Some(Value::Null) => return "-".to_string(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    };
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    truncate_cell(&text, CELL_WIDTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn preview_flattens_and_truncates() {
+        assert_eq!(preview(None), "-");
+        assert_eq!(preview(Some(&json!("a\n  b"))), "a b");
+        assert_eq!(preview(Some(&json!({"q": 1}))), r#"{"q":1}"#);
+        let long = preview(Some(&json!("x".repeat(100))));
+        assert_eq!(long.width(), CELL_WIDTH);
+        assert!(long.ends_with('…'));
+    }
+}
@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use dialoguer::console;
+use serde_json::{json, Map, Value};
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::{query_rows, quote_literal};
+use crate::ui::with_spinner;
+
+use super::list::cutoff;
+
+/// Characters of context shown on each side of a match.
+const CONTEXT: usize = 40;
+const SEARCH_FIELDS: [&str; 2] = ["input", "output"];
+
+pub struct SearchOptions {
+    pub since: Option<Duration>,
+    pub limit: usize,
+}
+
+/// Print spans whose input or output mentions `text`, with a snippet around each match.
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    text: &str,
+    opts: &SearchOptions,
+    json: bool,
+) -> Result<()> {
+    let since = opts.since.map(cutoff);
+    let query = search_query(&project.id, text, since.as_deref(), opts.limit);
+    let rows = with_spinner("Searching logs...", query_rows(client, &query)).await?;
+    let matches: Vec<(&Map<String, Value>, &str, String)> = rows
+        .iter()
+        .filter_map(|row| {
+            let (field, snippet) = SEARCH_FIELDS
+                .iter()
+                .find_map(|field| Some((*field, snippet(&text_of(row.get(*field)?), text)?)))
+                // The server's match is tokenized, so the exact phrase may not appear.
+                .or_else(|| Some(("input", truncated(&text_of(row.get("input")?)))))?;
+            Some((row, field, snippet))
+        })
+        .collect();
+
+    if json {
+        let results: Vec<Value> = matches
+            .iter()
+            .map(|(row, field, snippet)| {
+                json!({
+                    "id": row.get("id"),
+                    "span_id": row.get("span_id"),
+                    "root_span_id": row.get("root_span_id"),
+                    "created": row.get("created"),
+                    "field": field,
+                    "snippet": snippet,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string(&results)?);
+        return Ok(());
+    }
+
+    println!(
+        "{} matching spans in {}\n",
+        console::style(matches.len()),
+        console::style(&project.name).bold()
+    );
+    for (row, field, snippet) in &matches {
+        println!(
+            "{}  {}",
+            console::style(row.get("span_id").and_then(Value::as_str).unwrap_or("-")).dim(),
+            console::style(field).cyan()
+        );
+        println!("  {}", highlight(snippet, text));
+    }
+    Ok(())
+}
+
+fn search_query(project_id: &str, text: &str, since: Option<&str>, limit: usize) -> String {
+    let text = quote_literal(text);
+    let mut query = format!(
+        "SELECT * FROM project_logs({}) WHERE (input MATCH {text} OR output MATCH {text})",
+        quote_literal(project_id)
+    );
+    if let Some(since) = since {
+        query.push_str(&format!(" AND created >= {}", quote_literal(since)));
+    }
+    query.push_str(&format!(" ORDER BY created DESC LIMIT {limit}"));
+    query
+}
+
+/// Strings as-is; anything else as compact JSON.
+fn text_of(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// The text around the first case-insensitive occurrence of `needle`, on one line.
+fn snippet(haystack: &str, needle: &str) -> Option<String> {
+    let chars: Vec<char> = haystack.chars().collect();
+    let lower: Vec<char> = chars.iter().flat_map(|c| c.to_lowercase()).collect();
+    let needle: Vec<char> = needle.chars().flat_map(|c| c.to_lowercase()).collect();
+    // Lowercasing can change lengths; fall back to no match rather than misaligned offsets.
+    if needle.is_empty() || lower.len() != chars.len() {
+        return None;
+    }
+    let start = lower.windows(needle.len()).position(|w| w == needle)?;
+    let from = start.saturating_sub(CONTEXT);
+    let to = (start + needle.len() + CONTEXT).min(chars.len());
+    let mut out = String::new();
+    if from > 0 {
+        out.push('…');
+    }
+    out.extend(&chars[from..to]);
+    if to < chars.len() {
+        out.push('…');
+    }
+    Some(out.replace(['\n', '\r'], " "))
+}
+
+fn truncated(text: &str) -> String {
+    let mut out: String = text.chars().take(CONTEXT * 2).collect();
+    if out.len() < text.len() {
+        out.push('…');
+    }
+    out.replace(['\n', '\r'], " ")
+}
+
+/// Bold every case-insensitive occurrence of `needle` in `text`.
+fn highlight(text: &str, needle: &str) -> String {
+    let lower = text.to_lowercase();
+    let needle_lower = needle.to_lowercase();
+    if needle.is_empty() || lower.len() != text.len() {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    let mut rest = 0;
+    for (idx, _) in lower.match_indices(&needle_lower) {
+        out.push_str(&text[rest..idx]);
+        let end = idx + needle_lower.len();
+        out.push_str(&console::style(&text[idx..end]).yellow().bold().to_string());
+        rest = end;
+    }
+    out.push_str(&text[rest..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snippet_centers_on_case_insensitive_match() {
+        let text = format!(
+            "{}Our Refund Policy allows\nreturns{}",
+            "a".repeat(50),
+            "b".repeat(50)
+        );
+        let snippet = snippet(&text, "refund policy").unwrap();
+        assert!(snippet.starts_with('…') && snippet.ends_with('…'));
+        assert!(snippet.contains("Our Refund Policy allows returns"));
+        assert_eq!(
+            snippet.chars().count(),
+            CONTEXT * 2 + "refund policy".len() + 2
+        );
+        assert_eq!(super::snippet("nothing here", "refund"), None);
+        assert!(search_query("p1", "it's", None, 5).contains("input MATCH 'it''s'"));
+    }
+}
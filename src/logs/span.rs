@@ -0,0 +1,64 @@
+use serde_json::{Map, Value};
+
+/// The span's name from its attributes.
+pub fn name(row: &Map<String, Value>) -> &str {
+    row.get("span_attributes")
+        .and_then(|attrs| attrs.get("name"))
+        .and_then(Value::as_str)
+        .unwrap_or("-")
+}
+
+/// Wall-clock seconds between the span's start and end metrics.
+pub fn duration(row: &Map<String, Value>) -> Option<f64> {
+    let metrics = row.get("metrics")?;
+    let start = metrics.get("start")?.as_f64()?;
+    let end = metrics.get("end")?.as_f64()?;
+    Some(end - start)
+}
+
+/// Total tokens, falling back to prompt plus completion tokens.
+pub fn tokens(row: &Map<String, Value>) -> Option<u64> {
+    let metrics = row.get("metrics")?;
+    if let Some(tokens) = metrics.get("tokens").and_then(Value::as_u64) {
+        return Some(tokens);
+    }
+    let prompt = metrics.get("prompt_tokens").and_then(Value::as_u64);
+    let completion = metrics.get("completion_tokens").and_then(Value::as_u64);
+    match (prompt, completion) {
+        (None, None) => None,
+        (prompt, completion) => Some(prompt.unwrap_or(0) + completion.unwrap_or(0)),
+    }
+}
+
+pub fn has_error(row: &Map<String, Value>) -> bool {
+    row.get("error").is_some_and(|e| !e.is_null())
+}
+
+pub fn format_duration(row: &Map<String, Value>) -> String {
+    duration(row).map_or_else(|| "-".to_string(), |d| format!("{d:.2}s"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn tokens_fall_back_to_prompt_and_completion() {
+        let row = |metrics: Value| -> Map<String, Value> {
+            serde_json::from_value(json!({ "metrics": metrics })).unwrap()
+        };
+        assert_eq!(tokens(&row(json!({ "tokens": 42 }))), Some(42));
+        assert_eq!(
+            tokens(&row(
+                json!({ "prompt_tokens": 30, "completion_tokens": 12 })
+            )),
+            Some(42)
+        );
+        assert_eq!(tokens(&row(json!({ "start": 1.0 }))), None);
+        assert_eq!(
+            format_duration(&row(json!({ "start": 1.0, "end": 2.5 }))),
+            "1.50s"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use anyhow::Result;
+use dialoguer::console;
+use serde_json::{Map, Value};
+
+use crate::filter::WhereClause;
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::{query_rows, quote_literal, truncate_cell};
+
+use super::span;
+
+/// Most spans fetched by a single poll while following.
+pub(super) const POLL_LIMIT: usize = 1000;
+const PREVIEW_WIDTH: usize = 80;
+
+pub struct TailOptions<'a> {
+    pub lines: usize,
+    pub follow: bool,
+    pub filter: Option<&'a WhereClause>,
+    pub interval: Duration,
+}
+
+/// Print the latest `lines` spans, then with `follow` poll for spans written after the
+/// newest one seen until interrupted.
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    opts: &TailOptions<'_>,
+    json: bool,
+) -> Result<()> {
+    let filter = opts.filter.map(WhereClause::to_btql);
+    let mut rows = query_rows(
+        client,
+        &tail_query(&project.id, filter.as_deref(), None, opts.lines),
+    )
+    .await?;
+    rows.reverse();
+    let mut last = print_rows(&rows, json)?;
+
+    if !opts.follow {
+        return Ok(());
+    }
+    if !json {
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Following logs for '{}' (Ctrl-C to stop)",
+                project.name
+            ))
+            .dim()
+        );
+    }
+    loop {
+        tokio::time::sleep(opts.interval).await;
+        let query = tail_query(&project.id, filter.as_deref(), last.as_deref(), POLL_LIMIT);
+        let mut rows = query_rows(client, &query).await?;
+        if last.is_none() {
+            rows.reverse();
+        }
+        if let Some(newest) = print_rows(&rows, json)? {
+            last = Some(newest);
+        }
+    }
+}
+
+/// With `after`, the spans written since that transaction, oldest first; otherwise the
+/// newest `limit` spans, newest first. `filter` is a BTQL predicate from `WhereClause::to_btql`.
+pub(super) fn tail_query(
+    project_id: &str,
+    filter: Option<&str>,
+    after: Option<&str>,
+    limit: usize,
+) -> String {
+    let mut predicates = Vec::new();
+    if let Some(after) = after {
+        predicates.push(format!("_xact_id > {}", quote_literal(after)));
+    }
+    if let Some(filter) = filter {
+        predicates.push(filter.to_string());
+    }
+    let mut query = format!("SELECT * FROM project_logs({})", quote_literal(project_id));
+    if !predicates.is_empty() {
+        query.push_str(" WHERE ");
+        query.push_str(&predicates.join(" AND "));
+    }
+    let order = if after.is_some() { "ASC" } else { "DESC" };
+    query.push_str(&format!(" ORDER BY _xact_id {order} LIMIT {limit}"));
+    query
+}
+
+/// Print spans in order, returning the newest transaction id among them.
+pub(super) fn print_rows(rows: &[Map<String, Value>], json: bool) -> Result<Option<String>> {
+    let mut stdout = io::stdout().lock();
+    let mut newest: Option<String> = None;
+    for row in rows {
+        if json {
+            serde_json::to_writer(&mut stdout, row)?;
+            stdout.write_all(b"\n")?;
+        } else {
+            writeln!(stdout, "{}", span_line(row))?;
+        }
+        if let Some(xact_id) = row.get("_xact_id").and_then(Value::as_str) {
+            if newest.as_deref().is_none_or(|n| xact_newer(xact_id, n)) {
+                newest = Some(xact_id.to_string());
+            }
+        }
+    }
+    stdout.flush()?;
+    Ok(newest)
+}
+
+/// Transaction ids are decimal strings, so the longer (or lexically greater) one is newer.
+fn xact_newer(a: &str, b: &str) -> bool {
+    (a.len(), a) > (b.len(), b)
+}
+
+/// One line per span: time, name, duration, error marker, and an input → output preview.
+fn span_line(row: &Map<String, Value>) -> String {
+    let created = row.get("created").and_then(Value::as_str).unwrap_or("-");
+    let error = if span::has_error(row) {
+        format!(" {}", console::style("error").red())
+    } else {
+        String::new()
+    };
+    let preview = format!(
+        "{} → {}",
+        preview(row.get("input")),
+        preview(row.get("output"))
+    );
+    format!(
+        "{}  {:<24}  {:>8}{error}  {}",
+        console::style(created).dim(),
+        span::name(row),
+        span::format_duration(row),
+        truncate_cell(&preview, PREVIEW_WIDTH)
+    )
+}
+
+fn preview(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => "-".to_string(),
+        Some(Value::String(s)) => s.replace('\n', " "),
+        Some(other) => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tail_query_follows_from_last_xact_id() {
+        assert_eq!(
+            tail_query("p1", None, None, 20),
+            "SELECT * FROM project_logs('p1') ORDER BY _xact_id DESC LIMIT 20"
+        );
+        assert_eq!(
+            tail_query("p1", Some("metrics.tokens > 10"), Some("1000"), 1000),
+            "SELECT * FROM project_logs('p1') WHERE _xact_id > '1000' AND metrics.tokens > 10 \
+             ORDER BY _xact_id ASC LIMIT 1000"
+        );
+        assert!(xact_newer("1000", "999"));
+        assert!(!xact_newer("1000", "1001"));
+    }
+}
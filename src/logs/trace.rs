@@ -0,0 +1,409 @@
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::ExecutableCommand;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::Frame;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Terminal;
+use serde_json::{Map, Value};
+use urlencoding::encode;
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::query_rows;
+use crate::ui::{self, is_limited_terminal, with_spinner};
+
+use super::get::get_query;
+use super::span;
+
+const BAR_WIDTH: usize = 30;
+const DETAIL_FIELDS: [&str; 5] = ["input", "output", "metadata", "metrics", "error"];
+const HELP: &str = "↑/↓ select, ←/→ collapse/expand, Enter details, o open in browser, q quit";
+
+/// The spans of one trace arranged as a tree, with per-span collapse state.
+struct TraceTree {
+    rows: Vec<Map<String, Value>>,
+    children: Vec<Vec<usize>>,
+    roots: Vec<usize>,
+    collapsed: HashSet<usize>,
+    /// Earliest start and latest end across the trace, for the waterfall bars.
+    bounds: Option<(f64, f64)>,
+}
+
+impl TraceTree {
+    fn new(mut rows: Vec<Map<String, Value>>) -> Self {
+        rows.sort_by(|a, b| {
+            start(a)
+                .partial_cmp(&start(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let index: HashMap<&str, usize> = rows
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, row)| Some((row.get("span_id")?.as_str()?, idx)))
+            .collect();
+        let mut children = vec![Vec::new(); rows.len()];
+        let mut roots = Vec::new();
+        for (idx, row) in rows.iter().enumerate() {
+            let parent = row
+                .get("span_parents")
+                .and_then(|parents| parents.get(0))
+                .and_then(Value::as_str)
+                .and_then(|parent| index.get(parent))
+                .filter(|parent| **parent != idx);
+            match parent {
+                Some(parent) => children[*parent].push(idx),
+                None => roots.push(idx),
+            }
+        }
+        // Malformed parent cycles leave no root; start from the earliest span instead.
+        if roots.is_empty() && !rows.is_empty() {
+            roots.push(0);
+        }
+        let bounds = rows
+            .iter()
+            .filter_map(|row| Some((start(row)?, end(row)?)))
+            .reduce(|(s1, e1), (s2, e2)| (s1.min(s2), e1.max(e2)));
+        Self {
+            rows,
+            children,
+            roots,
+            collapsed: HashSet::new(),
+            bounds,
+        }
+    }
+
+    /// Visible spans in display order with their depth, skipping collapsed subtrees.
+    fn visible(&self) -> Vec<(usize, usize)> {
+        let mut out = Vec::new();
+        let mut stack: Vec<(usize, usize)> = self.roots.iter().rev().map(|&r| (r, 0)).collect();
+        while let Some((idx, depth)) = stack.pop() {
+            out.push((idx, depth));
+            // Guard against malformed parent cycles.
+            if out.len() > self.rows.len() {
+                break;
+            }
+            if !self.collapsed.contains(&idx) {
+                stack.extend(self.children[idx].iter().rev().map(|&c| (c, depth + 1)));
+            }
+        }
+        out
+    }
+
+    fn line(&self, idx: usize, depth: usize) -> Line<'static> {
+        let row = &self.rows[idx];
+        let marker = if self.children[idx].is_empty() {
+            "  "
+        } else if self.collapsed.contains(&idx) {
+            "▸ "
+        } else {
+            "▾ "
+        };
+        let mut spans = vec![
+            Span::raw(format!("{}{marker}", "  ".repeat(depth))),
+            Span::styled(
+                span::name(row).to_string(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!("  {}", span::format_duration(row))),
+        ];
+        if span::has_error(row) {
+            spans.push(Span::styled("  error", Style::default().fg(Color::Red)));
+        }
+        if let Some(bar) = self.bar(row) {
+            spans.push(Span::styled(
+                format!("  {bar}"),
+                Style::default().fg(Color::Cyan),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// A waterfall bar placing the span within the trace's time range.
+    fn bar(&self, row: &Map<String, Value>) -> Option<String> {
+        let (trace_start, trace_end) = self.bounds?;
+        let (start, end) = (start(row)?, end(row)?);
+        let total = (trace_end - trace_start).max(f64::EPSILON);
+        let offset = (((start - trace_start) / total) * BAR_WIDTH as f64) as usize;
+        let offset = offset.min(BAR_WIDTH - 1);
+        let width = ((((end - start) / total) * BAR_WIDTH as f64).round() as usize)
+            .clamp(1, BAR_WIDTH - offset);
+        Some(format!(
+            "{}{}{}",
+            " ".repeat(offset),
+            "█".repeat(width),
+            " ".repeat(BAR_WIDTH - offset - width)
+        ))
+    }
+}
+
+fn start(row: &Map<String, Value>) -> Option<f64> {
+    row.get("metrics")?.get("start")?.as_f64()
+}
+
+fn end(row: &Map<String, Value>) -> Option<f64> {
+    row.get("metrics")?.get("end")?.as_f64()
+}
+
+struct Viewer {
+    tree: TraceTree,
+    state: ListState,
+    /// Scroll offset of the detail popup, when open.
+    detail: Option<u16>,
+    status: String,
+    logs_url: String,
+}
+
+pub async fn run(client: &ApiClient, project: &Project, app_url: &str, id: &str) -> Result<()> {
+    if !ui::is_interactive() || is_limited_terminal() {
+        bail!("the trace viewer needs an interactive terminal; use `bt logs get {id}` instead");
+    }
+    let rows = with_spinner("Loading trace...", async {
+        let rows = query_rows(client, &get_query(&project.id, id)).await?;
+        // A single non-root span: load the rest of its trace.
+        let root = match rows.as_slice() {
+            [row] => row.get("root_span_id").and_then(Value::as_str),
+            _ => None,
+        };
+        match root {
+            Some(root) if root != id => query_rows(client, &get_query(&project.id, root)).await,
+            _ => Ok(rows),
+        }
+    })
+    .await?;
+    if rows.is_empty() {
+        bail!("no span or trace '{id}' found in '{}'", project.name);
+    }
+
+    let logs_url = format!(
+        "{}/app/{}/p/{}/logs",
+        app_url.trim_end_matches('/'),
+        encode(client.org_name()),
+        encode(&project.name)
+    );
+    let mut state = ListState::default();
+    state.select(Some(0));
+    let viewer = Viewer {
+        tree: TraceTree::new(rows),
+        state,
+        detail: None,
+        status: HELP.to_string(),
+        logs_url,
+    };
+    tokio::task::block_in_place(|| run_blocking(viewer))
+}
+
+fn run_blocking(mut viewer: Viewer) -> Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    stdout.execute(EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let res = (|| -> Result<()> {
+        loop {
+            terminal.draw(|f| draw(f, &mut viewer))?;
+            if event::poll(Duration::from_millis(200))? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press && handle_key(&mut viewer, key) {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    })();
+
+    disable_raw_mode().ok();
+    terminal.backend_mut().execute(LeaveAlternateScreen).ok();
+    terminal.show_cursor().ok();
+    res
+}
+
+/// Handle a key press, returning true to quit.
+fn handle_key(viewer: &mut Viewer, key: KeyEvent) -> bool {
+    if let Some(scroll) = viewer.detail.as_mut() {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => *scroll = scroll.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => *scroll = scroll.saturating_add(1),
+            KeyCode::PageUp => *scroll = scroll.saturating_sub(10),
+            KeyCode::PageDown => *scroll = scroll.saturating_add(10),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => {
+                viewer.detail = None;
+                viewer.status = HELP.to_string();
+            }
+            _ => {}
+        }
+        return false;
+    }
+
+    let visible = viewer.tree.visible();
+    let selected = viewer.state.selected().unwrap_or(0).min(visible.len() - 1);
+    let (idx, _) = visible[selected];
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => viewer.state.select(Some(selected.saturating_sub(1))),
+        KeyCode::Down | KeyCode::Char('j') => {
+            viewer
+                .state
+                .select(Some((selected + 1).min(visible.len() - 1)));
+        }
+        KeyCode::PageUp => viewer.state.select(Some(selected.saturating_sub(10))),
+        KeyCode::PageDown => viewer
+            .state
+            .select(Some((selected + 10).min(visible.len() - 1))),
+        KeyCode::Left | KeyCode::Char('h') => {
+            viewer.tree.collapsed.insert(idx);
+        }
+        KeyCode::Right | KeyCode::Char('l') => {
+            viewer.tree.collapsed.remove(&idx);
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            viewer.detail = Some(0);
+            viewer.status = "↑/↓ scroll, Esc close".to_string();
+        }
+        KeyCode::Char('o') => {
+            let url = span_url(&viewer.logs_url, &viewer.tree.rows[idx]);
+            viewer.status = match open::that(&url) {
+                Ok(()) => format!("Opened {url}"),
+                Err(err) => format!("Failed to open {url}: {err}"),
+            };
+        }
+        KeyCode::Esc | KeyCode::Char('q') => return true,
+        _ => {}
+    }
+    false
+}
+
+fn span_url(logs_url: &str, row: &Map<String, Value>) -> String {
+    let field = |key: &str| row.get(key).and_then(Value::as_str).unwrap_or_default();
+    format!(
+        "{logs_url}?r={}&s={}",
+        encode(field("root_span_id")),
+        encode(field("span_id"))
+    )
+}
+
+fn draw(frame: &mut Frame<'_>, viewer: &mut Viewer) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(frame.area());
+    let visible = viewer.tree.visible();
+    let items: Vec<ListItem> = visible
+        .iter()
+        .map(|&(idx, depth)| ListItem::new(viewer.tree.line(idx, depth)))
+        .collect();
+    let title = format!("Trace ({} spans)", viewer.tree.rows.len());
+    let list = List::new(items)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    frame.render_stateful_widget(list, chunks[0], &mut viewer.state);
+    frame.render_widget(
+        Paragraph::new(viewer.status.as_str()).style(Style::default().fg(Color::DarkGray)),
+        chunks[1],
+    );
+
+    if let Some(scroll) = viewer.detail {
+        let selected = viewer.state.selected().unwrap_or(0).min(visible.len() - 1);
+        let row = &viewer.tree.rows[visible[selected].0];
+        let area = popup_area(frame.area());
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(detail_lines(row))
+                .block(
+                    Block::default()
+                        .title(span::name(row).to_string())
+                        .borders(Borders::ALL),
+                )
+                .wrap(Wrap { trim: false })
+                .scroll((scroll, 0)),
+            area,
+        );
+    }
+}
+
+/// A centered rectangle covering most of `area`.
+fn popup_area(area: Rect) -> Rect {
+    let width = area.width * 4 / 5;
+    let height = area.height * 4 / 5;
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+fn detail_lines(row: &Map<String, Value>) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for field in DETAIL_FIELDS {
+        let Some(value) = row.get(field).filter(|v| !v.is_null()) else {
+            continue;
+        };
+        lines.push(Line::styled(
+            field.to_string(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ));
+        let pretty = match value {
+            Value::String(s) => s.clone(),
+            other => serde_json::to_string_pretty(other).unwrap_or_default(),
+        };
+        lines.extend(pretty.lines().map(|line| Line::raw(format!("  {line}"))));
+        lines.push(Line::raw(""));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn visible_spans_skip_collapsed_subtrees() {
+        let rows: Vec<Map<String, Value>> = serde_json::from_value(json!([
+            { "span_id": "tool", "span_parents": ["llm"], "metrics": { "start": 3.0, "end": 4.0 } },
+            { "span_id": "root", "metrics": { "start": 0.0, "end": 10.0 } },
+            { "span_id": "llm", "span_parents": ["root"], "metrics": { "start": 1.0, "end": 6.0 } },
+            { "span_id": "score", "span_parents": ["root"], "metrics": { "start": 7.0, "end": 8.0 } },
+        ]))
+        .unwrap();
+        let mut tree = TraceTree::new(rows);
+        let ids = |tree: &TraceTree| -> Vec<(String, usize)> {
+            tree.visible()
+                .into_iter()
+                .map(|(idx, depth)| {
+                    (
+                        tree.rows[idx]["span_id"].as_str().unwrap().to_string(),
+                        depth,
+                    )
+                })
+                .collect()
+        };
+        assert_eq!(
+            ids(&tree),
+            vec![
+                ("root".to_string(), 0),
+                ("llm".to_string(), 1),
+                ("tool".to_string(), 2),
+                ("score".to_string(), 1),
+            ]
+        );
+        let root_bar = tree.bar(&tree.rows[0]).unwrap();
+        assert_eq!(root_bar.chars().filter(|c| *c == '█').count(), BAR_WIDTH);
+
+        let llm = tree.visible()[1].0;
+        tree.collapsed.insert(llm);
+        assert_eq!(ids(&tree).len(), 3);
+    }
+}
@@ -0,0 +1,128 @@
+use std::io::Write;
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use dialoguer::console;
+use serde_json::{Map, Value};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::filter::WhereClause;
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::sql::query_rows;
+use crate::ui::{print_command_status, CommandStatus};
+
+use super::tail::{print_rows, tail_query, POLL_LIMIT};
+
+pub struct WatchOptions<'a> {
+    pub filter: &'a WhereClause,
+    pub notify_command: Option<&'a str>,
+    pub interval: Duration,
+}
+
+/// Poll for new spans matching the filter, printing each and alerting once per poll that
+/// finds any: by running `notify_command` with the matches as JSONL on stdin, or by
+/// ringing the terminal bell.
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    opts: &WatchOptions<'_>,
+    json: bool,
+) -> Result<()> {
+    let filter = opts.filter.to_btql();
+    // Start from the newest event so history doesn't trigger alerts.
+    let latest = query_rows(client, &tail_query(&project.id, None, None, 1)).await?;
+    let mut last = latest
+        .first()
+        .and_then(|row| row.get("_xact_id"))
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    if !json {
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Watching '{}' for {filter} (Ctrl-C to stop)",
+                project.name
+            ))
+            .dim()
+        );
+    }
+
+    loop {
+        tokio::time::sleep(opts.interval).await;
+        let query = tail_query(&project.id, Some(&filter), last.as_deref(), POLL_LIMIT);
+        let mut rows = query_rows(client, &query).await?;
+        if last.is_none() {
+            rows.reverse();
+        }
+        let Some(newest) = print_rows(&rows, json)? else {
+            continue;
+        };
+        last = Some(newest);
+        match opts.notify_command {
+            Some(command) => notify(command, &rows).await,
+            None => {
+                eprint!("\x07");
+                std::io::stderr().flush().ok();
+            }
+        }
+    }
+}
+
+/// Run the notify command; a failing command is reported but doesn't stop the watch.
+async fn notify(command: &str, rows: &[Map<String, Value>]) {
+    if let Err(err) = run_command(command, rows).await {
+        print_command_status(
+            CommandStatus::Warning,
+            &format!("notify command failed: {err:#}"),
+        );
+    }
+}
+
+async fn run_command(command: &str, rows: &[Map<String, Value>]) -> Result<()> {
+    let mut input = Vec::new();
+    for row in rows {
+        serde_json::to_writer(&mut input, row)?;
+        input.push(b'\n');
+    }
+
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    };
+    let mut child = cmd
+        .env("BT_LOGS_MATCH_COUNT", rows.len().to_string())
+        .stdin(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("failed to run '{command}'"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // A command that ignores its input may close stdin early; that's fine.
+        stdin.write_all(&input).await.ok();
+    }
+    let status = child.wait().await?;
+    if !status.success() {
+        anyhow::bail!("'{command}' exited with {status}");
+    }
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn notify_command_receives_matches_on_stdin() {
+        let rows: Vec<Map<String, Value>> =
+            serde_json::from_value(serde_json::json!([{ "id": "a" }, { "id": "b" }])).unwrap();
+        let check = "test \"$BT_LOGS_MATCH_COUNT\" = 2 && test \"$(wc -l)\" -eq 2";
+        run_command(check, &rows).await.unwrap();
+        assert!(run_command("exit 3", &rows).await.is_err());
+    }
+}
@@ -3,17 +3,27 @@ use clap::{Parser, Subcommand};
 use std::ffi::OsString;
 
 mod args;
+mod config;
+mod datasets;
 mod env;
 #[cfg(unix)]
 mod eval;
+mod experiments;
+mod filter;
+mod functions;
+mod git;
 mod http;
+mod ingest;
 mod login;
+mod logs;
 mod projects;
+mod prompts;
+mod scorers;
 mod self_update;
 mod sql;
 mod ui;
 
-use crate::args::CLIArgs;
+use crate::args::{BaseArgs, CLIArgs};
 
 #[derive(Debug, Parser)]
 #[command(name = "bt", about = "Braintrust CLI", version)]
@@ -31,22 +41,70 @@ enum Commands {
     Eval(CLIArgs<eval::EvalArgs>),
     /// Manage projects
     Projects(CLIArgs<projects::ProjectsArgs>),
+    /// Manage prompts in a project
+    Prompts(CLIArgs<prompts::PromptsArgs>),
+    /// List, invoke, and push functions in a project
+    Functions(CLIArgs<functions::FunctionsArgs>),
+    /// Manage scorers in a project
+    Scorers(CLIArgs<scorers::ScorersArgs>),
+    /// Inspect and manage experiments in a project
+    Experiments(CLIArgs<experiments::ExperimentsArgs>),
+    /// Manage datasets in a project
+    Datasets(CLIArgs<datasets::DatasetsArgs>),
+    /// Inspect a project's logs
+    Logs(CLIArgs<logs::LogsArgs>),
     #[command(name = "self")]
     /// Self-management commands
     SelfCommand(self_update::SelfArgs),
 }
 
+impl Commands {
+    fn base_mut(&mut self) -> Option<&mut BaseArgs> {
+        match self {
+            Commands::Sql(cmd) => Some(&mut cmd.base),
+            #[cfg(unix)]
+            Commands::Eval(cmd) => Some(&mut cmd.base),
+            Commands::Projects(cmd) => Some(&mut cmd.base),
+            Commands::Prompts(cmd) => Some(&mut cmd.base),
+            Commands::Functions(cmd) => Some(&mut cmd.base),
+            Commands::Scorers(cmd) => Some(&mut cmd.base),
+            Commands::Experiments(cmd) => Some(&mut cmd.base),
+            Commands::Datasets(cmd) => Some(&mut cmd.base),
+            Commands::Logs(cmd) => Some(&mut cmd.base),
+            Commands::SelfCommand(_) => None,
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let argv: Vec<OsString> = std::env::args_os().collect();
     env::bootstrap_from_args(&argv)?;
-    let cli = Cli::parse_from(argv);
+    let mut cli = Cli::parse_from(argv);
+    if let Some(base) = cli.command.base_mut() {
+        // --project and BRAINTRUST_DEFAULT_PROJECT override the project saved by `projects switch`.
+        if base.project.is_none() {
+            base.project = config::Config::load()
+                .unwrap_or_else(|err| {
+                    eprintln!("Warning: ignoring bt config: {err:#}");
+                    config::Config::default()
+                })
+                .project;
+        }
+        ui::init_answers(base)?;
+    }
 
     match cli.command {
         Commands::Sql(cmd) => sql::run(cmd.base, cmd.args).await?,
         #[cfg(unix)]
         Commands::Eval(cmd) => eval::run(cmd.base, cmd.args).await?,
         Commands::Projects(cmd) => projects::run(cmd.base, cmd.args).await?,
+        Commands::Prompts(cmd) => prompts::run(cmd.base, cmd.args).await?,
+        Commands::Functions(cmd) => functions::run(cmd.base, cmd.args).await?,
+        Commands::Scorers(cmd) => scorers::run(cmd.base, cmd.args).await?,
+        Commands::Experiments(cmd) => experiments::run(cmd.base, cmd.args).await?,
+        Commands::Datasets(cmd) => datasets::run(cmd.base, cmd.args).await?,
+        Commands::Logs(cmd) => logs::run(cmd.base, cmd.args).await?,
         Commands::SelfCommand(args) => self_update::run(args).await?,
     }
 
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use dialoguer::console;
+use serde_json::json;
+
+use crate::http::ApiClient;
+use crate::ui::{self, print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Grant, Role};
+use super::switch::find_project;
+
+const PERMISSIONS: &[&str] = &[
+    "create",
+    "read",
+    "update",
+    "delete",
+    "create_acls",
+    "read_acls",
+    "update_acls",
+    "delete_acls",
+];
+
+pub async fn list(client: &ApiClient, project: &str, json: bool) -> Result<()> {
+    let project = find_project(client, project).await?;
+    let (acls, roles) = with_spinner("Loading access...", async {
+        Ok::<_, anyhow::Error>((
+            api::list_project_acls(client, &project.id).await?,
+            api::list_roles(client).await?,
+        ))
+    })
+    .await?;
+    let role_names: HashMap<&str, &str> = roles
+        .iter()
+        .map(|r| (r.id.as_str(), r.name.as_str()))
+        .collect();
+
+    let mut emails: HashMap<String, String> = HashMap::new();
+    for user_id in acls.iter().filter_map(|acl| acl.user_id.as_deref()) {
+        if !emails.contains_key(user_id) {
+            let label = match api::get_user(client, user_id).await {
+                Ok(user) => user.email.unwrap_or_else(|| user_id.to_string()),
+                Err(_) => user_id.to_string(),
+            };
+            emails.insert(user_id.to_string(), label);
+        }
+    }
+
+    let rows: Vec<(String, String)> = acls
+        .iter()
+        .map(|acl| {
+            let who = match (&acl.user_id, &acl.group_id) {
+                (Some(user_id), _) => emails[user_id].clone(),
+                (None, Some(group_id)) => format!("group:{group_id}"),
+                (None, None) => "-".to_string(),
+            };
+            let grant = match (&acl.role_id, &acl.permission) {
+                (Some(role_id), _) => role_names
+                    .get(role_id.as_str())
+                    .map_or_else(|| role_id.clone(), |name| name.to_string()),
+                (None, Some(permission)) => permission.clone(),
+                (None, None) => "-".to_string(),
+            };
+            (who, grant)
+        })
+        .collect();
+
+    if json {
+        let rows: Vec<_> = rows
+            .iter()
+            .map(|(who, grant)| json!({ "member": who, "access": grant }))
+            .collect();
+        println!("{}", serde_json::to_string(&rows)?);
+        return Ok(());
+    }
+
+    let width = rows
+        .iter()
+        .map(|(who, _)| who.len())
+        .max()
+        .unwrap_or(0)
+        .max(6);
+    println!(
+        "{}  {}",
+        console::style(format!("{:width$}", "Member")).dim().bold(),
+        console::style("Access").dim().bold()
+    );
+    for (who, grant) in rows {
+        println!("{who:width$}  {grant}");
+    }
+    Ok(())
+}
+
+pub async fn grant(client: &ApiClient, project: &str, email: &str, role: &str) -> Result<()> {
+    let project = find_project(client, project).await?;
+    let user = find_user(client, email).await?;
+    let roles = api::list_roles(client).await?;
+    let grant = resolve_grant(&roles, role)?;
+
+    with_spinner(
+        "Granting access...",
+        api::create_project_acl(client, &project.id, &user.id, grant),
+    )
+    .await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Granted {role} on '{}' to {email}", project.name),
+    );
+    Ok(())
+}
+
+/// Remove a user's access to a project: every grant, or only those for `role`.
+pub async fn revoke(
+    client: &ApiClient,
+    project: &str,
+    email: &str,
+    role: Option<&str>,
+    yes: bool,
+) -> Result<()> {
+    let project = find_project(client, project).await?;
+    let user = find_user(client, email).await?;
+    let acls = api::list_project_acls(client, &project.id).await?;
+    let role_id = match role {
+        Some(role) => match resolve_grant(&api::list_roles(client).await?, role)? {
+            Grant::Role(id) => Some(id.to_string()),
+            Grant::Permission(permission) => Some(permission.to_string()),
+        },
+        None => None,
+    };
+
+    let matching: Vec<_> = acls
+        .iter()
+        .filter(|acl| acl.user_id.as_deref() == Some(user.id.as_str()))
+        .filter(|acl| {
+            role_id.is_none()
+                || acl.role_id.as_ref() == role_id.as_ref()
+                || acl.permission.as_ref() == role_id.as_ref()
+        })
+        .collect();
+    if matching.is_empty() {
+        bail!("{email} has no matching access to '{}'", project.name);
+    }
+
+    if !yes {
+        if !ui::is_interactive() {
+            bail!("refusing to revoke access without confirmation; pass --yes to revoke non-interactively");
+        }
+        let prompt = format!("Revoke {email}'s access to '{}'?", project.name);
+        if !ui::confirm(&prompt, false)? {
+            return Ok(());
+        }
+    }
+
+    for acl in &matching {
+        with_spinner("Revoking access...", api::delete_acl(client, &acl.id)).await?;
+    }
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Revoked access to '{}' from {email}", project.name),
+    );
+    Ok(())
+}
+
+async fn find_user(client: &ApiClient, email: &str) -> Result<api::User> {
+    with_spinner("Looking up user...", api::find_user_by_email(client, email))
+        .await?
+        .ok_or_else(|| anyhow!("no user with email '{email}' in this organization"))
+}
+
+/// Match `name` against the org's roles (case-insensitively), then the built-in permissions.
+fn resolve_grant<'a>(roles: &'a [Role], name: &'a str) -> Result<Grant<'a>> {
+    if let Some(role) = roles.iter().find(|r| r.name.eq_ignore_ascii_case(name)) {
+        return Ok(Grant::Role(&role.id));
+    }
+    if let Some(permission) = PERMISSIONS.iter().find(|p| **p == name) {
+        return Ok(Grant::Permission(permission));
+    }
+    let mut options: Vec<&str> = roles.iter().map(|r| r.name.as_str()).collect();
+    options.extend(PERMISSIONS);
+    bail!(
+        "unknown role '{name}' (expected one of: {})",
+        options.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_grant_prefers_roles_over_permissions() {
+        let roles = vec![Role {
+            id: "r1".to_string(),
+            name: "Engineer".to_string(),
+        }];
+        assert!(matches!(
+            resolve_grant(&roles, "engineer"),
+            Ok(Grant::Role("r1"))
+        ));
+        assert!(matches!(
+            resolve_grant(&roles, "read"),
+            Ok(Grant::Permission("read"))
+        ));
+        assert!(resolve_grant(&roles, "admin").is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use urlencoding::encode;
 
 use crate::http::ApiClient;
@@ -11,21 +12,48 @@ pub struct Project {
     pub org_id: String,
     #[serde(default)]
     pub description: Option<String>,
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub settings: Option<Value>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct User {
+    pub id: String,
+    #[serde(default)]
+    pub given_name: Option<String>,
+    #[serde(default)]
+    pub family_name: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+/// The `{ "objects": [...] }` envelope of every `/v1` list endpoint.
 #[derive(Debug, Deserialize)]
-struct ListResponse {
-    objects: Vec<Project>,
+pub(crate) struct ObjectList<T> {
+    pub(crate) objects: Vec<T>,
 }
 
 pub async fn list_projects(client: &ApiClient) -> Result<Vec<Project>> {
     let path = format!("/v1/project?org_name={}", encode(client.org_name()));
-    let list: ListResponse = client.get(&path).await?;
+    let list: ObjectList<Project> = client.get(&path).await?;
     Ok(list.objects)
 }
 
-pub async fn create_project(client: &ApiClient, name: &str) -> Result<Project> {
-    let body = serde_json::json!({ "name": name, "org_name": client.org_name() });
+pub async fn create_project(
+    client: &ApiClient,
+    name: &str,
+    description: Option<&str>,
+) -> Result<Project> {
+    let mut body = serde_json::json!({ "name": name, "org_name": client.org_name() });
+    if let Some(description) = description {
+        body["description"] = serde_json::json!(description);
+    }
     client.post("/v1/project", &body).await
 }
 
@@ -40,6 +68,111 @@ pub async fn get_project_by_name(client: &ApiClient, name: &str) -> Result<Optio
         encode(client.org_name()),
         encode(name)
     );
-    let list: ListResponse = client.get(&path).await?;
+    let list: ObjectList<Project> = client.get(&path).await?;
     Ok(list.objects.into_iter().next())
 }
+
+pub async fn get_user(client: &ApiClient, user_id: &str) -> Result<User> {
+    let path = format!("/v1/user/{}", encode(user_id));
+    client.get(&path).await
+}
+
+pub async fn update_project_settings(
+    client: &ApiClient,
+    project_id: &str,
+    settings: &Value,
+) -> Result<Project> {
+    let path = format!("/v1/project/{}", encode(project_id));
+    client
+        .patch(&path, &serde_json::json!({ "settings": settings }))
+        .await
+}
+
+/// List the objects of a project-scoped resource such as `prompt` or `dataset`.
+pub async fn list_project_objects(
+    client: &ApiClient,
+    resource: &str,
+    project_id: &str,
+) -> Result<Vec<Value>> {
+    let path = format!("/v1/{resource}?project_id={}", encode(project_id));
+    let list: ObjectList<Value> = client.get(&path).await?;
+    Ok(list.objects)
+}
+
+pub async fn create_object(client: &ApiClient, resource: &str, body: &Value) -> Result<Value> {
+    client.post(&format!("/v1/{resource}"), body).await
+}
+
+pub async fn find_user_by_email(client: &ApiClient, email: &str) -> Result<Option<User>> {
+    let path = format!(
+        "/v1/user?org_name={}&email={}",
+        encode(client.org_name()),
+        encode(email)
+    );
+    let users: ObjectList<User> = client.get(&path).await?;
+    Ok(users.objects.into_iter().next())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Role {
+    pub id: String,
+    pub name: String,
+}
+
+pub async fn list_roles(client: &ApiClient) -> Result<Vec<Role>> {
+    let path = format!("/v1/role?org_name={}", encode(client.org_name()));
+    let roles: ObjectList<Role> = client.get(&path).await?;
+    Ok(roles.objects)
+}
+
+/// One access grant on an object: a user or group gets either a role or a single permission.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Acl {
+    pub id: String,
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub group_id: Option<String>,
+    #[serde(default)]
+    pub role_id: Option<String>,
+    #[serde(default)]
+    pub permission: Option<String>,
+}
+
+pub async fn list_project_acls(client: &ApiClient, project_id: &str) -> Result<Vec<Acl>> {
+    let path = format!(
+        "/v1/acl?object_type=project&object_id={}",
+        encode(project_id)
+    );
+    let acls: ObjectList<Acl> = client.get(&path).await?;
+    Ok(acls.objects)
+}
+
+/// What an ACL grants: an org role (by id) or a single permission such as `read`.
+pub enum Grant<'a> {
+    Role(&'a str),
+    Permission(&'a str),
+}
+
+pub async fn create_project_acl(
+    client: &ApiClient,
+    project_id: &str,
+    user_id: &str,
+    grant: Grant<'_>,
+) -> Result<Acl> {
+    let mut body = serde_json::json!({
+        "object_type": "project",
+        "object_id": project_id,
+        "user_id": user_id,
+    });
+    match grant {
+        Grant::Role(role_id) => body["role_id"] = serde_json::json!(role_id),
+        Grant::Permission(permission) => body["permission"] = serde_json::json!(permission),
+    }
+    client.post("/v1/acl", &body).await
+}
+
+pub async fn delete_acl(client: &ApiClient, acl_id: &str) -> Result<()> {
+    let path = format!("/v1/acl/{}", encode(acl_id));
+    client.delete(&path).await
+}
@@ -0,0 +1,227 @@
+use anyhow::{anyhow, bail, Context, Result};
+use serde_json::{Map, Value};
+
+use crate::datasets::api as datasets;
+use crate::http::ApiClient;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api;
+
+const PROMPT_FIELDS: &[&str] = &[
+    "name",
+    "slug",
+    "description",
+    "prompt_data",
+    "function_type",
+    "tags",
+    "metadata",
+];
+const FUNCTION_FIELDS: &[&str] = &[
+    "name",
+    "slug",
+    "description",
+    "function_data",
+    "function_type",
+    "function_schema",
+    "tags",
+    "metadata",
+];
+const DATASET_FIELDS: &[&str] = &["name", "description", "metadata"];
+const ROW_FIELDS: &[&str] = &["id", "input", "expected", "metadata", "tags"];
+
+pub async fn run(
+    client: &ApiClient,
+    source: &str,
+    destination: &str,
+    with_datasets: bool,
+) -> Result<()> {
+    let src = with_spinner(
+        "Loading project...",
+        api::get_project_by_name(client, source),
+    )
+    .await?
+    .ok_or_else(|| anyhow!("project '{source}' not found"))?;
+    if api::get_project_by_name(client, destination)
+        .await?
+        .is_some()
+    {
+        bail!("project '{destination}' already exists");
+    }
+
+    let dst = with_spinner(
+        "Creating project...",
+        api::create_project(client, destination, src.description.as_deref()),
+    )
+    .await?;
+    if let Some(settings) = src.settings.as_ref().filter(|s| !s.is_null()) {
+        api::update_project_settings(client, &dst.id, settings)
+            .await
+            .context("failed to copy project settings")?;
+    }
+
+    let mut failures = Vec::new();
+
+    let prompts = api::list_project_objects(client, "prompt", &src.id).await?;
+    let copied_prompts = copy_objects(
+        client,
+        "prompt",
+        &prompts,
+        PROMPT_FIELDS,
+        &dst.id,
+        &mut failures,
+    )
+    .await;
+
+    // Prompt-based scorers were copied above; only code scorers remain.
+    let scorers: Vec<Value> = api::list_project_objects(client, "function", &src.id)
+        .await?
+        .into_iter()
+        .filter(|f| f["function_type"] == "scorer" && f["function_data"]["type"] != "prompt")
+        .collect();
+    let copied_scorers = copy_objects(
+        client,
+        "function",
+        &scorers,
+        FUNCTION_FIELDS,
+        &dst.id,
+        &mut failures,
+    )
+    .await;
+
+    let mut summary = format!(
+        "Cloned '{source}' to '{destination}' ({copied_prompts} prompts, {copied_scorers} scorers"
+    );
+    if with_datasets {
+        let datasets = api::list_project_objects(client, "dataset", &src.id).await?;
+        let mut copied = 0;
+        for dataset in &datasets {
+            let name = object_name(dataset);
+            match with_spinner(
+                &format!("Copying dataset '{name}'..."),
+                copy_dataset(client, dataset, &dst.id),
+            )
+            .await
+            {
+                Ok(()) => copied += 1,
+                Err(err) => failures.push(format!("dataset '{name}': {err}")),
+            }
+        }
+        summary.push_str(&format!(", {copied} datasets"));
+    }
+    summary.push(')');
+
+    for failure in &failures {
+        print_command_status(CommandStatus::Warning, &format!("Failed to copy {failure}"));
+    }
+    if !failures.is_empty() {
+        print_command_status(CommandStatus::Warning, &summary);
+        bail!("failed to copy {} object(s)", failures.len());
+    }
+    print_command_status(CommandStatus::Success, &summary);
+    Ok(())
+}
+
+/// Recreate `objects` in the destination project, recording failures instead of stopping.
+async fn copy_objects(
+    client: &ApiClient,
+    resource: &str,
+    objects: &[Value],
+    fields: &[&str],
+    project_id: &str,
+    failures: &mut Vec<String>,
+) -> usize {
+    let mut copied = 0;
+    for object in objects {
+        let body = with_project(pick(object, fields), project_id);
+        match api::create_object(client, resource, &body).await {
+            Ok(_) => copied += 1,
+            Err(err) => failures.push(format!("{resource} '{}': {err}", object_name(object))),
+        }
+    }
+    copied
+}
+
+async fn copy_dataset(client: &ApiClient, dataset: &Value, project_id: &str) -> Result<()> {
+    let source_id = dataset["id"].as_str().context("dataset is missing an id")?;
+    let created = api::create_object(
+        client,
+        "dataset",
+        &with_project(pick(dataset, DATASET_FIELDS), project_id),
+    )
+    .await?;
+    let target_id = created["id"]
+        .as_str()
+        .context("created dataset is missing an id")?;
+
+    let mut cursor: Option<String> = None;
+    loop {
+        let (rows, next) =
+            datasets::fetch_rows(client, source_id, 1000, cursor.as_deref(), None).await?;
+        if rows.is_empty() {
+            break;
+        }
+        let rows: Vec<Value> = rows
+            .iter()
+            .map(|row| Value::Object(pick(row, ROW_FIELDS)))
+            .collect();
+        datasets::insert_rows(client, target_id, &rows).await?;
+        match next {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    Ok(())
+}
+
+/// The non-null `fields` of `object`.
+fn pick(object: &Value, fields: &[&str]) -> Map<String, Value> {
+    fields
+        .iter()
+        .filter_map(|field| {
+            object
+                .get(*field)
+                .filter(|v| !v.is_null())
+                .map(|v| (field.to_string(), v.clone()))
+        })
+        .collect()
+}
+
+fn with_project(mut body: Map<String, Value>, project_id: &str) -> Value {
+    body.insert(
+        "project_id".to_string(),
+        Value::String(project_id.to_string()),
+    );
+    Value::Object(body)
+}
+
+fn object_name(object: &Value) -> &str {
+    object["name"].as_str().unwrap_or("<unnamed>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn pick_drops_ids_and_nulls() {
+        let prompt = json!({
+            "id": "p1",
+            "project_id": "src",
+            "name": "greet",
+            "slug": "greet",
+            "description": null,
+            "prompt_data": { "prompt": { "type": "chat" } },
+        });
+        let body = with_project(pick(&prompt, PROMPT_FIELDS), "dst");
+        assert_eq!(
+            body,
+            json!({
+                "name": "greet",
+                "slug": "greet",
+                "prompt_data": { "prompt": { "type": "chat" } },
+                "project_id": "dst",
+            })
+        );
+    }
+}
@@ -1,22 +1,26 @@
-use std::io::IsTerminal;
 use std::time::Duration;
 
 use anyhow::{bail, Result};
-use dialoguer::Input;
 
 use crate::http::ApiClient;
-use crate::ui::{print_command_status, with_spinner, with_spinner_visible, CommandStatus};
+use crate::ui::{self, print_command_status, with_spinner, with_spinner_visible, CommandStatus};
 
 use super::api;
 
-pub async fn run(client: &ApiClient, name: Option<&str>) -> Result<()> {
+pub async fn run(
+    client: &ApiClient,
+    name: Option<&str>,
+    description: Option<&str>,
+    if_not_exists: bool,
+    json: bool,
+) -> Result<()> {
     let name = match name {
         Some(n) if !n.is_empty() => n.to_string(),
         _ => {
-            if !std::io::stdin().is_terminal() {
+            if !ui::is_interactive() {
                 bail!("project name required. Use: bt projects create <name>");
             }
-            Input::new().with_prompt("Project name").interact_text()?
+            ui::input("Project name")?
         }
     };
 
@@ -26,22 +30,37 @@ pub async fn run(client: &ApiClient, name: Option<&str>) -> Result<()> {
         api::get_project_by_name(client, &name),
     )
     .await?;
-    if exists.is_some() {
-        bail!("project '{name}' already exists");
+    if let Some(project) = exists {
+        if !if_not_exists {
+            bail!("project '{name}' already exists");
+        }
+        if json {
+            println!("{}", serde_json::to_string(&project)?);
+        } else {
+            print_command_status(
+                CommandStatus::Success,
+                &format!("Project '{name}' already exists ({})", project.id),
+            );
+        }
+        return Ok(());
     }
 
     match with_spinner_visible(
         "Creating project...",
-        api::create_project(client, &name),
+        api::create_project(client, &name, description),
         Duration::from_millis(300),
     )
     .await
     {
-        Ok(_) => {
-            print_command_status(
-                CommandStatus::Success,
-                &format!("Successfully created '{name}'"),
-            );
+        Ok(project) => {
+            if json {
+                println!("{}", serde_json::to_string(&project)?);
+            } else {
+                print_command_status(
+                    CommandStatus::Success,
+                    &format!("Successfully created '{name}'"),
+                );
+            }
             Ok(())
         }
         Err(e) => {
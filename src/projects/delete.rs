@@ -1,63 +1,115 @@
-use std::io::IsTerminal;
-
-use anyhow::{bail, Result};
-use dialoguer::Confirm;
+use anyhow::{anyhow, bail, Result};
 
+use crate::filter::NameFilter;
 use crate::http::ApiClient;
-use crate::ui::{print_command_status, with_spinner, CommandStatus};
-
-use super::api;
-use super::switch::select_project_interactive;
-
-pub async fn run(client: &ApiClient, name: Option<&str>) -> Result<()> {
-    let project = match name {
-        Some(n) => with_spinner("Loading project...", api::get_project_by_name(client, n))
-            .await?
-            .ok_or_else(|| anyhow::anyhow!("project '{n}' not found"))?,
-        None => {
-            if !std::io::stdin().is_terminal() {
-                bail!("project name required. Use: bt projects delete <name>");
-            }
-            let name = select_project_interactive(client).await?;
-            with_spinner(
-                "Loading project...",
-                api::get_project_by_name(client, &name),
-            )
-            .await?
-            .ok_or_else(|| anyhow::anyhow!("project '{name}' not found"))?
-        }
-    };
+use crate::ui::{self, print_command_status, with_spinner, CommandStatus};
 
-    if std::io::stdin().is_terminal() {
-        let confirm = Confirm::new()
-            .with_prompt(format!("Delete project '{}'?", project.name))
-            .default(false)
-            .interact()?;
+use super::api::{self, Project};
 
-        if !confirm {
-            return Ok(());
+pub async fn run(
+    client: &ApiClient,
+    names: &[String],
+    filter: Option<&NameFilter>,
+    yes: bool,
+) -> Result<()> {
+    let projects = select_projects(client, names, filter).await?;
+    if projects.is_empty() {
+        if filter.is_some() {
+            bail!("no projects match the filter");
         }
+        return Ok(());
     }
 
-    match with_spinner(
-        "Deleting project...",
-        api::delete_project(client, &project.id),
-    )
-    .await
-    {
-        Ok(_) => {
-            print_command_status(
+    if !confirm_delete(&projects, yes)? {
+        return Ok(());
+    }
+
+    delete_all(client, &projects).await
+}
+
+/// Ask before deleting `projects`; refuses when there is nobody to ask unless `yes` is set.
+pub(super) fn confirm_delete(projects: &[Project], yes: bool) -> Result<bool> {
+    if yes {
+        return Ok(true);
+    }
+    if !ui::is_interactive() {
+        bail!("refusing to delete without confirmation; pass --yes to delete non-interactively");
+    }
+    let prompt = match projects {
+        [project] => format!("Delete project '{}'?", project.name),
+        _ => {
+            let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+            format!("Delete {} projects ({})?", names.len(), names.join(", "))
+        }
+    };
+    ui::confirm(&prompt, false)
+}
+
+/// Delete `projects`, printing a line per project and failing if any deletion failed.
+pub(super) async fn delete_all(client: &ApiClient, projects: &[Project]) -> Result<()> {
+    let mut failed = 0;
+    for project in projects {
+        match with_spinner(
+            &format!("Deleting '{}'...", project.name),
+            api::delete_project(client, &project.id),
+        )
+        .await
+        {
+            Ok(_) => print_command_status(
                 CommandStatus::Success,
                 &format!("Deleted '{}'", project.name),
-            );
-            Ok(())
+            ),
+            Err(e) => {
+                failed += 1;
+                print_command_status(
+                    CommandStatus::Error,
+                    &format!("Failed to delete '{}': {e}", project.name),
+                );
+            }
         }
-        Err(e) => {
-            print_command_status(
-                CommandStatus::Error,
-                &format!("Failed to delete '{}'", project.name),
-            );
-            Err(e)
+    }
+
+    match (failed, projects.len()) {
+        (0, _) => Ok(()),
+        (1, 1) => bail!("failed to delete '{}'", projects[0].name),
+        (failed, total) => bail!("failed to delete {failed} of {total} projects"),
+    }
+}
+
+async fn select_projects(
+    client: &ApiClient,
+    names: &[String],
+    filter: Option<&NameFilter>,
+) -> Result<Vec<Project>> {
+    if !names.is_empty() {
+        let mut projects = Vec::with_capacity(names.len());
+        for name in names {
+            let project =
+                with_spinner("Loading project...", api::get_project_by_name(client, name))
+                    .await?
+                    .ok_or_else(|| anyhow!("project '{name}' not found"))?;
+            projects.push(project);
         }
+        return Ok(projects);
+    }
+
+    let mut projects = with_spinner("Loading projects...", api::list_projects(client)).await?;
+    projects.sort_by(|a, b| a.name.cmp(&b.name));
+    if let Some(filter) = filter {
+        projects.retain(|p| filter.matches(&p.name));
+        return Ok(projects);
+    }
+
+    if !ui::is_interactive() {
+        bail!("project name required. Use: bt projects delete <name>");
+    }
+    if projects.is_empty() {
+        bail!("no projects found");
     }
+    let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+    let selected = ui::multi_select("Select projects to delete", &names)?;
+    Ok(selected
+        .into_iter()
+        .map(|idx| projects[idx].clone())
+        .collect())
 }
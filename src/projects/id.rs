@@ -0,0 +1,26 @@
+use anyhow::Result;
+use serde_json::{Map, Value};
+
+use crate::http::ApiClient;
+
+use super::switch::find_project;
+
+pub async fn run(client: &ApiClient, names: &[String], json: bool) -> Result<()> {
+    let mut projects = Vec::with_capacity(names.len());
+    for name in names {
+        projects.push(find_project(client, name).await?);
+    }
+
+    if json {
+        let ids: Map<String, Value> = projects
+            .into_iter()
+            .map(|p| (p.name, Value::String(p.id)))
+            .collect();
+        println!("{}", serde_json::to_string(&ids)?);
+    } else {
+        for project in &projects {
+            println!("{}", project.id);
+        }
+    }
+    Ok(())
+}
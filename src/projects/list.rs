@@ -1,16 +1,56 @@
 use anyhow::Result;
+use clap::ValueEnum;
 use dialoguer::console;
 use unicode_width::UnicodeWidthStr;
 
+use crate::filter::NameFilter;
 use crate::http::ApiClient;
 use crate::ui::with_spinner;
 
 use super::api;
+use super::tags::TagStore;
 
-pub async fn run(client: &ApiClient, org_name: &str, json: bool) -> Result<()> {
-    let projects = with_spinner("Loading projects...", api::list_projects(client)).await?;
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum SortKey {
+    /// Project name
+    Name,
+    /// Creation time
+    Created,
+    /// Last update time (creation time for projects never updated)
+    Updated,
+}
+
+#[derive(Default)]
+pub struct ListOptions {
+    pub filter: Option<NameFilter>,
+    pub tag: Option<String>,
+    pub sort: Option<SortKey>,
+    pub desc: bool,
+    pub ids: bool,
+}
+
+pub async fn run(client: &ApiClient, org_name: &str, json: bool, opts: &ListOptions) -> Result<()> {
+    let mut projects = with_spinner("Loading projects...", api::list_projects(client)).await?;
+    if let Some(filter) = &opts.filter {
+        projects.retain(|p| filter.matches(&p.name));
+    }
+    let tags = TagStore::load()?;
+    if let Some(tag) = &opts.tag {
+        projects.retain(|p| tags.get(&p.id).contains(tag));
+    }
+    if let Some(key) = opts.sort {
+        sort_projects(&mut projects, key, opts.desc);
+    }
 
     if json {
+        let projects = projects
+            .iter()
+            .map(|p| {
+                let mut value = serde_json::to_value(p)?;
+                value["tags"] = serde_json::json!(tags.get(&p.id));
+                Ok(value)
+            })
+            .collect::<Result<Vec<_>>>()?;
         println!("{}", serde_json::to_string(&projects)?);
     } else {
         println!(
@@ -26,13 +66,40 @@ pub async fn run(client: &ApiClient, org_name: &str, json: bool) -> Result<()> {
             .max()
             .unwrap_or(20)
             .max(20);
+        let tag_width = projects
+            .iter()
+            .map(|p| tags.get(&p.id).join(", ").width())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+        let id_width = if opts.ids {
+            projects
+                .iter()
+                .map(|p| p.id.len())
+                .max()
+                .unwrap_or(0)
+                .max(2)
+        } else {
+            0
+        };
 
         // Print header
+        let id_header = if opts.ids {
+            format!(
+                "{}  ",
+                console::style(format!("{:id_width$}", "ID")).dim().bold()
+            )
+        } else {
+            String::new()
+        };
         println!(
-            "{}  {}",
+            "{}  {id_header}{}  {}",
             console::style(format!("{:width$}", "Project name", width = name_width))
                 .dim()
                 .bold(),
+            console::style(format!("{:width$}", "Tags", width = tag_width))
+                .dim()
+                .bold(),
             console::style("Description").dim().bold()
         );
 
@@ -44,10 +111,22 @@ pub async fn run(client: &ApiClient, org_name: &str, json: bool) -> Result<()> {
                 .filter(|s| !s.is_empty())
                 .unwrap_or("-");
             let padding = name_width - project.name.width();
+            let project_tags = tags.get(&project.id).join(", ");
+            let project_tags = if project_tags.is_empty() {
+                "-".to_string()
+            } else {
+                project_tags
+            };
+            let id = if opts.ids {
+                format!("{:id_width$}  ", project.id)
+            } else {
+                String::new()
+            };
             println!(
-                "{}{:padding$}  {}",
+                "{}{:padding$}  {id}{:tag_width$}  {}",
                 project.name,
                 "",
+                project_tags,
                 desc,
                 padding = padding
             );
@@ -56,3 +135,55 @@ pub async fn run(client: &ApiClient, org_name: &str, json: bool) -> Result<()> {
 
     Ok(())
 }
+
+/// Sort by `key`, keeping projects without the timestamp last in either direction.
+fn sort_projects(projects: &mut [api::Project], key: SortKey, desc: bool) {
+    match key {
+        SortKey::Name => projects.sort_by_key(|p| p.name.to_lowercase()),
+        SortKey::Created | SortKey::Updated => projects.sort_by(|a, b| {
+            let time = |p: &api::Project| match key {
+                SortKey::Updated => p.updated.clone().or_else(|| p.created.clone()),
+                _ => p.created.clone(),
+            };
+            match (time(a), time(b)) {
+                (Some(a), Some(b)) if desc => b.cmp(&a),
+                (Some(a), Some(b)) => a.cmp(&b),
+                (a, b) => b.is_some().cmp(&a.is_some()),
+            }
+        }),
+    }
+    if desc && key == SortKey::Name {
+        projects.reverse();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(name: &str, created: Option<&str>) -> api::Project {
+        serde_json::from_value(serde_json::json!({
+            "id": name,
+            "name": name,
+            "org_id": "org",
+            "created": created,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn sort_by_created_keeps_missing_last() {
+        let mut projects = vec![
+            project("b", Some("2024-02-01T00:00:00Z")),
+            project("c", None),
+            project("a", Some("2024-03-01T00:00:00Z")),
+        ];
+        sort_projects(&mut projects, SortKey::Created, true);
+        let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+
+        sort_projects(&mut projects, SortKey::Created, false);
+        let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["b", "a", "c"]);
+    }
+}
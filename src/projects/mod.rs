@@ -1,16 +1,28 @@
+use std::time::Duration;
+
 use anyhow::Result;
 use clap::{Args, Subcommand};
 
 use crate::args::BaseArgs;
+use crate::filter::NameFilter;
 use crate::http::ApiClient;
 use crate::login::login;
 
-mod api;
+mod access;
+pub(crate) mod api;
+mod clone;
 mod create;
 mod delete;
+mod id;
 mod list;
+mod prune;
+mod settings;
+mod show;
+mod stats;
 mod switch;
+pub(crate) mod tags;
 mod view;
+mod watch;
 
 #[derive(Debug, Clone, Args)]
 pub struct ProjectsArgs {
@@ -21,21 +33,198 @@ pub struct ProjectsArgs {
 #[derive(Debug, Clone, Subcommand)]
 enum ProjectsCommands {
     /// List all projects
-    List,
+    List(ListArgs),
     /// Create a new project
     Create(CreateArgs),
+    /// Print the IDs of projects, one per line
+    Id(IdArgs),
+    /// Show a project's details and settings
+    Show(ShowArgs),
+    /// Read or change project settings such as the comparison key
+    #[command(subcommand)]
+    Settings(SettingsCommands),
+    /// Summarize a project's experiments, datasets, and logs
+    Stats(StatsArgs),
+    /// Create a project with a copy of another project's prompts, scorers, and settings
+    Clone(CloneArgs),
     /// Open a project in the browser
     View(ViewArgs),
-    /// Delete a project
+    /// Delete one or more projects
     Delete(DeleteArgs),
-    /// Switch to a project
+    /// Find projects with no experiments, datasets, logs, functions, or prompts and delete them
+    Prune(PruneArgs),
+    /// Make a project the default for later commands
     Switch(SwitchArgs),
+    /// List, grant, or revoke members' access to a project
+    #[command(subcommand)]
+    Access(AccessCommands),
+    /// Print new experiments and datasets as they appear in a project
+    Watch(WatchArgs),
+    /// Manage project tags (local to this machine, not shared)
+    ///
+    /// Tags are kept in project_tags.json in the bt config directory. They are not
+    /// stored in Braintrust, so teammates and other machines won't see them.
+    #[command(subcommand)]
+    Tag(TagCommands),
+}
+
+#[derive(Debug, Clone, Args)]
+struct WatchArgs {
+    /// Project name
+    name: String,
+
+    /// Seconds between checks
+    #[arg(
+        long,
+        default_value_t = 10,
+        value_name = "SECONDS",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    interval: u64,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum AccessCommands {
+    /// List who has access to a project
+    List(AccessListArgs),
+    /// Give a user a role or permission on a project
+    Grant(AccessGrantArgs),
+    /// Remove a user's access to a project
+    Revoke(AccessRevokeArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+struct AccessListArgs {
+    /// Project name
+    project: String,
+}
+
+#[derive(Debug, Clone, Args)]
+struct AccessGrantArgs {
+    /// Project name
+    project: String,
+
+    /// Email of the user to grant access to
+    #[arg(long, value_name = "EMAIL")]
+    user: String,
+
+    /// Org role name, or a permission such as read or update
+    #[arg(long)]
+    role: String,
+}
+
+#[derive(Debug, Clone, Args)]
+struct AccessRevokeArgs {
+    /// Project name
+    project: String,
+
+    /// Email of the user to revoke access from
+    #[arg(long, value_name = "EMAIL")]
+    user: String,
+
+    /// Only revoke this role or permission (revokes all of the user's access when omitted)
+    #[arg(long)]
+    role: Option<String>,
+
+    /// Skip the confirmation prompt (required when not running interactively)
+    #[arg(long, short = 'y', visible_alias = "force")]
+    yes: bool,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum TagCommands {
+    /// Add tags to a project
+    Add(TagArgs),
+    /// Remove tags from a project
+    Remove(TagArgs),
+    /// List a project's tags, or every tag and its projects
+    List(TagListArgs),
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum SettingsCommands {
+    /// Print a project's settings, or a single setting
+    Get(SettingsGetArgs),
+    /// Change a single setting
+    Set(SettingsSetArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+struct SettingsGetArgs {
+    /// Project name
+    project: String,
+
+    /// Setting to print (prints all settings when omitted)
+    key: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct SettingsSetArgs {
+    /// Project name
+    project: String,
+
+    /// Setting to change, e.g. comparison_key
+    key: String,
+
+    /// New value, parsed as JSON when possible (`null` clears the setting)
+    value: String,
+}
+
+#[derive(Debug, Clone, Args)]
+struct TagArgs {
+    /// Project name
+    project: String,
+
+    /// Tags to add or remove
+    #[arg(required = true)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct TagListArgs {
+    /// Project name (lists all tags when omitted)
+    project: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct ListArgs {
+    /// Only show projects whose name contains this text or matches this glob
+    #[arg(long, value_name = "PATTERN")]
+    filter: Option<String>,
+
+    /// Treat --filter as a regular expression
+    #[arg(long, requires = "filter")]
+    regex: bool,
+
+    /// Only show projects with this local tag (see `bt projects tag`)
+    #[arg(long, value_name = "TAG")]
+    tag: Option<String>,
+
+    /// Sort projects by this field (defaults to the API's order)
+    #[arg(long, value_enum, value_name = "FIELD")]
+    sort: Option<list::SortKey>,
+
+    /// Sort in descending order
+    #[arg(long, requires = "sort")]
+    desc: bool,
+
+    /// Show each project's ID
+    #[arg(long)]
+    ids: bool,
 }
 
 #[derive(Debug, Clone, Args)]
 struct CreateArgs {
     /// Name of the project to create
     name: Option<String>,
+
+    /// Project description
+    #[arg(long, short = 'd')]
+    description: Option<String>,
+
+    /// Succeed without changes if the project already exists
+    #[arg(long)]
+    if_not_exists: bool,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -47,6 +236,10 @@ struct ViewArgs {
     /// Project name (flag)
     #[arg(long = "name", short = 'n')]
     name_flag: Option<String>,
+
+    /// Open this section of the project instead of its overview
+    #[arg(long, value_enum)]
+    section: Option<view::Section>,
 }
 
 impl ViewArgs {
@@ -58,16 +251,95 @@ impl ViewArgs {
 }
 
 #[derive(Debug, Clone, Args)]
-struct DeleteArgs {
-    /// Name of the project to delete
+struct CloneArgs {
+    /// Project to copy from
+    source: String,
+
+    /// Name of the new project
+    destination: String,
+
+    /// Also copy datasets and their rows
+    #[arg(long)]
+    with_datasets: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct IdArgs {
+    /// Project names
+    #[arg(required = true)]
+    names: Vec<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct ShowArgs {
+    /// Name of the project to show
     name: Option<String>,
 }
 
+#[derive(Debug, Clone, Args)]
+struct StatsArgs {
+    /// Name of the project to summarize
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct DeleteArgs {
+    /// Names of the projects to delete (pick interactively when omitted)
+    #[arg(conflicts_with = "filter")]
+    names: Vec<String>,
+
+    /// Delete every project whose name contains this text or matches this glob
+    #[arg(long, value_name = "PATTERN")]
+    filter: Option<String>,
+
+    /// Treat --filter as a regular expression
+    #[arg(long, requires = "filter")]
+    regex: bool,
+
+    /// Skip the confirmation prompt (required when not running interactively)
+    #[arg(long, short = 'y', visible_alias = "force")]
+    yes: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct PruneArgs {
+    /// Delete every empty project without prompting
+    #[arg(long, short = 'y', visible_alias = "force", conflicts_with = "dry_run")]
+    yes: bool,
+
+    /// Only list the empty projects
+    #[arg(long)]
+    dry_run: bool,
+}
+
 #[derive(Debug, Clone, Args)]
 struct SwitchArgs {
     /// Project name
     #[arg(long = "name", short = 'n')]
     name: Option<String>,
+
+    /// Print a shell `export` line instead of saving the project to the bt config file
+    #[arg(long)]
+    export: bool,
+}
+
+/// The project named by `--project` (or the active project), picked interactively when unset.
+pub(crate) async fn resolve_project(
+    client: &ApiClient,
+    name: Option<&str>,
+) -> Result<api::Project> {
+    match name {
+        Some(name) => switch::find_project(client, name).await,
+        None => {
+            if !crate::ui::is_interactive() {
+                anyhow::bail!(
+                    "no project selected. Pass --project or run: bt projects switch <name>"
+                );
+            }
+            let name = switch::select_project_interactive(client).await?;
+            switch::find_project(client, &name).await
+        }
+    }
 }
 
 pub async fn run(base: BaseArgs, args: ProjectsArgs) -> Result<()> {
@@ -75,14 +347,90 @@ pub async fn run(base: BaseArgs, args: ProjectsArgs) -> Result<()> {
     let client = ApiClient::new(&ctx)?;
 
     match args.command {
-        None | Some(ProjectsCommands::List) => {
-            list::run(&client, &ctx.login.org_name, base.json).await
+        None => {
+            let opts = list::ListOptions::default();
+            list::run(&client, &ctx.login.org_name, base.json, &opts).await
+        }
+        Some(ProjectsCommands::List(a)) => {
+            let filter = a
+                .filter
+                .as_deref()
+                .map(|pattern| NameFilter::new(pattern, a.regex))
+                .transpose()?;
+            let opts = list::ListOptions {
+                filter,
+                tag: a.tag,
+                sort: a.sort,
+                desc: a.desc,
+                ids: a.ids,
+            };
+            list::run(&client, &ctx.login.org_name, base.json, &opts).await
+        }
+        Some(ProjectsCommands::Create(a)) => {
+            create::run(
+                &client,
+                a.name.as_deref(),
+                a.description.as_deref(),
+                a.if_not_exists,
+                base.json,
+            )
+            .await
+        }
+        Some(ProjectsCommands::Id(a)) => id::run(&client, &a.names, base.json).await,
+        Some(ProjectsCommands::Show(a)) => show::run(&client, a.name.as_deref(), base.json).await,
+        Some(ProjectsCommands::Settings(SettingsCommands::Get(a))) => {
+            settings::get(&client, &a.project, a.key.as_deref(), base.json).await
+        }
+        Some(ProjectsCommands::Settings(SettingsCommands::Set(a))) => {
+            settings::set(&client, &a.project, &a.key, &a.value).await
+        }
+        Some(ProjectsCommands::Stats(a)) => stats::run(&client, a.name.as_deref(), base.json).await,
+        Some(ProjectsCommands::Clone(a)) => {
+            clone::run(&client, &a.source, &a.destination, a.with_datasets).await
         }
-        Some(ProjectsCommands::Create(a)) => create::run(&client, a.name.as_deref()).await,
         Some(ProjectsCommands::View(a)) => {
-            view::run(&client, &ctx.app_url, &ctx.login.org_name, a.name()).await
+            view::run(
+                &client,
+                &ctx.app_url,
+                &ctx.login.org_name,
+                a.name(),
+                a.section,
+            )
+            .await
+        }
+        Some(ProjectsCommands::Delete(a)) => {
+            let filter = a
+                .filter
+                .as_deref()
+                .map(|pattern| NameFilter::new(pattern, a.regex))
+                .transpose()?;
+            delete::run(&client, &a.names, filter.as_ref(), a.yes).await
+        }
+        Some(ProjectsCommands::Prune(a)) => prune::run(&client, a.yes, a.dry_run).await,
+        Some(ProjectsCommands::Switch(a)) => {
+            switch::run(&client, a.name.as_deref(), a.export).await
+        }
+        Some(ProjectsCommands::Access(AccessCommands::List(a))) => {
+            access::list(&client, &a.project, base.json).await
+        }
+        Some(ProjectsCommands::Access(AccessCommands::Grant(a))) => {
+            access::grant(&client, &a.project, &a.user, &a.role).await
+        }
+        Some(ProjectsCommands::Access(AccessCommands::Revoke(a))) => {
+            access::revoke(&client, &a.project, &a.user, a.role.as_deref(), a.yes).await
+        }
+        Some(ProjectsCommands::Watch(a)) => {
+            let interval = Duration::from_secs(a.interval);
+            watch::run(&client, &a.name, interval, base.json).await
+        }
+        Some(ProjectsCommands::Tag(TagCommands::Add(a))) => {
+            tags::add(&client, &a.project, &a.tags).await
+        }
+        Some(ProjectsCommands::Tag(TagCommands::Remove(a))) => {
+            tags::remove(&client, &a.project, &a.tags).await
+        }
+        Some(ProjectsCommands::Tag(TagCommands::List(a))) => {
+            tags::list(&client, a.project.as_deref(), base.json).await
         }
-        Some(ProjectsCommands::Delete(a)) => delete::run(&client, a.name.as_deref()).await,
-        Some(ProjectsCommands::Switch(a)) => switch::run(&client, a.name.as_deref()).await,
     }
 }
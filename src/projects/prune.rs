@@ -0,0 +1,75 @@
+use anyhow::Result;
+
+use crate::http::ApiClient;
+use crate::ui::{self, print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Project};
+use super::delete::{confirm_delete, delete_all};
+use super::stats;
+
+/// Find projects with no experiments, datasets, logs, functions, or prompts and offer to delete them.
+pub async fn run(client: &ApiClient, yes: bool, dry_run: bool) -> Result<()> {
+    let mut projects = with_spinner("Loading projects...", api::list_projects(client)).await?;
+    projects.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let empty = with_spinner(
+        &format!("Checking {} projects...", projects.len()),
+        find_empty(client, projects),
+    )
+    .await;
+    if empty.is_empty() {
+        println!("No empty projects found");
+        return Ok(());
+    }
+
+    println!("{} empty projects:", empty.len());
+    for project in &empty {
+        println!("  {}", project.name);
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    let selected = if yes || !ui::is_interactive() {
+        empty
+    } else {
+        let names: Vec<&str> = empty.iter().map(|p| p.name.as_str()).collect();
+        let picked = ui::multi_select("Select projects to delete", &names)?;
+        picked.into_iter().map(|idx| empty[idx].clone()).collect()
+    };
+    if selected.is_empty() || !confirm_delete(&selected, yes)? {
+        return Ok(());
+    }
+    delete_all(client, &selected).await
+}
+
+async fn find_empty(client: &ApiClient, projects: Vec<Project>) -> Vec<Project> {
+    let mut empty = Vec::new();
+    for project in projects {
+        match is_empty(client, &project).await {
+            Ok(true) => empty.push(project),
+            Ok(false) => {}
+            Err(err) => print_command_status(
+                CommandStatus::Warning,
+                &format!("Skipping '{}': {err}", project.name),
+            ),
+        }
+    }
+    empty
+}
+
+async fn is_empty(client: &ApiClient, project: &Project) -> Result<bool> {
+    let stats = stats::collect(client, project).await?;
+    if stats.experiments > 0 || stats.datasets > 0 || stats.spans > 0 {
+        return Ok(false);
+    }
+    for resource in ["function", "prompt"] {
+        if !api::list_project_objects(client, resource, &project.id)
+            .await?
+            .is_empty()
+        {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
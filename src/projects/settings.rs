@@ -0,0 +1,102 @@
+use anyhow::{bail, Result};
+use dialoguer::console;
+use serde_json::{Map, Value};
+
+use crate::http::ApiClient;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Project};
+use super::switch::find_project;
+
+fn settings_of(project: &Project) -> Map<String, Value> {
+    project
+        .settings
+        .as_ref()
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default()
+}
+
+pub async fn get(client: &ApiClient, project: &str, key: Option<&str>, json: bool) -> Result<()> {
+    let settings = settings_of(&find_project(client, project).await?);
+
+    if let Some(key) = key {
+        let value = settings.get(key).cloned().unwrap_or(Value::Null);
+        match value {
+            Value::String(s) if !json => println!("{s}"),
+            value => println!("{value}"),
+        }
+        return Ok(());
+    }
+
+    if json {
+        println!("{}", serde_json::to_string(&settings)?);
+    } else if settings.is_empty() {
+        println!("No settings configured for '{project}'");
+    } else {
+        let width = settings.keys().map(String::len).max().unwrap_or(0);
+        for (key, value) in &settings {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            println!(
+                "{}  {value}",
+                console::style(format!("{key:width$}")).dim().bold()
+            );
+        }
+    }
+    Ok(())
+}
+
+pub async fn set(client: &ApiClient, project: &str, key: &str, value: &str) -> Result<()> {
+    if key.is_empty() {
+        bail!("setting name can't be empty");
+    }
+    let project = find_project(client, project).await?;
+    let mut settings = settings_of(&project);
+    apply_setting(&mut settings, key, value);
+
+    with_spinner(
+        "Updating settings...",
+        api::update_project_settings(client, &project.id, &Value::Object(settings)),
+    )
+    .await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Updated {key} for '{}'", project.name),
+    );
+    Ok(())
+}
+
+/// Set `key` to `value` parsed as JSON (falling back to a plain string); `null` removes it.
+fn apply_setting(settings: &mut Map<String, Value>, key: &str, value: &str) {
+    match serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string())) {
+        Value::Null => {
+            settings.remove(key);
+        }
+        value => {
+            settings.insert(key.to_string(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn apply_setting_parses_json_and_clears_null() {
+        let mut settings = Map::new();
+        apply_setting(&mut settings, "comparison_key", "input.question");
+        apply_setting(&mut settings, "spanFieldOrder", r#"["input","output"]"#);
+        assert_eq!(
+            Value::Object(settings.clone()),
+            json!({ "comparison_key": "input.question", "spanFieldOrder": ["input", "output"] })
+        );
+
+        apply_setting(&mut settings, "comparison_key", "null");
+        assert!(!settings.contains_key("comparison_key"));
+    }
+}
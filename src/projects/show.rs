@@ -0,0 +1,94 @@
+use anyhow::{anyhow, bail, Result};
+use dialoguer::console;
+use serde_json::Value;
+
+use crate::http::ApiClient;
+use crate::ui::{self, with_spinner};
+
+use super::api::{self, Project};
+use super::switch::select_project_interactive;
+
+pub async fn run(client: &ApiClient, name: Option<&str>, json: bool) -> Result<()> {
+    let name = match name {
+        Some(n) => n.to_string(),
+        None => {
+            if !ui::is_interactive() {
+                bail!("project name required. Use: bt projects show <name>");
+            }
+            select_project_interactive(client).await?
+        }
+    };
+
+    let project = with_spinner(
+        "Loading project...",
+        api::get_project_by_name(client, &name),
+    )
+    .await?
+    .ok_or_else(|| anyhow!("project '{name}' not found"))?;
+
+    if json {
+        println!("{}", serde_json::to_string(&project)?);
+        return Ok(());
+    }
+
+    // The creator is informational, so fall back to the raw id if it can't be resolved.
+    let created_by = match project.user_id.as_deref() {
+        Some(id) => Some(match api::get_user(client, id).await {
+            Ok(user) => user_label(&user).unwrap_or_else(|| id.to_string()),
+            Err(_) => id.to_string(),
+        }),
+        None => None,
+    };
+
+    for (label, value) in detail_rows(&project, created_by.as_deref()) {
+        println!(
+            "{}  {value}",
+            console::style(format!("{label:16}")).dim().bold()
+        );
+    }
+
+    Ok(())
+}
+
+fn detail_rows(project: &Project, created_by: Option<&str>) -> Vec<(String, String)> {
+    let or_dash = |value: Option<&str>| value.filter(|s| !s.is_empty()).unwrap_or("-").to_string();
+    let mut rows = vec![
+        ("Name".to_string(), project.name.clone()),
+        ("ID".to_string(), project.id.clone()),
+        (
+            "Description".to_string(),
+            or_dash(project.description.as_deref()),
+        ),
+        ("Created".to_string(), or_dash(project.created.as_deref())),
+        ("Created by".to_string(), or_dash(created_by)),
+    ];
+
+    match project.settings.as_ref().and_then(Value::as_object) {
+        Some(settings) if !settings.is_empty() => {
+            for (key, value) in settings {
+                let value = match value {
+                    Value::String(s) => s.clone(),
+                    Value::Null => "-".to_string(),
+                    other => other.to_string(),
+                };
+                rows.push((format!("settings.{key}"), value));
+            }
+        }
+        _ => rows.push(("Settings".to_string(), "-".to_string())),
+    }
+    rows
+}
+
+fn user_label(user: &api::User) -> Option<String> {
+    let name = [user.given_name.as_deref(), user.family_name.as_deref()]
+        .into_iter()
+        .flatten()
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    match (name.is_empty(), user.email.as_deref()) {
+        (false, Some(email)) => Some(format!("{name} <{email}>")),
+        (false, None) => Some(name),
+        (true, email) => email.map(str::to_string),
+    }
+}
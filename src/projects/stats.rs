@@ -0,0 +1,131 @@
+use anyhow::{anyhow, bail, Result};
+use dialoguer::console;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::http::ApiClient;
+use crate::sql::{query_rows, quote_literal};
+use crate::ui::{self, with_spinner};
+
+use super::api;
+use super::switch::select_project_interactive;
+
+#[derive(Debug, Serialize)]
+pub(super) struct ProjectStats {
+    project: String,
+    pub experiments: usize,
+    last_experiment: Option<String>,
+    pub datasets: usize,
+    last_dataset: Option<String>,
+    pub spans: u64,
+    last_log: Option<String>,
+    last_activity: Option<String>,
+}
+
+pub async fn run(client: &ApiClient, name: Option<&str>, json: bool) -> Result<()> {
+    let name = match name {
+        Some(n) => n.to_string(),
+        None => {
+            if !ui::is_interactive() {
+                bail!("project name required. Use: bt projects stats <name>");
+            }
+            select_project_interactive(client).await?
+        }
+    };
+
+    let project = with_spinner(
+        "Loading project...",
+        api::get_project_by_name(client, &name),
+    )
+    .await?
+    .ok_or_else(|| anyhow!("project '{name}' not found"))?;
+    let stats = with_spinner("Collecting stats...", collect(client, &project)).await?;
+
+    if json {
+        println!("{}", serde_json::to_string(&stats)?);
+        return Ok(());
+    }
+
+    let with_last = |count: String, last: &Option<String>| match last {
+        Some(last) => format!("{count} (latest {last})"),
+        None => count,
+    };
+    let rows = [
+        (
+            "Experiments",
+            with_last(stats.experiments.to_string(), &stats.last_experiment),
+        ),
+        (
+            "Datasets",
+            with_last(stats.datasets.to_string(), &stats.last_dataset),
+        ),
+        (
+            "Logged spans",
+            with_last(stats.spans.to_string(), &stats.last_log),
+        ),
+        (
+            "Last activity",
+            stats
+                .last_activity
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+    ];
+    println!("{}\n", console::style(&stats.project).bold());
+    for (label, value) in rows {
+        println!(
+            "{}  {value}",
+            console::style(format!("{label:14}")).dim().bold()
+        );
+    }
+    Ok(())
+}
+
+pub(super) async fn collect(client: &ApiClient, project: &api::Project) -> Result<ProjectStats> {
+    let experiments = api::list_project_objects(client, "experiment", &project.id).await?;
+    let datasets = api::list_project_objects(client, "dataset", &project.id).await?;
+    let logs = query_rows(
+        client,
+        &format!(
+            "SELECT COUNT(1) AS spans, MAX(created) AS last_log FROM project_logs({})",
+            quote_literal(&project.id)
+        ),
+    )
+    .await?;
+    let logs = logs.first();
+
+    let last_experiment = latest_created(&experiments);
+    let last_dataset = latest_created(&datasets);
+    let last_log = logs
+        .and_then(|row| row.get("last_log"))
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    let last_activity = [&last_experiment, &last_dataset, &last_log]
+        .into_iter()
+        .flatten()
+        .max()
+        .cloned();
+
+    Ok(ProjectStats {
+        project: project.name.clone(),
+        experiments: experiments.len(),
+        last_experiment,
+        datasets: datasets.len(),
+        last_dataset,
+        spans: logs
+            .and_then(|row| row.get("spans"))
+            .and_then(Value::as_u64)
+            .unwrap_or(0),
+        last_log,
+        last_activity,
+    })
+}
+
+/// The most recent `created` timestamp. ISO 8601 timestamps sort lexicographically.
+fn latest_created(objects: &[Value]) -> Option<String> {
+    objects
+        .iter()
+        .filter_map(|o| o["created"].as_str())
+        .max()
+        .map(str::to_string)
+}
@@ -1,14 +1,12 @@
-use std::io::IsTerminal;
-
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 
+use crate::config::Config;
 use crate::http::ApiClient;
-use crate::ui;
-use crate::ui::with_spinner;
+use crate::ui::{self, print_command_status, with_spinner, CommandStatus};
 
-use super::api;
+use super::api::{self, Project};
 
-pub async fn run(client: &ApiClient, name: Option<&str>) -> Result<()> {
+pub async fn run(client: &ApiClient, name: Option<&str>, export: bool) -> Result<()> {
     let project_name = match name {
         Some(n) => {
             // Check if project exists
@@ -16,17 +14,15 @@ pub async fn run(client: &ApiClient, name: Option<&str>) -> Result<()> {
                 with_spinner("Loading project...", api::get_project_by_name(client, n)).await?;
             if exists.is_none() {
                 // Offer to create
-                if !std::io::stdin().is_terminal() {
+                if !ui::is_interactive() {
                     bail!("project '{n}' not found");
                 }
 
-                let create = dialoguer::Confirm::new()
-                    .with_prompt(format!("Project '{n}' not found. Create it?"))
-                    .default(false)
-                    .interact()?;
+                let create = ui::confirm(&format!("Project '{n}' not found. Create it?"), false)?;
 
                 if create {
-                    with_spinner("Creating project...", api::create_project(client, n)).await?;
+                    with_spinner("Creating project...", api::create_project(client, n, None))
+                        .await?;
                 } else {
                     bail!("project '{n}' not found");
                 }
@@ -36,11 +32,25 @@ pub async fn run(client: &ApiClient, name: Option<&str>) -> Result<()> {
         None => select_project_interactive(client).await?,
     };
 
-    ui::print_env_export(
-        "BRAINTRUST_DEFAULT_PROJECT",
-        &project_name,
+    if export {
+        ui::print_env_export(
+            "BRAINTRUST_DEFAULT_PROJECT",
+            &project_name,
+            &format!("Switched to {project_name}"),
+        );
+        return Ok(());
+    }
+
+    let mut config = Config::load()?;
+    config.project = Some(project_name.clone());
+    config.save()?;
+    print_command_status(
+        CommandStatus::Success,
         &format!("Switched to {project_name}"),
     );
+    if std::env::var_os("BRAINTRUST_DEFAULT_PROJECT").is_some() {
+        eprintln!("Note: BRAINTRUST_DEFAULT_PROJECT is set and takes precedence in this shell");
+    }
     Ok(())
 }
 
@@ -56,3 +66,10 @@ pub async fn select_project_interactive(client: &ApiClient) -> Result<String> {
     let selection = ui::fuzzy_select("Select project", &names)?;
     Ok(projects[selection].name.clone())
 }
+
+/// Look up a project by name, failing if it doesn't exist.
+pub async fn find_project(client: &ApiClient, name: &str) -> Result<Project> {
+    with_spinner("Loading project...", api::get_project_by_name(client, name))
+        .await?
+        .ok_or_else(|| anyhow!("project '{name}' not found"))
+}
@@ -0,0 +1,175 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use dialoguer::console;
+
+use crate::config::config_dir;
+use crate::http::ApiClient;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+use super::api;
+use super::switch::find_project;
+
+/// Tags keyed by project id, stored in `project_tags.json` in the bt config directory.
+/// They exist only on this machine; Braintrust itself has no project tags.
+#[derive(Debug, Default)]
+pub struct TagStore {
+    path: PathBuf,
+    tags: BTreeMap<String, BTreeSet<String>>,
+    /// Set when the file exists but couldn't be read, so `save` won't overwrite it.
+    unreadable: bool,
+}
+
+impl TagStore {
+    /// Load the store, warning about and ignoring a file that can't be read or parsed.
+    pub fn load() -> Result<Self> {
+        let path = config_dir()
+            .map(|dir| dir.join("project_tags.json"))
+            .context("failed to resolve bt config directory")?;
+        let tags = match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .with_context(|| format!("failed to parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        };
+        Ok(match tags {
+            Ok(tags) => Self {
+                path,
+                tags,
+                unreadable: false,
+            },
+            Err(err) => {
+                eprintln!("Warning: ignoring tags: {err:#}");
+                Self {
+                    path,
+                    tags: BTreeMap::new(),
+                    unreadable: true,
+                }
+            }
+        })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = &self.path;
+        if self.unreadable {
+            bail!(
+                "refusing to overwrite unreadable {}; fix or delete it first",
+                path.display()
+            );
+        }
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(&self.tags)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, id: &str) -> Vec<String> {
+        self.tags
+            .get(id)
+            .map(|tags| tags.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn add(&mut self, id: &str, tags: &[String]) {
+        self.tags
+            .entry(id.to_string())
+            .or_default()
+            .extend(tags.iter().cloned());
+    }
+
+    pub fn remove(&mut self, id: &str, tags: &[String]) {
+        if let Some(existing) = self.tags.get_mut(id) {
+            for tag in tags {
+                existing.remove(tag);
+            }
+            if existing.is_empty() {
+                self.tags.remove(id);
+            }
+        }
+    }
+}
+
+pub fn validate_tag(tag: &str) -> Result<()> {
+    if tag.is_empty() || tag.chars().any(|c| c.is_whitespace() || c == ',') {
+        bail!("invalid tag '{tag}' (tags can't be empty or contain spaces or commas)");
+    }
+    Ok(())
+}
+
+pub async fn add(client: &ApiClient, project: &str, tags: &[String]) -> Result<()> {
+    tags.iter().try_for_each(|tag| validate_tag(tag))?;
+    let project = find_project(client, project).await?;
+    let mut store = TagStore::load()?;
+    store.add(&project.id, tags);
+    store.save()?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Tagged '{}' with {}", project.name, tags.join(", ")),
+    );
+    Ok(())
+}
+
+pub async fn remove(client: &ApiClient, project: &str, tags: &[String]) -> Result<()> {
+    let project = find_project(client, project).await?;
+    let mut store = TagStore::load()?;
+    store.remove(&project.id, tags);
+    store.save()?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Removed {} from '{}'", tags.join(", "), project.name),
+    );
+    Ok(())
+}
+
+/// Print a project's tags, or every tag with the projects carrying it.
+pub async fn list(client: &ApiClient, project: Option<&str>, json: bool) -> Result<()> {
+    let store = TagStore::load()?;
+    if let Some(project) = project {
+        let tags = store.get(&find_project(client, project).await?.id);
+        if json {
+            println!("{}", serde_json::to_string(&tags)?);
+        } else {
+            for tag in tags {
+                println!("{tag}");
+            }
+        }
+        return Ok(());
+    }
+
+    let projects = with_spinner("Loading projects...", api::list_projects(client)).await?;
+    let mut by_tag: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for project in &projects {
+        for tag in store.get(&project.id) {
+            by_tag.entry(tag).or_default().push(project.name.clone());
+        }
+    }
+    if json {
+        println!("{}", serde_json::to_string(&by_tag)?);
+    } else {
+        for (tag, names) in &by_tag {
+            println!("{}  {}", console::style(tag).bold(), names.join(", "));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_and_remove_tags() {
+        let mut store = TagStore::default();
+        store.add("p1", &["team-a".to_string(), "prod".to_string()]);
+        store.add("p1", &["prod".to_string()]);
+        assert_eq!(store.get("p1"), vec!["prod", "team-a"]);
+
+        store.remove("p1", &["prod".to_string(), "team-a".to_string()]);
+        assert!(store.get("p1").is_empty());
+        assert!(store.tags.is_empty());
+        assert!(validate_tag("a b").is_err());
+    }
+}
@@ -1,24 +1,46 @@
-use std::io::IsTerminal;
-
 use anyhow::{bail, Result};
+use clap::ValueEnum;
 use urlencoding::encode;
 
 use crate::http::ApiClient;
-use crate::ui::{print_command_status, with_spinner, CommandStatus};
+use crate::ui::{self, print_command_status, with_spinner, CommandStatus};
 
 use super::api;
 use super::switch::select_project_interactive;
 
+/// A page within a project in the Braintrust app.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Section {
+    Logs,
+    Experiments,
+    Datasets,
+    Prompts,
+    Playgrounds,
+}
+
+impl Section {
+    fn path(self) -> &'static str {
+        match self {
+            Section::Logs => "logs",
+            Section::Experiments => "experiments",
+            Section::Datasets => "datasets",
+            Section::Prompts => "prompts",
+            Section::Playgrounds => "playgrounds",
+        }
+    }
+}
+
 pub async fn run(
     client: &ApiClient,
     app_url: &str,
     org_name: &str,
     name: Option<&str>,
+    section: Option<Section>,
 ) -> Result<()> {
     let project_name = match name {
         Some(n) => n.to_string(),
         None => {
-            if !std::io::stdin().is_terminal() {
+            if !ui::is_interactive() {
                 bail!("project name required. Use: bt projects view <name>")
             }
             select_project_interactive(client).await?
@@ -35,12 +57,16 @@ pub async fn run(
         bail!("project '{project_name}' not found");
     }
 
-    let url = format!(
+    let mut url = format!(
         "{}/app/{}/p/{}",
         app_url.trim_end_matches('/'),
         encode(org_name),
         encode(&project_name)
     );
+    if let Some(section) = section {
+        url.push('/');
+        url.push_str(section.path());
+    }
 
     open::that(&url)?;
     print_command_status(CommandStatus::Success, &format!("Opened {url} in browser"));
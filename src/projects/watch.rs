@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use anyhow::Result;
+use dialoguer::console;
+use serde_json::Value;
+
+use crate::http::ApiClient;
+
+use super::api;
+use super::switch::find_project;
+
+const WATCHED: &[&str] = &["experiment", "dataset"];
+
+/// Poll a project and print a line for every experiment or dataset created after startup.
+pub async fn run(client: &ApiClient, name: &str, interval: Duration, json: bool) -> Result<()> {
+    let project = find_project(client, name).await?;
+
+    let mut seen = HashSet::new();
+    for kind in WATCHED {
+        for object in api::list_project_objects(client, kind, &project.id).await? {
+            if let Some(id) = object["id"].as_str() {
+                seen.insert(id.to_string());
+            }
+        }
+    }
+    eprintln!(
+        "{}",
+        console::style(format!(
+            "Watching '{}' for new experiments and datasets every {}s (Ctrl+C to stop)",
+            project.name,
+            interval.as_secs()
+        ))
+        .dim()
+    );
+
+    loop {
+        tokio::time::sleep(interval).await;
+        for kind in WATCHED {
+            let objects = match api::list_project_objects(client, kind, &project.id).await {
+                Ok(objects) => objects,
+                Err(err) => {
+                    eprintln!("Error: {err}");
+                    continue;
+                }
+            };
+            let mut new: Vec<&Value> = objects
+                .iter()
+                .filter(|o| o["id"].as_str().is_some_and(|id| !seen.contains(id)))
+                .collect();
+            new.sort_by_key(|o| o["created"].as_str().unwrap_or_default().to_string());
+            for object in new {
+                seen.insert(object["id"].as_str().unwrap_or_default().to_string());
+                print_new(kind, object, json)?;
+            }
+        }
+    }
+}
+
+fn print_new(kind: &str, object: &Value, json: bool) -> Result<()> {
+    if json {
+        let mut event = object.clone();
+        event["type"] = Value::String(kind.to_string());
+        println!("{}", serde_json::to_string(&event)?);
+        return Ok(());
+    }
+    let created = object["created"].as_str().unwrap_or("-");
+    let name = object["name"].as_str().unwrap_or("<unnamed>");
+    println!(
+        "{}  {}  {name}",
+        console::style(created).dim(),
+        console::style(format!("{kind:10}")).cyan()
+    );
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use urlencoding::encode;
+
+use crate::http::ApiClient;
+use crate::projects::api::ObjectList;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Prompt {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub project_id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub created: Option<String>,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    #[serde(default)]
+    pub prompt_data: Option<Value>,
+}
+
+pub async fn list_prompts(client: &ApiClient, project_id: &str) -> Result<Vec<Prompt>> {
+    let path = format!("/v1/prompt?project_id={}", encode(project_id));
+    let list: ObjectList<Prompt> = client.get(&path).await?;
+    Ok(list.objects)
+}
+
+pub async fn get_prompt_by_slug(
+    client: &ApiClient,
+    project_id: &str,
+    slug: &str,
+) -> Result<Option<Prompt>> {
+    let path = format!(
+        "/v1/prompt?project_id={}&slug={}",
+        encode(project_id),
+        encode(slug)
+    );
+    let list: ObjectList<Prompt> = client.get(&path).await?;
+    Ok(list.objects.into_iter().next())
+}
+
+pub async fn delete_prompt(client: &ApiClient, prompt_id: &str) -> Result<()> {
+    let path = format!("/v1/prompt/{}", encode(prompt_id));
+    client.delete(&path).await
+}
@@ -0,0 +1,30 @@
+use anyhow::{bail, Result};
+
+use crate::http::ApiClient;
+use crate::ui::{self, print_command_status, with_spinner, CommandStatus};
+
+use super::api::{self, Prompt};
+
+pub async fn run(client: &ApiClient, prompt: &Prompt, yes: bool) -> Result<()> {
+    if !yes {
+        if !ui::is_interactive() {
+            bail!(
+                "refusing to delete without confirmation; pass --yes to delete non-interactively"
+            );
+        }
+        if !ui::confirm(&format!("Delete prompt '{}'?", prompt.slug), false)? {
+            return Ok(());
+        }
+    }
+
+    with_spinner(
+        &format!("Deleting '{}'...", prompt.slug),
+        api::delete_prompt(client, &prompt.id),
+    )
+    .await?;
+    print_command_status(
+        CommandStatus::Success,
+        &format!("Deleted '{}'", prompt.slug),
+    );
+    Ok(())
+}
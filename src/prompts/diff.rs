@@ -0,0 +1,161 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use dialoguer::console;
+use similar::TextDiff;
+
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::with_spinner;
+
+use super::api;
+use super::spec::{self, PromptSpec};
+
+/// Print a unified diff between each local prompt file and the project's prompt with the same slug.
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    paths: &[PathBuf],
+    json: bool,
+) -> Result<()> {
+    let files = spec::find_files(paths)?;
+    if files.is_empty() {
+        bail!("no prompt files found");
+    }
+    let remote = with_spinner("Loading prompts...", api::list_prompts(client, &project.id)).await?;
+
+    let mut results = Vec::new();
+    for path in &files {
+        let local = PromptSpec::load(path)
+            .map_err(|err| err.context(format!("invalid prompt file {}", path.display())))?;
+        let remote = remote
+            .iter()
+            .find(|p| p.slug == local.slug)
+            .map(PromptSpec::from_remote)
+            .transpose()?;
+        let diff = match &remote {
+            Some(remote) => unified_diff(&local.slug, &path.display().to_string(), remote, &local)?,
+            None => None,
+        };
+        results.push((path, local.slug, remote.is_some(), diff));
+    }
+
+    let changed = results
+        .iter()
+        .filter(|(_, _, exists, diff)| !exists || diff.is_some())
+        .count();
+    if json {
+        let value: Vec<_> = results
+            .iter()
+            .map(|(path, slug, exists, diff)| {
+                let status = match (exists, diff) {
+                    (false, _) => "new",
+                    (true, Some(_)) => "changed",
+                    (true, None) => "unchanged",
+                };
+                serde_json::json!({
+                    "path": path,
+                    "slug": slug,
+                    "status": status,
+                    "diff": diff,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string(&value)?);
+    } else {
+        for (path, slug, exists, diff) in &results {
+            match (exists, diff) {
+                (false, _) => println!(
+                    "{} {} ({}) is not in '{}'",
+                    console::style("+").green(),
+                    console::style(slug).bold(),
+                    path.display(),
+                    project.name
+                ),
+                (true, Some(diff)) => print!("{}", colorize(diff)),
+                (true, None) => {}
+            }
+        }
+        println!(
+            "{} of {} prompts differ from '{}'",
+            console::style(changed).bold(),
+            results.len(),
+            project.name
+        );
+    }
+
+    if changed > 0 {
+        bail!("{changed} prompt(s) differ from the remote project");
+    }
+    Ok(())
+}
+
+/// A unified diff from `remote` to `local` as YAML, or `None` when they match.
+fn unified_diff(
+    slug: &str,
+    path: &str,
+    remote: &PromptSpec,
+    local: &PromptSpec,
+) -> Result<Option<String>> {
+    let before = serde_yaml::to_string(remote)?;
+    let after = serde_yaml::to_string(local)?;
+    if before == after {
+        return Ok(None);
+    }
+    let diff = TextDiff::from_lines(&before, &after);
+    Ok(Some(
+        diff.unified_diff()
+            .context_radius(3)
+            .header(&format!("remote/{slug}"), path)
+            .to_string(),
+    ))
+}
+
+fn colorize(diff: &str) -> String {
+    diff.lines()
+        .map(|line| {
+            let styled = match line.as_bytes().first() {
+                Some(b'+') if !line.starts_with("+++") => console::style(line).green(),
+                Some(b'-') if !line.starts_with("---") => console::style(line).red(),
+                Some(b'@') => console::style(line).cyan(),
+                _ if line.starts_with("+++") || line.starts_with("---") => {
+                    console::style(line).bold()
+                }
+                _ => console::style(line),
+            };
+            format!("{styled}\n")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::spec::Message;
+    use super::*;
+
+    #[test]
+    fn unified_diff_shows_changed_messages() {
+        let remote = PromptSpec {
+            slug: "greeter".to_string(),
+            name: Some("greeter".to_string()),
+            description: None,
+            model: Some("gpt-4o".to_string()),
+            params: Default::default(),
+            messages: vec![Message {
+                role: "user".to_string(),
+                content: Some("Hi".into()),
+            }],
+        };
+        assert!(unified_diff("greeter", "g.yaml", &remote, &remote)
+            .unwrap()
+            .is_none());
+
+        let mut local = remote.clone();
+        local.messages[0].content = Some("Hello".into());
+        let diff = unified_diff("greeter", "g.yaml", &remote, &local)
+            .unwrap()
+            .unwrap();
+        assert!(diff.starts_with("--- remote/greeter\n+++ g.yaml\n"));
+        assert!(diff.contains("-  content: Hi\n+  content: Hello\n"));
+    }
+}
@@ -0,0 +1,328 @@
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+
+use super::api::Prompt;
+use super::render::render_prompt;
+
+/// Anthropic requires `max_tokens`; used when the prompt doesn't set one.
+const DEFAULT_MAX_TOKENS: u64 = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// OpenAI Chat Completions request body
+    Openai,
+    /// Anthropic Messages request body
+    Anthropic,
+}
+
+/// Print the prompt as a request body for `format`, rendering its templates when `vars` are given.
+pub fn run(
+    prompt: &Prompt,
+    format: ExportFormat,
+    vars: &[(String, Value)],
+    json: bool,
+) -> Result<()> {
+    let data = prompt.prompt_data.as_ref();
+    let messages = if vars.is_empty() {
+        template_messages(data)?
+    } else {
+        let vars: Map<String, Value> = vars.iter().cloned().collect();
+        let mut missing = Vec::new();
+        let rendered = render_prompt(data, &vars, &mut missing)?;
+        if !missing.is_empty() {
+            eprintln!("warning: no value for {}", missing.join(", "));
+        }
+        as_messages(rendered)
+    };
+    let Some(model) = data
+        .and_then(|d| d.pointer("/options/model"))
+        .and_then(Value::as_str)
+    else {
+        bail!("prompt '{}' has no model set", prompt.slug);
+    };
+    let params = data
+        .and_then(|d| d.pointer("/options/params"))
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+    // Braintrust stores tools as a JSON string in the OpenAI format.
+    let tools = match data
+        .and_then(|d| d.pointer("/prompt/tools"))
+        .and_then(Value::as_str)
+        .filter(|t| !t.trim().is_empty())
+    {
+        Some(tools) => Some(serde_json::from_str::<Vec<Value>>(tools)?),
+        None => None,
+    };
+
+    let body = match format {
+        ExportFormat::Openai => openai_body(model, messages, &params, tools),
+        ExportFormat::Anthropic => anthropic_body(model, messages, &params, tools),
+    };
+    if json {
+        println!("{}", serde_json::to_string(&body)?);
+    } else {
+        println!("{}", serde_json::to_string_pretty(&body)?);
+    }
+    Ok(())
+}
+
+/// The prompt's messages with templates left in place; completion prompts become one user message.
+fn template_messages(data: Option<&Value>) -> Result<Vec<Value>> {
+    let Some(prompt) = data.and_then(|d| d.get("prompt")) else {
+        bail!("prompt has no messages to export");
+    };
+    if let Some(content) = prompt.get("content") {
+        return Ok(as_messages(content.clone()));
+    }
+    match prompt.get("messages").and_then(Value::as_array) {
+        Some(messages) => Ok(messages.clone()),
+        None => bail!("prompt has no messages to export"),
+    }
+}
+
+fn as_messages(rendered: Value) -> Vec<Value> {
+    match rendered {
+        Value::Array(messages) => messages,
+        content => vec![json!({ "role": "user", "content": content })],
+    }
+}
+
+fn openai_body(
+    model: &str,
+    messages: Vec<Value>,
+    params: &Map<String, Value>,
+    tools: Option<Vec<Value>>,
+) -> Value {
+    let mut body = Map::new();
+    body.insert("model".to_string(), json!(model));
+    body.insert("messages".to_string(), Value::Array(messages));
+    for (key, value) in params {
+        // Braintrust-only settings that OpenAI would reject.
+        if key != "use_cache" {
+            body.insert(key.clone(), value.clone());
+        }
+    }
+    if let Some(tools) = tools {
+        body.insert("tools".to_string(), Value::Array(tools));
+    }
+    Value::Object(body)
+}
+
+fn anthropic_body(
+    model: &str,
+    messages: Vec<Value>,
+    params: &Map<String, Value>,
+    tools: Option<Vec<Value>>,
+) -> Value {
+    let mut system = Vec::new();
+    let mut converted = Vec::new();
+    for message in messages {
+        let role = message
+            .get("role")
+            .and_then(Value::as_str)
+            .unwrap_or("user");
+        match role {
+            "system" | "developer" => system.push(text_of(message.get("content"))),
+            "tool" => {
+                let result = json!({
+                    "type": "tool_result",
+                    "tool_use_id": message.get("tool_call_id"),
+                    "content": text_of(message.get("content")),
+                });
+                // Anthropic expects every result for one assistant turn in a single user message.
+                match converted.last_mut() {
+                    Some(last) if is_tool_results(last) => last["content"]
+                        .as_array_mut()
+                        .expect("tool results are a list")
+                        .push(result),
+                    _ => converted.push(json!({ "role": "user", "content": [result] })),
+                }
+            }
+            "assistant" => converted.push(assistant_message(&message)),
+            _ => converted.push(json!({
+                "role": "user",
+                "content": anthropic_content(message.get("content")),
+            })),
+        }
+    }
+
+    let mut body = Map::new();
+    body.insert("model".to_string(), json!(model));
+    let max_tokens = params
+        .get("max_tokens")
+        .or_else(|| params.get("max_completion_tokens"))
+        .and_then(|value| {
+            // Prompts saved from the UI can store whole numbers as floats.
+            value
+                .as_u64()
+                .or_else(|| value.as_f64().filter(|n| *n >= 1.0).map(|n| n as u64))
+        })
+        .unwrap_or(DEFAULT_MAX_TOKENS);
+    body.insert("max_tokens".to_string(), json!(max_tokens));
+    if !system.is_empty() {
+        body.insert("system".to_string(), json!(system.join("\n\n")));
+    }
+    body.insert("messages".to_string(), Value::Array(converted));
+    for key in ["temperature", "top_p", "top_k"] {
+        if let Some(value) = params.get(key) {
+            body.insert(key.to_string(), value.clone());
+        }
+    }
+    match params.get("stop") {
+        Some(Value::String(stop)) => {
+            body.insert("stop_sequences".to_string(), json!([stop]));
+        }
+        Some(stop @ Value::Array(_)) => {
+            body.insert("stop_sequences".to_string(), stop.clone());
+        }
+        _ => {}
+    }
+    if let Some(tools) = tools {
+        let tools: Vec<Value> = tools
+            .iter()
+            .filter_map(|tool| tool.get("function"))
+            .map(|function| {
+                json!({
+                    "name": function.get("name"),
+                    "description": function.get("description"),
+                    "input_schema": function
+                        .get("parameters")
+                        .cloned()
+                        .unwrap_or_else(|| json!({ "type": "object" })),
+                })
+            })
+            .collect();
+        body.insert("tools".to_string(), Value::Array(tools));
+    }
+    Value::Object(body)
+}
+
+/// Whether `message` is a user message holding only `tool_result` blocks.
+fn is_tool_results(message: &Value) -> bool {
+    message["role"] == "user"
+        && message["content"]
+            .as_array()
+            .is_some_and(|parts| parts.iter().all(|part| part["type"] == "tool_result"))
+}
+
+/// An assistant message, with OpenAI tool calls as `tool_use` blocks.
+fn assistant_message(message: &Value) -> Value {
+    let mut content = match message.get("content") {
+        None | Some(Value::Null) => Vec::new(),
+        other => match anthropic_content(other) {
+            Value::Array(parts) => parts,
+            text => vec![json!({ "type": "text", "text": text })],
+        },
+    };
+    for call in message
+        .get("tool_calls")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let arguments = call
+            .pointer("/function/arguments")
+            .and_then(Value::as_str)
+            .and_then(|args| serde_json::from_str(args).ok())
+            .unwrap_or_else(|| json!({}));
+        content.push(json!({
+            "type": "tool_use",
+            "id": call.get("id"),
+            "name": call.pointer("/function/name"),
+            "input": arguments,
+        }));
+    }
+    json!({ "role": "assistant", "content": content })
+}
+
+/// Message content in Anthropic's shape: text stays a string, image URLs become image blocks.
+fn anthropic_content(content: Option<&Value>) -> Value {
+    match content {
+        Some(Value::Array(parts)) => Value::Array(
+            parts
+                .iter()
+                .map(|part| match part.get("type").and_then(Value::as_str) {
+                    Some("image_url") => json!({
+                        "type": "image",
+                        "source": { "type": "url", "url": part.pointer("/image_url/url") },
+                    }),
+                    _ => part.clone(),
+                })
+                .collect(),
+        ),
+        Some(Value::String(text)) => json!(text),
+        Some(other) => json!(other.to_string()),
+        None => json!(""),
+    }
+}
+
+fn text_of(content: Option<&Value>) -> String {
+    match content {
+        Some(Value::String(text)) => text.clone(),
+        Some(Value::Array(parts)) => parts
+            .iter()
+            .filter_map(|part| part.get("text").and_then(Value::as_str))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Some(other) => other.to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anthropic_body_moves_system_prompt_and_maps_params() {
+        let messages = vec![
+            json!({ "role": "system", "content": "Be terse." }),
+            json!({ "role": "user", "content": "Hi {{name}}" }),
+        ];
+        let params = json!({ "temperature": 0, "stop": "END", "use_cache": true });
+        let body = anthropic_body(
+            "claude-sonnet",
+            messages.clone(),
+            params.as_object().unwrap(),
+            None,
+        );
+        assert_eq!(
+            body,
+            json!({
+                "model": "claude-sonnet",
+                "max_tokens": DEFAULT_MAX_TOKENS,
+                "system": "Be terse.",
+                "messages": [{ "role": "user", "content": "Hi {{name}}" }],
+                "temperature": 0,
+                "stop_sequences": ["END"],
+            })
+        );
+
+        let body = openai_body("gpt-4o", messages, params.as_object().unwrap(), None);
+        assert_eq!(body["temperature"], 0);
+        assert!(body.get("use_cache").is_none());
+        assert_eq!(body["messages"][0]["role"], "system");
+    }
+
+    #[test]
+    fn anthropic_body_groups_tool_results_and_reads_float_max_tokens() {
+        let messages = vec![
+            json!({ "role": "user", "content": "Weather?" }),
+            json!({ "role": "tool", "tool_call_id": "a", "content": "sunny" }),
+            json!({ "role": "tool", "tool_call_id": "b", "content": "warm" }),
+        ];
+        let params = json!({ "max_tokens": 256.0 });
+        let body = anthropic_body("claude-sonnet", messages, params.as_object().unwrap(), None);
+        assert_eq!(body["max_tokens"], 256);
+        assert_eq!(body["messages"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            body["messages"][1]["content"],
+            json!([
+                { "type": "tool_result", "tool_use_id": "a", "content": "sunny" },
+                { "type": "tool_result", "tool_use_id": "b", "content": "warm" },
+            ])
+        );
+    }
+}
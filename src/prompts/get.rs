@@ -0,0 +1,113 @@
+use anyhow::Result;
+use dialoguer::console;
+use serde_json::Value;
+
+use crate::experiments::format_created;
+
+use super::api::Prompt;
+
+pub fn run(prompt: &Prompt, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string(prompt)?);
+        return Ok(());
+    }
+
+    for (label, value) in detail_rows(prompt) {
+        println!(
+            "{}  {value}",
+            console::style(format!("{label:12}")).dim().bold()
+        );
+    }
+    for (role, content) in messages(prompt.prompt_data.as_ref()) {
+        println!("\n{}", console::style(role).cyan().bold());
+        println!("{content}");
+    }
+    Ok(())
+}
+
+fn detail_rows(prompt: &Prompt) -> Vec<(&'static str, String)> {
+    let or_dash = |value: Option<&str>| value.filter(|s| !s.is_empty()).unwrap_or("-").to_string();
+    let model = prompt
+        .prompt_data
+        .as_ref()
+        .and_then(|data| data.pointer("/options/model"))
+        .and_then(Value::as_str);
+    let tags = prompt.tags.as_deref().unwrap_or_default().join(", ");
+    vec![
+        ("Name", prompt.name.clone()),
+        ("Slug", prompt.slug.clone()),
+        ("ID", prompt.id.clone()),
+        ("Description", or_dash(prompt.description.as_deref())),
+        ("Model", or_dash(model)),
+        ("Tags", or_dash(Some(&tags))),
+        ("Created", format_created(prompt.created.as_deref())),
+    ]
+}
+
+/// The prompt's chat messages as (role, text), or its completion text under `prompt`.
+fn messages(prompt_data: Option<&Value>) -> Vec<(String, String)> {
+    let Some(prompt) = prompt_data.and_then(|data| data.get("prompt")) else {
+        return Vec::new();
+    };
+    if let Some(content) = prompt.get("content").and_then(Value::as_str) {
+        return vec![("prompt".to_string(), content.to_string())];
+    }
+    let Some(messages) = prompt.get("messages").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    messages
+        .iter()
+        .map(|message| {
+            let role = message
+                .get("role")
+                .and_then(Value::as_str)
+                .unwrap_or("message")
+                .to_string();
+            let content = match message.get("content") {
+                Some(Value::String(s)) => s.clone(),
+                // Multimodal content is a list of parts; show the text ones.
+                Some(Value::Array(parts)) => parts
+                    .iter()
+                    .filter_map(|part| part.get("text").and_then(Value::as_str))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                Some(other) => other.to_string(),
+                None => String::new(),
+            };
+            (role, content)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn messages_reads_chat_and_completion_prompts() {
+        let chat = json!({
+            "prompt": {
+                "type": "chat",
+                "messages": [
+                    { "role": "system", "content": "Be terse." },
+                    { "role": "user", "content": [{ "type": "text", "text": "{{input}}" }] },
+                ],
+            },
+        });
+        assert_eq!(
+            messages(Some(&chat)),
+            vec![
+                ("system".to_string(), "Be terse.".to_string()),
+                ("user".to_string(), "{{input}}".to_string()),
+            ]
+        );
+
+        let completion = json!({ "prompt": { "type": "completion", "content": "Q: {{q}}" } });
+        assert_eq!(
+            messages(Some(&completion)),
+            vec![("prompt".to_string(), "Q: {{q}}".to_string())]
+        );
+        assert!(messages(None).is_empty());
+    }
+}
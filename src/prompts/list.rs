@@ -0,0 +1,67 @@
+use anyhow::Result;
+use dialoguer::console;
+use unicode_width::UnicodeWidthStr;
+
+use crate::experiments::format_created;
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::with_spinner;
+
+use super::api;
+
+pub async fn run(client: &ApiClient, project: &Project, json: bool) -> Result<()> {
+    let prompts =
+        with_spinner("Loading prompts...", api::list_prompts(client, &project.id)).await?;
+
+    if json {
+        println!("{}", serde_json::to_string(&prompts)?);
+        return Ok(());
+    }
+
+    println!(
+        "{} prompts found in {}\n",
+        console::style(prompts.len()),
+        console::style(&project.name).bold()
+    );
+    let slug_width = prompts
+        .iter()
+        .map(|p| p.slug.width())
+        .max()
+        .unwrap_or(0)
+        .max(15);
+    let name_width = prompts
+        .iter()
+        .map(|p| p.name.width())
+        .max()
+        .unwrap_or(0)
+        .max(15);
+    println!(
+        "{}  {}  {}  {}",
+        console::style(format!("{:slug_width$}", "Slug"))
+            .dim()
+            .bold(),
+        console::style(format!("{:name_width$}", "Name"))
+            .dim()
+            .bold(),
+        console::style(format!("{:20}", "Created")).dim().bold(),
+        console::style("Description").dim().bold()
+    );
+    for prompt in &prompts {
+        let description = prompt
+            .description
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .unwrap_or("-");
+        println!(
+            "{}{:slug_padding$}  {}{:name_padding$}  {:20}  {description}",
+            prompt.slug,
+            "",
+            prompt.name,
+            "",
+            format_created(prompt.created.as_deref()),
+            slug_padding = slug_width - prompt.slug.width(),
+            name_padding = name_width - prompt.name.width(),
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Result};
+use clap::{Args, Subcommand};
+
+use crate::args::BaseArgs;
+use crate::http::ApiClient;
+use crate::login::login;
+use crate::projects::api::Project;
+use crate::projects::resolve_project;
+use crate::ui::{self, with_spinner};
+
+mod api;
+mod delete;
+mod diff;
+mod export;
+mod get;
+mod list;
+mod play;
+mod render;
+mod run;
+mod spec;
+mod template;
+
+#[derive(Debug, Clone, Args)]
+pub struct PromptsArgs {
+    #[command(subcommand)]
+    command: Option<PromptsCommands>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum PromptsCommands {
+    /// List prompts in the active project
+    List,
+    /// Show a prompt's details and messages
+    Get(GetArgs),
+    /// Delete a prompt
+    Delete(DeleteArgs),
+    /// Compare local prompt files with the project's prompts
+    Diff(DiffArgs),
+    /// Run a prompt with template variables and print the model's response
+    Run(RunArgs),
+    /// Edit a prompt's variables and run it repeatedly in an interactive playground
+    Play(PlayArgs),
+    /// Fill in a prompt's template variables locally and print its messages
+    Render(RenderArgs),
+    /// Print a prompt as a request body for the OpenAI or Anthropic API
+    Export(ExportArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+struct GetArgs {
+    /// Prompt slug (pick interactively when omitted)
+    slug: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct DeleteArgs {
+    /// Prompt slug (pick interactively when omitted)
+    slug: Option<String>,
+
+    /// Skip the confirmation prompt (required when not running interactively)
+    #[arg(long, short = 'y', visible_alias = "force")]
+    yes: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct DiffArgs {
+    /// Prompt files (YAML or JSON) or directories of them
+    #[arg(default_value = "prompts")]
+    paths: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct RunArgs {
+    /// Prompt slug (pick interactively when omitted)
+    slug: Option<String>,
+
+    /// Template variable, e.g. customer_name=Ada (repeatable; values may be JSON)
+    #[arg(long = "var", value_name = "NAME=VALUE", value_parser = run::parse_var)]
+    vars: Vec<(String, serde_json::Value)>,
+
+    /// Print the response as it is generated
+    #[arg(long)]
+    stream: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+struct PlayArgs {
+    /// Prompt slug (pick interactively when omitted)
+    slug: Option<String>,
+
+    /// Initial value for a template variable (repeatable)
+    #[arg(long = "var", value_name = "NAME=VALUE", value_parser = run::parse_var)]
+    vars: Vec<(String, serde_json::Value)>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct RenderArgs {
+    /// Prompt slug (pick interactively when omitted)
+    slug: Option<String>,
+
+    /// Template variable, e.g. customer_name=Ada (repeatable; values may be JSON)
+    #[arg(long = "var", value_name = "NAME=VALUE", value_parser = run::parse_var)]
+    vars: Vec<(String, serde_json::Value)>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct ExportArgs {
+    /// Prompt slug (pick interactively when omitted)
+    slug: Option<String>,
+
+    /// Provider whose request format to emit
+    #[arg(long, value_enum, default_value = "openai")]
+    format: export::ExportFormat,
+
+    /// Template variable to fill in (templates are left as-is when none are given)
+    #[arg(long = "var", value_name = "NAME=VALUE", value_parser = run::parse_var)]
+    vars: Vec<(String, serde_json::Value)>,
+}
+
+pub async fn run(base: BaseArgs, args: PromptsArgs) -> Result<()> {
+    let ctx = login(&base).await?;
+    let client = ApiClient::new(&ctx)?;
+    let project = resolve_project(&client, base.project.as_deref()).await?;
+
+    match args.command {
+        None | Some(PromptsCommands::List) => list::run(&client, &project, base.json).await,
+        Some(PromptsCommands::Get(a)) => {
+            let prompt = resolve_prompt(&client, &project, a.slug.as_deref()).await?;
+            get::run(&prompt, base.json)
+        }
+        Some(PromptsCommands::Delete(a)) => {
+            let prompt = resolve_prompt(&client, &project, a.slug.as_deref()).await?;
+            delete::run(&client, &prompt, a.yes).await
+        }
+        Some(PromptsCommands::Run(a)) => {
+            let prompt = resolve_prompt(&client, &project, a.slug.as_deref()).await?;
+            run::run(&client, &prompt, &a.vars, a.stream, base.json).await
+        }
+        Some(PromptsCommands::Play(a)) => {
+            let prompt = resolve_prompt(&client, &project, a.slug.as_deref()).await?;
+            play::run(&client, &prompt, &a.vars).await
+        }
+        Some(PromptsCommands::Render(a)) => {
+            let prompt = resolve_prompt(&client, &project, a.slug.as_deref()).await?;
+            render::run(&prompt, &a.vars, base.json)
+        }
+        Some(PromptsCommands::Export(a)) => {
+            let prompt = resolve_prompt(&client, &project, a.slug.as_deref()).await?;
+            export::run(&prompt, a.format, &a.vars, base.json)
+        }
+        Some(PromptsCommands::Diff(a)) => diff::run(&client, &project, &a.paths, base.json).await,
+    }
+}
+
+async fn find_prompt(client: &ApiClient, project: &Project, slug: &str) -> Result<api::Prompt> {
+    with_spinner(
+        "Loading prompt...",
+        api::get_prompt_by_slug(client, &project.id, slug),
+    )
+    .await?
+    .ok_or_else(|| anyhow!("prompt '{slug}' not found in '{}'", project.name))
+}
+
+/// The prompt with this slug, or one picked interactively from the project's prompts.
+async fn resolve_prompt(
+    client: &ApiClient,
+    project: &Project,
+    slug: Option<&str>,
+) -> Result<api::Prompt> {
+    if let Some(slug) = slug {
+        return find_prompt(client, project, slug).await;
+    }
+    if !ui::is_interactive() {
+        bail!("prompt slug required");
+    }
+    let mut prompts =
+        with_spinner("Loading prompts...", api::list_prompts(client, &project.id)).await?;
+    if prompts.is_empty() {
+        bail!("no prompts found in '{}'", project.name);
+    }
+    let labels: Vec<String> = prompts
+        .iter()
+        .map(|p| {
+            if p.name == p.slug {
+                p.slug.clone()
+            } else {
+                format!("{} ({})", p.slug, p.name)
+            }
+        })
+        .collect();
+    let selection = ui::fuzzy_select("Select prompt", &labels)?;
+    Ok(prompts.swap_remove(selection))
+}
@@ -0,0 +1,384 @@
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::ExecutableCommand;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::Frame;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Terminal;
+use serde_json::{Map, Value};
+use tokio::sync::mpsc;
+use unicode_width::UnicodeWidthStr;
+
+use crate::functions::{self, api};
+use crate::http::ApiClient;
+use crate::ui;
+
+use super::api::Prompt;
+use super::run::parse_value;
+use super::template;
+
+const HELP: &str =
+    "↑/↓ select, type to edit, Enter next/run, Ctrl+R run, PgUp/PgDn scroll, Esc quit";
+
+/// What the main loop should do after a key press.
+#[derive(Debug, PartialEq)]
+enum Action {
+    None,
+    Run,
+    Quit,
+}
+
+struct Playground {
+    title: String,
+    vars: Vec<(String, String)>,
+    /// Index into `vars`, or `vars.len()` for the run button.
+    selected: usize,
+    output: String,
+    error: Option<String>,
+    /// Lines scrolled up from the bottom of the output.
+    scroll_back: u16,
+    started: Option<Instant>,
+    first_token: Option<Duration>,
+    elapsed: Option<Duration>,
+}
+
+impl Playground {
+    fn new(prompt: &Prompt, initial: &[(String, Value)]) -> Result<Self> {
+        let texts = template::prompt_texts(prompt.prompt_data.as_ref());
+        let mut names: Vec<String> = Vec::new();
+        for text in texts {
+            for name in template::variables(text)? {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        for (name, _) in initial {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        let vars = names
+            .into_iter()
+            .map(|name| {
+                let value = match initial.iter().find(|(n, _)| *n == name) {
+                    Some((_, Value::String(s))) => s.clone(),
+                    Some((_, other)) => other.to_string(),
+                    None => String::new(),
+                };
+                (name, value)
+            })
+            .collect();
+        let model = prompt
+            .prompt_data
+            .as_ref()
+            .and_then(|data| data.pointer("/options/model"))
+            .and_then(Value::as_str);
+        let title = match model {
+            Some(model) => format!("{} ({model})", prompt.slug),
+            None => prompt.slug.clone(),
+        };
+        Ok(Self {
+            title,
+            vars,
+            selected: 0,
+            output: String::new(),
+            error: None,
+            scroll_back: 0,
+            started: None,
+            first_token: None,
+            elapsed: None,
+        })
+    }
+
+    fn input(&self) -> Value {
+        let vars: Map<String, Value> = self
+            .vars
+            .iter()
+            .map(|(name, value)| (name.clone(), parse_value(value)))
+            .collect();
+        Value::Object(vars)
+    }
+
+    fn running(&self) -> bool {
+        self.started.is_some() && self.elapsed.is_none()
+    }
+
+    fn start(&mut self) {
+        self.output.clear();
+        self.error = None;
+        self.scroll_back = 0;
+        self.started = Some(Instant::now());
+        self.first_token = None;
+        self.elapsed = None;
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if self.first_token.is_none() {
+            self.first_token = self.started.map(|s| s.elapsed());
+        }
+        self.output.push_str(text);
+    }
+
+    fn finish(&mut self, result: Result<()>) {
+        self.elapsed = self.started.map(|s| s.elapsed());
+        if let Err(err) = result {
+            self.error = Some(format!("{err:#}"));
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Action {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let count = self.vars.len() + 1;
+        match key.code {
+            KeyCode::Esc => return Action::Quit,
+            KeyCode::Char('c') if ctrl => return Action::Quit,
+            KeyCode::Char('r') if ctrl => return Action::Run,
+            KeyCode::Up | KeyCode::BackTab => self.selected = (self.selected + count - 1) % count,
+            KeyCode::Down | KeyCode::Tab => self.selected = (self.selected + 1) % count,
+            KeyCode::Enter if self.selected == self.vars.len() => return Action::Run,
+            KeyCode::Enter => self.selected += 1,
+            KeyCode::PageUp => self.scroll_back = self.scroll_back.saturating_add(10),
+            KeyCode::PageDown => self.scroll_back = self.scroll_back.saturating_sub(10),
+            KeyCode::Char('u') if ctrl => {
+                if let Some((_, value)) = self.vars.get_mut(self.selected) {
+                    value.clear();
+                }
+            }
+            KeyCode::Backspace => {
+                if let Some((_, value)) = self.vars.get_mut(self.selected) {
+                    value.pop();
+                }
+            }
+            KeyCode::Char(c) if !ctrl => {
+                if let Some((_, value)) = self.vars.get_mut(self.selected) {
+                    value.push(c);
+                }
+            }
+            _ => {}
+        }
+        Action::None
+    }
+
+    fn stats(&self) -> String {
+        let secs = |d: Option<Duration>| {
+            d.map_or_else(|| "-".to_string(), |d| format!("{:.2}s", d.as_secs_f64()))
+        };
+        let status = match (&self.error, self.running(), self.started) {
+            (Some(_), _, _) => "failed",
+            (None, true, _) => "running",
+            (None, false, Some(_)) => "done",
+            (None, false, None) => "ready",
+        };
+        // The stream doesn't report usage, so estimate at ~4 characters per token.
+        format!(
+            "{status} · first token {} · total {} · ~{} output tokens",
+            secs(self.first_token),
+            secs(self.elapsed.or_else(|| self.started.map(|s| s.elapsed()))),
+            self.output.chars().count().div_ceil(4)
+        )
+    }
+}
+
+type Invocation<'a> = Pin<Box<dyn Future<Output = Result<()>> + 'a>>;
+
+/// An interactive screen for filling in a prompt's variables and running it repeatedly.
+pub async fn run(client: &ApiClient, prompt: &Prompt, vars: &[(String, Value)]) -> Result<()> {
+    if !ui::is_interactive() {
+        bail!("the playground needs an interactive terminal; use `bt prompts run` instead");
+    }
+    let mut playground = Playground::new(prompt, vars)?;
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    stdout.execute(EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    let mut invocation: Option<Invocation<'_>> = None;
+    let mut list_state = ListState::default();
+    let res = async {
+        loop {
+            list_state.select(Some(playground.selected));
+            terminal.draw(|f| draw(f, &playground, &mut list_state))?;
+
+            tokio::select! {
+                result = async { invocation.as_mut().expect("guarded").await },
+                    if invocation.is_some() =>
+                {
+                    invocation = None;
+                    while let Ok(text) = rx.try_recv() {
+                        playground.push_text(&text);
+                    }
+                    playground.finish(result);
+                }
+                _ = tokio::time::sleep(Duration::from_millis(30)) => {}
+            }
+            while let Ok(text) = rx.try_recv() {
+                playground.push_text(&text);
+            }
+
+            while event::poll(Duration::ZERO)? {
+                let Event::Key(key) = event::read()? else {
+                    continue;
+                };
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match playground.handle_key(key) {
+                    Action::Quit => return Ok(()),
+                    Action::Run if invocation.is_none() => {
+                        playground.start();
+                        invocation = Some(Box::pin(invoke(
+                            client,
+                            &prompt.id,
+                            playground.input(),
+                            tx.clone(),
+                        )));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    .await;
+
+    disable_raw_mode().ok();
+    terminal.backend_mut().execute(LeaveAlternateScreen).ok();
+    terminal.show_cursor().ok();
+    res
+}
+
+async fn invoke(
+    client: &ApiClient,
+    prompt_id: &str,
+    input: Value,
+    tx: mpsc::UnboundedSender<String>,
+) -> Result<()> {
+    let response = api::invoke_stream(client, prompt_id, &input).await?;
+    functions::read_stream(response, |text| {
+        tx.send(text.to_string()).ok();
+        Ok(())
+    })
+    .await
+}
+
+fn draw(frame: &mut Frame<'_>, playground: &Playground, list_state: &mut ListState) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(frame.area());
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(rows[0]);
+
+    let mut items: Vec<ListItem> = playground
+        .vars
+        .iter()
+        .map(|(name, value)| {
+            ListItem::new(vec![
+                Line::from(Span::styled(name.clone(), Style::default().fg(Color::Cyan))),
+                Line::from(format!("  {value}")),
+            ])
+        })
+        .collect();
+    let run_label = if playground.running() {
+        "▶ Running..."
+    } else {
+        "▶ Run"
+    };
+    items.push(ListItem::new(Line::from(Span::styled(
+        run_label,
+        Style::default().add_modifier(Modifier::BOLD),
+    ))));
+    let list = List::new(items)
+        .block(Block::default().title("Variables").borders(Borders::ALL))
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    frame.render_stateful_widget(list, columns[0], list_state);
+
+    let output = match &playground.error {
+        Some(error) => format!("{}\n\n{error}", playground.output),
+        None => playground.output.clone(),
+    };
+    let inner_width = columns[1].width.saturating_sub(2).max(1) as usize;
+    let inner_height = columns[1].height.saturating_sub(2);
+    let bottom = wrapped_height(&output, inner_width).saturating_sub(inner_height);
+    let scroll = bottom.saturating_sub(playground.scroll_back);
+    frame.render_widget(
+        Paragraph::new(output)
+            .block(
+                Block::default()
+                    .title(playground.title.as_str())
+                    .borders(Borders::ALL),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((scroll, 0)),
+        columns[1],
+    );
+
+    frame.render_widget(Paragraph::new(playground.stats()), rows[1]);
+    frame.render_widget(
+        Paragraph::new(HELP).style(Style::default().fg(Color::DarkGray)),
+        rows[2],
+    );
+}
+
+/// Lines `text` takes up when wrapped to `width` columns (approximately, as words wrap whole).
+fn wrapped_height(text: &str, width: usize) -> u16 {
+    let lines: usize = text
+        .split('\n')
+        .map(|line| line.width().div_ceil(width).max(1))
+        .sum();
+    lines.min(u16::MAX as usize) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn editing_variables_then_running() {
+        let prompt: Prompt = serde_json::from_value(json!({
+            "id": "p1",
+            "name": "greeter",
+            "slug": "greeter",
+            "project_id": "proj",
+            "prompt_data": {
+                "prompt": { "messages": [{ "role": "user", "content": "Hi {{name}}, {{n}}" }] },
+            },
+        }))
+        .unwrap();
+        let mut playground = Playground::new(&prompt, &[("n".to_string(), json!(2))]).unwrap();
+        assert_eq!(playground.vars[1], ("n".to_string(), "2".to_string()));
+
+        for c in "Ada".chars() {
+            assert_eq!(playground.handle_key(key(KeyCode::Char(c))), Action::None);
+        }
+        assert_eq!(playground.handle_key(key(KeyCode::Enter)), Action::None);
+        assert_eq!(playground.handle_key(key(KeyCode::Enter)), Action::None);
+        assert_eq!(playground.handle_key(key(KeyCode::Enter)), Action::Run);
+        assert_eq!(playground.input(), json!({ "name": "Ada", "n": 2 }));
+        assert_eq!(playground.handle_key(key(KeyCode::Esc)), Action::Quit);
+    }
+}
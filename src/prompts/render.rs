@@ -0,0 +1,87 @@
+use anyhow::{bail, Result};
+use serde_json::{Map, Value};
+
+use super::api::Prompt;
+use super::template;
+
+/// Fill in the prompt's templates locally and print the messages that would be sent.
+pub fn run(prompt: &Prompt, vars: &[(String, Value)], json: bool) -> Result<()> {
+    let vars: Map<String, Value> = vars.iter().cloned().collect();
+    let mut missing = Vec::new();
+    let rendered = render_prompt(prompt.prompt_data.as_ref(), &vars, &mut missing)?;
+    if !missing.is_empty() {
+        eprintln!("warning: no value for {}", missing.join(", "));
+    }
+
+    if json {
+        println!("{}", serde_json::to_string(&rendered)?);
+    } else {
+        match &rendered {
+            Value::String(text) => println!("{text}"),
+            other => println!("{}", serde_json::to_string_pretty(other)?),
+        }
+    }
+    Ok(())
+}
+
+/// The rendered message array for chat prompts, or the rendered text for completion prompts.
+pub(super) fn render_prompt(
+    prompt_data: Option<&Value>,
+    vars: &Map<String, Value>,
+    missing: &mut Vec<String>,
+) -> Result<Value> {
+    let Some(prompt) = prompt_data.and_then(|data| data.get("prompt")) else {
+        bail!("prompt has no messages to render");
+    };
+    if let Some(content) = prompt.get("content").and_then(Value::as_str) {
+        return Ok(Value::String(template::render(content, vars, missing)?));
+    }
+    let Some(messages) = prompt.get("messages").and_then(Value::as_array) else {
+        bail!("prompt has no messages to render");
+    };
+    let mut rendered = messages.clone();
+    for message in &mut rendered {
+        match message.get_mut("content") {
+            Some(Value::String(text)) => *text = template::render(text, vars, missing)?,
+            Some(Value::Array(parts)) => {
+                for part in parts {
+                    if let Some(Value::String(text)) = part.get_mut("text") {
+                        *text = template::render(text, vars, missing)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(Value::Array(rendered))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn render_prompt_fills_every_message() {
+        let data = json!({
+            "prompt": {
+                "type": "chat",
+                "messages": [
+                    { "role": "system", "content": "You help {{company}}." },
+                    { "role": "user", "content": [{ "type": "text", "text": "Hi, I'm {{name}}" }] },
+                ],
+            },
+        });
+        let vars = json!({ "name": "Ada" });
+        let mut missing = Vec::new();
+        let rendered = render_prompt(Some(&data), vars.as_object().unwrap(), &mut missing).unwrap();
+        assert_eq!(
+            rendered,
+            json!([
+                { "role": "system", "content": "You help ." },
+                { "role": "user", "content": [{ "type": "text", "text": "Hi, I'm Ada" }] },
+            ])
+        );
+        assert_eq!(missing, vec!["company"]);
+    }
+}
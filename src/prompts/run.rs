@@ -0,0 +1,53 @@
+use anyhow::Result;
+use serde_json::{Map, Value};
+
+use crate::functions;
+use crate::http::ApiClient;
+
+use super::api::Prompt;
+
+/// Parse a `--var name=value` argument, reading the value as JSON when it parses and as text otherwise.
+pub fn parse_var(raw: &str) -> Result<(String, Value), String> {
+    let (name, value) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got '{raw}'"))?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("missing variable name in '{raw}'"));
+    }
+    Ok((name.to_string(), parse_value(value)))
+}
+
+/// A variable's value as JSON when it parses, otherwise as text.
+pub fn parse_value(raw: &str) -> Value {
+    serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))
+}
+
+pub async fn run(
+    client: &ApiClient,
+    prompt: &Prompt,
+    vars: &[(String, Value)],
+    stream: bool,
+    json: bool,
+) -> Result<()> {
+    let input = Value::Object(vars.iter().cloned().collect::<Map<_, _>>());
+    let label = format!("Running '{}'...", prompt.slug);
+    functions::invoke_and_print(client, &prompt.id, &label, &input, stream, json).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parse_var_reads_json_or_text() {
+        assert_eq!(parse_var("n=3"), Ok(("n".to_string(), json!(3))));
+        assert_eq!(
+            parse_var("customer_name=Ada"),
+            Ok(("customer_name".to_string(), json!("Ada")))
+        );
+        assert!(parse_var("=Ada").is_err());
+        assert!(parse_var("Ada").is_err());
+    }
+}
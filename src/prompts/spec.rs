@@ -0,0 +1,186 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::api::Prompt;
+
+/// A prompt as written in a local YAML or JSON file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptSpec {
+    pub slug: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub params: Map<String, Value>,
+    #[serde(default)]
+    pub messages: Vec<Message>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    pub role: String,
+    /// Text, or a list of content parts for multimodal messages. Absent on assistant
+    /// messages that only carry tool calls.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Value>,
+}
+
+impl PromptSpec {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let mut spec: Self = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&contents)?
+        } else {
+            serde_yaml::from_str(&contents)?
+        };
+        spec.name.get_or_insert_with(|| spec.slug.clone());
+        spec.params.values_mut().for_each(normalize_numbers);
+        Ok(spec)
+    }
+
+    /// The remote prompt in the same shape, with a completion prompt as a single `prompt` message.
+    pub fn from_remote(prompt: &Prompt) -> Result<Self> {
+        let data = prompt.prompt_data.as_ref();
+        let options = data.and_then(|d| d.get("options"));
+        let messages = match data.and_then(|d| d.get("prompt")) {
+            Some(p) if p.get("content").is_some() => vec![Message {
+                role: "prompt".to_string(),
+                content: Some(p["content"].clone()),
+            }],
+            Some(p) => match p.get("messages") {
+                Some(messages) => serde_json::from_value(messages.clone())
+                    .with_context(|| format!("prompt '{}' has invalid messages", prompt.slug))?,
+                None => Vec::new(),
+            },
+            None => Vec::new(),
+        };
+        let mut params = options
+            .and_then(|o| o.get("params"))
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+        params.values_mut().for_each(normalize_numbers);
+        Ok(Self {
+            slug: prompt.slug.clone(),
+            name: Some(prompt.name.clone()),
+            description: prompt.description.clone().filter(|d| !d.is_empty()),
+            model: options
+                .and_then(|o| o.get("model"))
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            params,
+            messages,
+        })
+    }
+}
+
+/// Store whole floats as integers so `0` and `0.0` compare (and print) the same.
+fn normalize_numbers(value: &mut Value) {
+    match value {
+        Value::Number(n) if !n.is_i64() && !n.is_u64() => {
+            if let Some(f) = n.as_f64().filter(|f| f.fract() == 0.0 && f.abs() < 1e15) {
+                *value = Value::from(f as i64);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(normalize_numbers),
+        Value::Object(map) => map.values_mut().for_each(normalize_numbers),
+        _ => {}
+    }
+}
+
+/// Prompt files under `paths`: `.yaml`, `.yml`, and `.json` files, searching directories recursively.
+pub fn find_files(paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            collect(path, &mut files)?;
+        } else if path.exists() {
+            files.push(path.clone());
+        } else {
+            bail!("{} does not exist", path.display());
+        }
+    }
+    Ok(files)
+}
+
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let mut entries = std::fs::read_dir(dir)
+        .with_context(|| format!("failed to read {}", dir.display()))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            collect(&path, files)?;
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext == "yaml" || ext == "yml" || ext == "json")
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn from_remote_matches_an_equivalent_local_file() {
+        let prompt: Prompt = serde_json::from_value(json!({
+            "id": "p1",
+            "name": "greeter",
+            "slug": "greeter",
+            "project_id": "proj",
+            "description": "",
+            "prompt_data": {
+                "prompt": {
+                    "type": "chat",
+                    "messages": [{ "role": "user", "content": "Hi {{name}}" }],
+                },
+                "options": { "model": "gpt-4o", "params": { "temperature": 0.0 } },
+            },
+        }))
+        .unwrap();
+        let mut local: PromptSpec = serde_yaml::from_str(
+            "slug: greeter\nmodel: gpt-4o\nparams:\n  temperature: 0\n\
+             messages:\n  - role: user\n    content: Hi {{name}}\n",
+        )
+        .unwrap();
+        local.name = Some(local.slug.clone());
+        assert_eq!(PromptSpec::from_remote(&prompt).unwrap(), local);
+    }
+
+    #[test]
+    fn from_remote_allows_tool_call_messages_and_reports_bad_ones() {
+        let remote = |messages: Value| -> Prompt {
+            serde_json::from_value(json!({
+                "id": "p1",
+                "name": "agent",
+                "slug": "agent",
+                "project_id": "proj",
+                "prompt_data": { "prompt": { "type": "chat", "messages": messages } },
+            }))
+            .unwrap()
+        };
+        let spec = PromptSpec::from_remote(&remote(json!([
+            { "role": "assistant", "tool_calls": [] },
+            { "role": "user", "content": null },
+        ])))
+        .unwrap();
+        assert_eq!(spec.messages.len(), 2);
+        assert!(spec.messages.iter().all(|m| m.content.is_none()));
+
+        let err = PromptSpec::from_remote(&remote(json!([{ "content": "no role" }]))).unwrap_err();
+        assert_eq!(err.to_string(), "prompt 'agent' has invalid messages");
+    }
+}
@@ -0,0 +1,314 @@
+use anyhow::{bail, Result};
+use serde_json::{Map, Value};
+
+/// Names of the top-level variables a mustache template reads, in order of first use.
+/// Names used only inside a section may be fields of its items, so they are left out.
+pub fn variables(template: &str) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    collect_variables(&parse(template)?, &mut names);
+    Ok(names)
+}
+
+fn collect_variables(nodes: &[Node<'_>], names: &mut Vec<String>) {
+    for node in nodes {
+        let name = match node {
+            Node::Text(_) => continue,
+            Node::Var(name) => name,
+            Node::Section {
+                name,
+                inverted,
+                children,
+            } => {
+                // An inverted section renders in the enclosing scope; a section may not.
+                if *inverted {
+                    collect_variables(children, names);
+                }
+                name
+            }
+        };
+        // Only the top-level key is an input; `{{input.question}}` needs `input`.
+        let root = name.split('.').next().unwrap_or(name);
+        if !root.is_empty() && root != "." && !names.iter().any(|n| n == root) {
+            names.push(root.to_string());
+        }
+    }
+}
+
+/// Every string in a prompt's messages or completion text, for finding its variables.
+pub fn prompt_texts(prompt_data: Option<&Value>) -> Vec<&str> {
+    let Some(prompt) = prompt_data.and_then(|data| data.get("prompt")) else {
+        return Vec::new();
+    };
+    let mut texts = Vec::new();
+    if let Some(content) = prompt.get("content").and_then(Value::as_str) {
+        texts.push(content);
+    }
+    for message in prompt
+        .get("messages")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        match message.get("content") {
+            Some(Value::String(text)) => texts.push(text),
+            Some(Value::Array(parts)) => texts.extend(
+                parts
+                    .iter()
+                    .filter_map(|part| part.get("text").and_then(Value::as_str)),
+            ),
+            _ => {}
+        }
+    }
+    texts
+}
+
+enum Node<'a> {
+    Text(&'a str),
+    Var(&'a str),
+    Section {
+        name: &'a str,
+        inverted: bool,
+        children: Vec<Node<'a>>,
+    },
+}
+
+/// Fill a mustache template. Unlike stock mustache nothing is HTML-escaped, and non-string
+/// values are written as JSON. Top-level variables with no value are added to `missing`.
+pub fn render(
+    template: &str,
+    vars: &Map<String, Value>,
+    missing: &mut Vec<String>,
+) -> Result<String> {
+    let nodes = parse(template)?;
+    let root = Value::Object(vars.clone());
+    let mut out = String::new();
+    render_nodes(&nodes, &mut vec![&root], &mut out, missing);
+    Ok(out)
+}
+
+fn parse(template: &str) -> Result<Vec<Node<'_>>> {
+    // Each open section's name and the nodes collected so far at that depth.
+    let mut stack: Vec<(&str, bool, Vec<Node>)> = vec![("", false, Vec::new())];
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let (tag, next) = if let Some(inner) = after.strip_prefix('{') {
+            let Some(end) = inner.find("}}}") else {
+                bail!("unclosed {{{{{{ tag");
+            };
+            (&after[..end + 1], &inner[end + 3..])
+        } else {
+            let Some(end) = after.find("}}") else {
+                bail!("unclosed {{{{ tag");
+            };
+            (&after[..end], &after[end + 2..])
+        };
+        let tag = tag.trim();
+        let mut text = &rest[..start];
+        let mut next = next;
+        if matches!(tag.chars().next(), Some('#' | '^' | '/' | '!')) {
+            let at_line_start = template[..template.len() - rest.len()].ends_with('\n')
+                || rest.len() == template.len();
+            if let Some((before, after)) = standalone(rest, start, next, at_line_start) {
+                (text, next) = (before, after);
+            }
+        }
+        rest = next;
+        let nodes = &mut stack.last_mut().expect("root is never popped").2;
+        if !text.is_empty() {
+            nodes.push(Node::Text(text));
+        }
+        match tag.chars().next() {
+            Some('!') => {}
+            Some('#' | '^') => stack.push((tag[1..].trim(), tag.starts_with('^'), Vec::new())),
+            Some('/') => {
+                let name = tag[1..].trim();
+                match stack.pop() {
+                    Some((open, inverted, children)) if !stack.is_empty() && open == name => {
+                        let parent = &mut stack.last_mut().expect("checked above").2;
+                        parent.push(Node::Section {
+                            name,
+                            inverted,
+                            children,
+                        });
+                    }
+                    _ => bail!("unexpected {{{{/{name}}}}}"),
+                }
+            }
+            Some('{') => nodes.push(Node::Var(tag[1..].trim())),
+            Some('&') => nodes.push(Node::Var(tag[1..].trim())),
+            Some('>' | '=') => bail!("partials and delimiter changes are not supported"),
+            _ => nodes.push(Node::Var(tag)),
+        }
+    }
+    if stack.len() > 1 {
+        bail!("unclosed section {{{{#{}}}}}", stack[stack.len() - 1].0);
+    }
+    let mut nodes = stack.pop().expect("root is never popped").2;
+    if !rest.is_empty() {
+        nodes.push(Node::Text(rest));
+    }
+    Ok(nodes)
+}
+
+/// When the tag at `rest[start..]` (followed by `next`) sits alone on its line, the text
+/// before and after it with that whole line removed, as mustache does for standalone
+/// section, inverted-section and comment tags.
+fn standalone<'a>(
+    rest: &'a str,
+    start: usize,
+    next: &'a str,
+    at_line_start: bool,
+) -> Option<(&'a str, &'a str)> {
+    let line_start = match rest[..start].rfind('\n') {
+        Some(newline) => newline + 1,
+        None if at_line_start => 0,
+        None => return None,
+    };
+    if !rest[line_start..start]
+        .chars()
+        .all(|c| c == ' ' || c == '\t')
+    {
+        return None;
+    }
+    let (line_rest, after) = match next.find('\n') {
+        Some(newline) => (&next[..newline], &next[newline + 1..]),
+        None => (next, ""),
+    };
+    if !line_rest.trim().is_empty() {
+        return None;
+    }
+    Some((&rest[..line_start], after))
+}
+
+fn render_nodes(
+    nodes: &[Node<'_>],
+    context: &mut Vec<&Value>,
+    out: &mut String,
+    missing: &mut Vec<String>,
+) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var(name) => match lookup(context, name) {
+                Some(Value::String(s)) => out.push_str(s),
+                Some(Value::Null) => {}
+                Some(other) => out.push_str(&other.to_string()),
+                None => note_missing(context, name, missing),
+            },
+            Node::Section {
+                name,
+                inverted,
+                children,
+            } => {
+                let value = lookup(context, name);
+                if value.is_none() {
+                    note_missing(context, name, missing);
+                }
+                let truthy = value.is_some_and(is_truthy);
+                if *inverted {
+                    if !truthy {
+                        render_nodes(children, context, out, missing);
+                    }
+                    continue;
+                }
+                match value {
+                    Some(Value::Array(items)) => {
+                        for item in items {
+                            context.push(item);
+                            render_nodes(children, context, out, missing);
+                            context.pop();
+                        }
+                    }
+                    Some(value) if truthy => {
+                        context.push(value);
+                        render_nodes(children, context, out, missing);
+                        context.pop();
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Resolve `a.b.c` against the innermost context that has `a`, or `.` to the current item.
+fn lookup<'v>(context: &[&'v Value], name: &str) -> Option<&'v Value> {
+    if name == "." {
+        return context.last().copied();
+    }
+    let mut parts = name.split('.');
+    let first = parts.next()?;
+    let mut value = context.iter().rev().find_map(|scope| scope.get(first))?;
+    for part in parts {
+        value = value.get(part)?;
+    }
+    Some(value)
+}
+
+/// Record a missing top-level variable; names inside a section may belong to the item.
+fn note_missing(context: &[&Value], name: &str, missing: &mut Vec<String>) {
+    let root = name.split('.').next().unwrap_or(name);
+    if context.len() == 1 && !missing.iter().any(|m| m == root) {
+        missing.push(root.to_string());
+    }
+}
+
+/// Falsy the way mustache.js sees it: null, false, 0, "", and empty lists.
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64() != Some(0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variables_lists_top_level_names_once() {
+        let template = "Hi {{ name }}! {{#items}}{{.}} {{x}}{{/items}} {{! note }}\
+                        {{{raw}}} {{input.question}} {{^flag}}{{fallback}}{{/flag}} {{name}}";
+        assert_eq!(
+            variables(template).unwrap(),
+            vec!["name", "items", "raw", "input", "fallback", "flag"]
+        );
+        assert!(variables("no variables {{").is_err());
+    }
+
+    #[test]
+    fn render_fills_variables_and_sections_without_escaping() {
+        let vars = serde_json::json!({
+            "name": "<Ada>",
+            "n": 2,
+            "items": [{ "x": "a" }, { "x": "b" }],
+            "meta": { "tier": "pro" },
+            "empty": [],
+        });
+        let mut missing = Vec::new();
+        let out = render(
+            "{{name}} {{{name}}} {{n}} {{#items}}[{{x}}]{{/items}}{{^empty}} none{{/empty}} \
+             {{meta.tier}} {{meta}} {{! comment }}{{unset}}",
+            vars.as_object().unwrap(),
+            &mut missing,
+        )
+        .unwrap();
+        assert_eq!(out, r#"<Ada> <Ada> 2 [a][b] none pro {"tier":"pro"} "#);
+        assert_eq!(missing, vec!["unset"]);
+        assert!(render("{{#a}}x", &Map::new(), &mut missing).is_err());
+    }
+
+    #[test]
+    fn standalone_tags_leave_no_blank_lines() {
+        let vars = serde_json::json!({ "items": [{ "x": "a" }, { "x": "b" }] });
+        let template = "Items:\n{{#items}}\n  - {{x}}\n  {{/items}}\n{{! note }}\n\
+                        {{^none}}\nnone\n{{/none}}\nDone {{^none}}yes{{/none}}\n{{#none}}x{{/none}}";
+        let out = render(template, vars.as_object().unwrap(), &mut Vec::new()).unwrap();
+        assert_eq!(out, "Items:\n  - a\n  - b\nnone\nDone yes\n");
+    }
+}
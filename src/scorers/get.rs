@@ -0,0 +1,68 @@
+use anyhow::Result;
+use dialoguer::console;
+use serde_json::Value;
+
+use crate::experiments::format_created;
+use crate::functions::api::Function;
+
+use super::implementation;
+
+pub fn run(scorer: &Function, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string(scorer)?);
+        return Ok(());
+    }
+
+    for (label, value) in detail_rows(scorer) {
+        println!(
+            "{}  {value}",
+            console::style(format!("{label:12}")).dim().bold()
+        );
+    }
+    let messages = scorer
+        .prompt_data
+        .as_ref()
+        .and_then(|data| data.pointer("/prompt/messages"))
+        .and_then(Value::as_array);
+    for message in messages.into_iter().flatten() {
+        let role = message
+            .get("role")
+            .and_then(Value::as_str)
+            .unwrap_or("message");
+        println!("\n{}", console::style(role).cyan().bold());
+        match message.get("content") {
+            Some(Value::String(text)) => println!("{text}"),
+            Some(other) => println!("{other}"),
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+fn detail_rows(scorer: &Function) -> Vec<(&'static str, String)> {
+    let or_dash = |value: Option<&str>| value.filter(|s| !s.is_empty()).unwrap_or("-").to_string();
+    let mut rows = vec![
+        ("Name", scorer.name.clone()),
+        ("Slug", scorer.slug.clone()),
+        ("ID", scorer.id.clone()),
+        ("Kind", implementation(scorer).to_string()),
+        ("Description", or_dash(scorer.description.as_deref())),
+        ("Created", format_created(scorer.created.as_deref())),
+    ];
+    if let Some(data) = &scorer.prompt_data {
+        let model = data.pointer("/options/model").and_then(Value::as_str);
+        rows.push(("Model", or_dash(model)));
+        if let Some(choices) = data
+            .pointer("/parser/choice_scores")
+            .and_then(Value::as_object)
+        {
+            let choices = choices
+                .iter()
+                .map(|(choice, score)| format!("{choice}={score}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            rows.push(("Choices", choices));
+        }
+    }
+    rows
+}
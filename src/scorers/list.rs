@@ -0,0 +1,56 @@
+use anyhow::Result;
+use dialoguer::console;
+use unicode_width::UnicodeWidthStr;
+
+use crate::experiments::format_created;
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::with_spinner;
+
+use super::{implementation, list_scorers};
+
+pub async fn run(client: &ApiClient, project: &Project, json: bool) -> Result<()> {
+    let scorers = with_spinner("Loading scorers...", list_scorers(client, project)).await?;
+
+    if json {
+        println!("{}", serde_json::to_string(&scorers)?);
+        return Ok(());
+    }
+
+    println!(
+        "{} scorers found in {}\n",
+        console::style(scorers.len()),
+        console::style(&project.name).bold()
+    );
+    let slug_width = scorers
+        .iter()
+        .map(|s| s.slug.width())
+        .max()
+        .unwrap_or(0)
+        .max(15);
+    println!(
+        "{}  {}  {}  {}",
+        console::style(format!("{:slug_width$}", "Slug"))
+            .dim()
+            .bold(),
+        console::style(format!("{:6}", "Kind")).dim().bold(),
+        console::style(format!("{:20}", "Created")).dim().bold(),
+        console::style("Description").dim().bold()
+    );
+    for scorer in &scorers {
+        let description = scorer
+            .description
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .unwrap_or("-");
+        println!(
+            "{}{:slug_padding$}  {:6}  {:20}  {description}",
+            scorer.slug,
+            "",
+            implementation(scorer),
+            format_created(scorer.created.as_deref()),
+            slug_padding = slug_width - scorer.slug.width(),
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use clap::{Args, Subcommand};
+use serde_json::Value;
+
+use crate::args::BaseArgs;
+use crate::functions::api::{self, Function};
+use crate::http::ApiClient;
+use crate::login::login;
+use crate::projects::api::Project;
+use crate::projects::resolve_project;
+use crate::ui::{self, with_spinner};
+
+mod get;
+mod list;
+mod push;
+
+#[derive(Debug, Clone, Args)]
+pub struct ScorersArgs {
+    #[command(subcommand)]
+    command: Option<ScorersCommands>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum ScorersCommands {
+    /// List scorers in the active project
+    List,
+    /// Show a scorer's details and prompt
+    Get(GetArgs),
+    /// Push an LLM scorer from a YAML file, or a code scorer from TypeScript or JavaScript
+    Push(PushArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+struct GetArgs {
+    /// Scorer slug (pick interactively when omitted)
+    slug: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+struct PushArgs {
+    /// YAML scorer definition (prompt, choice_scores, model, use_cot) or code file
+    file: PathBuf,
+
+    /// Slug to push as (YAML files default to their `slug` field, then the file name)
+    #[arg(long)]
+    slug: Option<String>,
+}
+
+pub async fn run(base: BaseArgs, args: ScorersArgs) -> Result<()> {
+    let ctx = login(&base).await?;
+    let client = ApiClient::new(&ctx)?;
+    let project = resolve_project(&client, base.project.as_deref()).await?;
+
+    match args.command {
+        None | Some(ScorersCommands::List) => list::run(&client, &project, base.json).await,
+        Some(ScorersCommands::Get(a)) => {
+            let scorer = resolve_scorer(&client, &project, a.slug.as_deref()).await?;
+            get::run(&scorer, base.json)
+        }
+        Some(ScorersCommands::Push(a)) => {
+            push::run(&client, &project, &a.file, a.slug.as_deref(), base.json).await
+        }
+    }
+}
+
+async fn list_scorers(client: &ApiClient, project: &Project) -> Result<Vec<Function>> {
+    let mut functions = api::list_functions(client, &project.id).await?;
+    functions.retain(|f| f.function_type.as_deref() == Some("scorer"));
+    Ok(functions)
+}
+
+/// How the scorer is implemented: `llm` for prompt-based scorers, otherwise e.g. `code`.
+fn implementation(scorer: &Function) -> &str {
+    match scorer
+        .function_data
+        .as_ref()
+        .and_then(|data| data.get("type"))
+        .and_then(Value::as_str)
+    {
+        Some("prompt") => "llm",
+        Some(other) => other,
+        None => "-",
+    }
+}
+
+/// The scorer with this slug, or one picked interactively from the project's scorers.
+async fn resolve_scorer(
+    client: &ApiClient,
+    project: &Project,
+    slug: Option<&str>,
+) -> Result<Function> {
+    let mut scorers = with_spinner("Loading scorers...", list_scorers(client, project)).await?;
+    if let Some(slug) = slug {
+        return match scorers.iter().position(|s| s.slug == slug) {
+            Some(index) => Ok(scorers.swap_remove(index)),
+            None => bail!("scorer '{slug}' not found in '{}'", project.name),
+        };
+    }
+    if !ui::is_interactive() {
+        bail!("scorer slug required");
+    }
+    if scorers.is_empty() {
+        bail!("no scorers found in '{}'", project.name);
+    }
+    let slugs: Vec<&str> = scorers.iter().map(|s| s.slug.as_str()).collect();
+    let selection = ui::fuzzy_select("Select scorer", &slugs)?;
+    Ok(scorers.swap_remove(selection))
+}
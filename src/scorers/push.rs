@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use dialoguer::console;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::functions::api;
+use crate::functions::push::{self as code, PushOptions};
+use crate::http::ApiClient;
+use crate::projects::api::Project;
+use crate::ui::{print_command_status, with_spinner, CommandStatus};
+
+const DEFAULT_MODEL: &str = "gpt-4o";
+
+/// An LLM classifier scorer in the autoevals YAML format, plus naming fields.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScorerSpec {
+    #[serde(default)]
+    slug: Option<String>,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    /// Mustache template, with `{{input}}`, `{{output}}`, and `{{expected}}` available.
+    prompt: String,
+    /// Score for each answer the model may choose.
+    choice_scores: BTreeMap<String, f64>,
+    #[serde(default)]
+    model: Option<String>,
+    /// Ask the model to reason before choosing.
+    #[serde(default = "default_use_cot")]
+    use_cot: bool,
+}
+
+fn default_use_cot() -> bool {
+    true
+}
+
+/// Push a YAML scorer definition, or bundle and push a code scorer.
+pub async fn run(
+    client: &ApiClient,
+    project: &Project,
+    file: &Path,
+    slug: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let ext = file.extension().and_then(|e| e.to_str()).unwrap_or("");
+    if !matches!(ext, "yaml" | "yml") {
+        let Some(slug) = slug else {
+            bail!("--slug is required when pushing a code scorer");
+        };
+        let opts = PushOptions {
+            slug,
+            name: None,
+            description: None,
+            kind: Some("scorer"),
+        };
+        return code::run(client, project, file, &opts, json).await;
+    }
+
+    let contents = std::fs::read_to_string(file)
+        .with_context(|| format!("failed to read {}", file.display()))?;
+    let spec: ScorerSpec = serde_yaml::from_str(&contents)
+        .with_context(|| format!("invalid scorer file {}", file.display()))?;
+    let slug = match slug.or(spec.slug.as_deref()) {
+        Some(slug) => slug.to_string(),
+        None => file
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .context("cannot derive a slug from the file name; pass --slug")?,
+    };
+    let body = scorer_body(project, &slug, &spec)?;
+    let scorer = with_spinner(
+        &format!("Pushing '{slug}'..."),
+        api::upsert_function(client, &body),
+    )
+    .await?;
+
+    if json {
+        println!("{}", serde_json::to_string(&scorer)?);
+        return Ok(());
+    }
+    print_command_status(
+        CommandStatus::Success,
+        &format!(
+            "Pushed '{}' to {} (version {})",
+            scorer.slug,
+            console::style(&project.name).bold(),
+            scorer.version.as_deref().unwrap_or("unknown")
+        ),
+    );
+    Ok(())
+}
+
+fn scorer_body(project: &Project, slug: &str, spec: &ScorerSpec) -> Result<Value> {
+    if spec.choice_scores.is_empty() {
+        bail!("choice_scores must list at least one choice");
+    }
+    let mut body = json!({
+        "project_id": project.id,
+        "slug": slug,
+        "name": spec.name.as_deref().unwrap_or(slug),
+        "function_type": "scorer",
+        "function_data": { "type": "prompt" },
+        "prompt_data": {
+            "prompt": {
+                "type": "chat",
+                "messages": [{ "role": "user", "content": spec.prompt }],
+            },
+            "options": { "model": spec.model.as_deref().unwrap_or(DEFAULT_MODEL) },
+            "parser": {
+                "type": "llm_classifier",
+                "use_cot": spec.use_cot,
+                "choice_scores": spec.choice_scores,
+            },
+        },
+    });
+    if let Some(description) = &spec.description {
+        body["description"] = json!(description);
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scorer_body_builds_an_llm_classifier() {
+        let spec: ScorerSpec = serde_yaml::from_str(
+            "prompt: 'Is {{output}} polite? A) yes B) no'\n\
+             choice_scores:\n  A: 1\n  B: 0\n",
+        )
+        .unwrap();
+        let project: Project = serde_json::from_value(json!({
+            "id": "p1",
+            "name": "proj",
+            "org_id": "org",
+        }))
+        .unwrap();
+        let body = scorer_body(&project, "politeness", &spec).unwrap();
+        assert_eq!(body["name"], "politeness");
+        assert_eq!(body["function_type"], "scorer");
+        assert_eq!(body["prompt_data"]["options"]["model"], DEFAULT_MODEL);
+        assert_eq!(
+            body["prompt_data"]["parser"],
+            json!({
+                "type": "llm_classifier",
+                "use_cot": true,
+                "choice_scores": { "A": 1.0, "B": 0.0 },
+            })
+        );
+    }
+}
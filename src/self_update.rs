@@ -7,6 +7,8 @@ use clap::{Args, Subcommand, ValueEnum};
 use reqwest::Client;
 use serde::Deserialize;
 
+use crate::config::config_dir;
+
 #[derive(Debug, Clone, Args)]
 pub struct SelfArgs {
     #[command(subcommand)]
@@ -220,21 +222,7 @@ fn run_installer(channel: UpdateChannel) -> Result<()> {
 }
 
 fn receipt_path() -> Option<PathBuf> {
-    #[cfg(windows)]
-    {
-        env::var_os("APPDATA")
-            .map(PathBuf::from)
-            .map(|path| path.join("bt").join("bt-receipt.json"))
-    }
-    #[cfg(not(windows))]
-    {
-        if let Some(xdg) = env::var_os("XDG_CONFIG_HOME") {
-            return Some(PathBuf::from(xdg).join("bt").join("bt-receipt.json"));
-        }
-        env::var_os("HOME")
-            .map(PathBuf::from)
-            .map(|path| path.join(".config").join("bt").join("bt-receipt.json"))
-    }
+    config_dir().map(|dir| dir.join("bt-receipt.json"))
 }
 
 fn cargo_home_bin_path() -> Option<PathBuf> {
@@ -1,37 +1,277 @@
 use std::collections::HashMap;
-use std::io;
-use std::time::Duration;
-
-use anyhow::Result;
-use clap::Args;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use clap::{Args, Subcommand, ValueEnum};
+use crossterm::cursor::MoveTo;
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyModifiers, KeyboardEnhancementFlags,
+    PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+};
 use crossterm::terminal::{
-    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+    disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, Clear, ClearType,
+    EnterAlternateScreen, LeaveAlternateScreen,
 };
 use crossterm::ExecutableCommand;
+use dialoguer::console;
 use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::prelude::Frame;
-use ratatui::style::Style;
-use ratatui::text::Line;
-use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph, Tabs, Wrap};
 use ratatui::Terminal;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
 use unicode_width::UnicodeWidthStr;
 
+use self::cache::QueryCache;
+use self::editor::Editor;
+use self::engine::EngineOptions;
+use self::export::ExportFormat;
+use self::history::HistoryFile;
+use self::inspect::Inspector;
+use self::limit::RowLimit;
+use self::schema::SchemaBrowser;
 use crate::args::BaseArgs;
 use crate::http::ApiClient;
 use crate::login::login;
-use crate::ui::with_spinner;
+use crate::ui::{
+    copy_to_clipboard, is_limited_terminal, print_command_status, print_paged, with_spinner,
+    CommandStatus,
+};
+
+mod batch;
+mod cache;
+mod editor;
+mod engine;
+mod export;
+mod highlight;
+mod history;
+mod html;
+mod inspect;
+mod limit;
+mod output;
+mod parquet;
+mod saved;
+mod schema;
+mod sort;
+mod statements;
+mod stats;
+mod template;
+
+pub(crate) use self::export::csv_escape;
+pub(crate) use self::output::AtomicFile;
+pub(crate) use self::parquet::write_parquet;
+pub(crate) use self::sort::sort_rows;
 
 #[derive(Debug, Clone, Args)]
 pub struct SqlArgs {
-    /// SQL query to execute
+    #[command(subcommand)]
+    pub command: Option<SqlCommands>,
+
+    /// SQL query to execute (use `-` to read from stdin)
+    #[arg(conflicts_with = "file")]
     pub query: Option<String>,
+
+    /// Read the query from a file (repeat to run several files in order).
+    /// Statements separated by `;` run one after another
+    #[arg(short = 'f', long, value_name = "PATH")]
+    pub file: Vec<PathBuf>,
+
+    /// Keep running the remaining statements after one fails
+    #[arg(long, global = true)]
+    pub keep_going: bool,
+
+    /// Value for a `{{name}}` placeholder in the query (repeatable; missing values are prompted for)
+    #[arg(
+        long = "param",
+        global = true,
+        value_name = "NAME=VALUE",
+        value_parser = template::parse_param
+    )]
+    pub params: Vec<(String, String)>,
+
+    /// Use a plain line-based prompt instead of the full-screen interface
+    #[arg(long)]
+    pub no_tui: bool,
+
+    /// Number of interactive queries to keep in persistent history
+    #[arg(
+        long,
+        env = "BT_SQL_HISTORY_SIZE",
+        default_value_t = 1000,
+        value_name = "N"
+    )]
+    pub history_size: usize,
+
+    /// Don't read or write persistent query history
+    #[arg(long, env = "BT_SQL_NO_HISTORY")]
+    pub no_history: bool,
+
+    /// Key that runs the query in the interactive editor (the other inserts a newline)
+    #[arg(
+        long,
+        value_enum,
+        env = "BT_SQL_SUBMIT_KEY",
+        default_value_t = SubmitKey::Enter,
+        value_name = "KEY"
+    )]
+    pub submit_key: SubmitKey,
+
+    /// Follow result cursors until all rows have been fetched
+    #[arg(long, global = true)]
+    pub all: bool,
+
+    /// Maximum number of pages to fetch when following cursors (implies --all)
+    #[arg(long, global = true, value_name = "N")]
+    pub max_pages: Option<usize>,
+
+    /// Re-run the query every N seconds, redrawing the output in place
+    #[arg(
+        long,
+        global = true,
+        value_name = "SECONDS",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub watch: Option<u64>,
+
+    /// Limit queries to N rows, capping any larger LIMIT clause of their own
+    #[arg(
+        long,
+        global = true,
+        value_name = "N",
+        conflicts_with_all = ["all", "max_pages"]
+    )]
+    pub limit: Option<usize>,
+
+    /// Row limit applied to interactive queries when --limit is not given (0 disables)
+    #[arg(
+        long,
+        env = "BT_SQL_DEFAULT_LIMIT",
+        default_value_t = 100,
+        value_name = "N"
+    )]
+    pub default_limit: usize,
+
+    /// Comma-separated columns to show, in order, in table, vertical, and Parquet output
+    #[arg(long, global = true, value_delimiter = ',', value_name = "COLUMNS")]
+    pub columns: Vec<String>,
+
+    /// Seconds to reuse cached results of an identical query (default 0: no caching)
+    #[arg(
+        long,
+        global = true,
+        env = "BT_SQL_CACHE_TTL",
+        default_value_t = 0,
+        value_name = "SECONDS"
+    )]
+    pub cache_ttl: u64,
+
+    /// Always query the API, ignoring and not updating cached results
+    #[arg(long, global = true)]
+    pub no_cache: bool,
+
+    /// Don't pipe output that doesn't fit the terminal through $PAGER
+    #[arg(long, global = true, env = "BT_NO_PAGER")]
+    pub no_pager: bool,
+
+    /// Truncate table cells wider than N characters with an ellipsis (not applied to -o files)
+    #[arg(long, global = true, default_value_t = 60, value_name = "N")]
+    pub max_col_width: usize,
+
+    /// Show full cell contents in tables, without truncation
+    #[arg(long, global = true)]
+    pub full: bool,
+
+    #[command(flatten)]
+    pub engine: EngineOptions,
+
+    /// Output format (defaults to table, or json with --json)
+    #[arg(long, global = true, value_enum, value_name = "FORMAT")]
+    pub format: Option<OutputFormat>,
+
+    /// Write results to a file instead of stdout (.parquet files are written as Parquet).
+    /// The file is replaced atomically once all rows have been written.
+    #[arg(short = 'o', long, global = true, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SqlCommands {
+    /// Save a named query
+    Save(SaveArgs),
+    /// Run a saved query
+    Run(RunArgs),
+    /// List saved queries
+    List,
+    /// Delete a saved query
+    Delete(DeleteArgs),
+    /// Run every .sql/.btql file in a directory, writing one result file per query
+    Batch(BatchArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct SaveArgs {
+    /// Name of the saved query
+    name: String,
+
+    /// Query text (read from stdin when omitted)
+    query: Option<String>,
+
+    /// Overwrite an existing saved query
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct RunArgs {
+    /// Name of the saved query
+    name: String,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct BatchArgs {
+    /// Directory containing the query files
+    dir: PathBuf,
+
+    /// Directory to write results to
+    #[arg(long, value_name = "DIR")]
+    out_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct DeleteArgs {
+    /// Name of the saved query
+    name: String,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum SubmitKey {
+    /// Enter runs the query; Shift+Enter, Alt+Enter or Ctrl+J insert a newline
+    Enter,
+    /// Alt+Enter runs the query; Enter inserts a newline
+    AltEnter,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// ASCII table
+    Table,
+    /// The full response as a single JSON document
+    Json,
+    /// One JSON object per row, streamed page by page
+    Jsonl,
+    /// One `column: value` block per row, for wide rows
+    Vertical,
+    /// A standalone HTML page with a sortable table
+    Html,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct SqlResponse {
     pub data: Vec<Map<String, Value>>,
     pub schema: Value,
@@ -43,15 +283,44 @@ struct SqlResponse {
     pub realtime_state: Option<RealtimeState>,
     #[serde(flatten)]
     pub extra: HashMap<String, Value>,
+    /// Column selection from `--columns`, overriding the schema's column order.
+    #[serde(skip)]
+    pub columns: Option<Vec<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl SqlArgs {
+    fn max_col_width(&self) -> Option<usize> {
+        (!self.full && self.max_col_width > 0).then_some(self.max_col_width)
+    }
+}
+
+impl SqlResponse {
+    /// Restrict rendered columns to `columns`, in that order.
+    fn select_columns(&mut self, columns: &[String]) -> Result<()> {
+        if columns.is_empty() {
+            return Ok(());
+        }
+        let available = result_headers(self);
+        if !available.is_empty() {
+            if let Some(missing) = columns.iter().find(|c| !available.contains(c)) {
+                bail!(
+                    "unknown column '{missing}' (available: {})",
+                    available.join(", ")
+                );
+            }
+        }
+        self.columns = Some(columns.to_vec());
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct FreshnessState {
     pub last_considered_xact_id: String,
     pub last_processed_xact_id: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct RealtimeState {
     pub actual_xact_id: String,
     pub minimum_xact_id: String,
@@ -61,36 +330,469 @@ struct RealtimeState {
 }
 
 pub async fn run(base: BaseArgs, args: SqlArgs) -> Result<()> {
+    match &args.command {
+        Some(SqlCommands::Save(a)) => return save_query(a),
+        Some(SqlCommands::List) => return list_saved_queries(base.json),
+        Some(SqlCommands::Delete(a)) => {
+            saved::delete(&a.name)?;
+            print_command_status(CommandStatus::Success, &format!("Deleted '{}'", a.name));
+            return Ok(());
+        }
+        Some(SqlCommands::Batch(_)) | Some(SqlCommands::Run(_)) | None => {}
+    }
+
     let ctx = login(&base).await?;
     let client = ApiClient::new(&ctx)?;
+    let format = args.format.unwrap_or(if base.json {
+        OutputFormat::Json
+    } else {
+        OutputFormat::Table
+    });
 
-    if let Some(query) = args.query {
-        let response = with_spinner("Running query...", execute_query(&client, &query)).await?;
-        print_response(&response, base.json)?;
+    let cache = QueryCache::new(
+        Duration::from_secs(args.cache_ttl),
+        !args.no_cache && args.watch.is_none() && !args.engine.realtime,
+        client.org_name(),
+        base.project.as_deref(),
+    );
+    if let Some(SqlCommands::Batch(a)) = &args.command {
+        return batch::run_batch(&client, &cache, &a.dir, &a.out_dir, &args, format).await;
+    }
+    let queries = match &args.command {
+        Some(SqlCommands::Run(a)) => vec![(a.name.clone(), saved::load(&a.name)?)],
+        _ => collect_queries(&args)?,
+    };
+    let mut statements = Vec::new();
+    for (label, query) in queries {
+        let query = template::fill_placeholders(&query, &args.params)?;
+        let parts = statements::split_statements(&query);
+        if parts.len() == 1 {
+            statements.extend(parts.into_iter().map(|part| (label.clone(), part)));
+        } else {
+            statements.extend(
+                parts
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, part)| (format!("{label} #{}", idx + 1), part)),
+            );
+        }
+    }
+    let queries = statements;
+    if let Some(interval) = args.watch {
+        let [(_, query)] = queries.as_slice() else {
+            bail!("--watch requires a single query");
+        };
+        return watch_query(
+            &client,
+            &cache,
+            query,
+            &args,
+            format,
+            Duration::from_secs(interval),
+        )
+        .await;
+    }
+    if !queries.is_empty() {
+        if queries.len() > 1 && args.output.is_some() {
+            bail!("--output can only be used with a single query");
+        }
+        let mut failures = 0;
+        for (label, query) in &queries {
+            if queries.len() > 1 {
+                eprintln!("-- {label}");
+            }
+            if let Err(err) = run_query(&client, &cache, query, &args, format).await {
+                if queries.len() == 1 {
+                    return Err(err);
+                }
+                if !args.keep_going {
+                    return Err(err.context(format!("{label} failed")));
+                }
+                eprintln!("Error: {err:#}");
+                failures += 1;
+            }
+        }
+        if failures > 0 {
+            bail!("{failures} of {} queries failed", queries.len());
+        }
         return Ok(());
     }
 
-    run_interactive(base, client).await
+    let history = HistoryFile::new(args.history_size, !args.no_history);
+    let limit = match args.limit {
+        Some(rows) => Some(RowLimit {
+            rows,
+            explicit: true,
+        }),
+        None => (args.default_limit > 0).then_some(RowLimit {
+            rows: args.default_limit,
+            explicit: false,
+        }),
+    };
+    if args.no_tui || is_limited_terminal() {
+        return run_plain_repl(
+            format,
+            limit,
+            args.max_col_width(),
+            args.engine,
+            client,
+            history,
+        )
+        .await;
+    }
+
+    let mut app = App::new(format, args.submit_key, limit, base.project, history);
+    app.max_col_width = args.max_col_width();
+    app.engine = args.engine;
+    run_interactive(app, client).await
+}
+
+fn save_query(args: &SaveArgs) -> Result<()> {
+    let query = match args.query.as_deref() {
+        Some("-") | None => read_stdin_query()?,
+        Some(query) => query.to_string(),
+    };
+    saved::save(&args.name, &query, args.force)?;
+    print_command_status(CommandStatus::Success, &format!("Saved '{}'", args.name));
+    Ok(())
+}
+
+fn list_saved_queries(json: bool) -> Result<()> {
+    let queries = saved::list()?;
+    if json {
+        println!("{}", serde_json::to_string(&queries)?);
+        return Ok(());
+    }
+
+    if queries.is_empty() {
+        println!("No saved queries. Save one with: bt sql save <name> \"<query>\"");
+        return Ok(());
+    }
+
+    let name_width = queries
+        .iter()
+        .map(|q| q.name.width())
+        .max()
+        .unwrap_or(20)
+        .max(20);
+    println!(
+        "{}  {}",
+        console::style(format!("{:name_width$}", "Name"))
+            .dim()
+            .bold(),
+        console::style("Query").dim().bold()
+    );
+    for saved in &queries {
+        let first_line = saved.query.lines().next().unwrap_or_default();
+        let suffix = if saved.query.lines().nth(1).is_some() {
+            " …"
+        } else {
+            ""
+        };
+        let padding = name_width - saved.name.width();
+        println!("{}{:padding$}  {first_line}{suffix}", saved.name, "");
+    }
+    Ok(())
+}
+
+/// Query text and a label for each query source, from `--file` paths, the
+/// positional argument, or stdin (`-`, or no query when stdin is not a TTY).
+fn collect_queries(args: &SqlArgs) -> Result<Vec<(String, String)>> {
+    if !args.file.is_empty() {
+        return args
+            .file
+            .iter()
+            .map(|path| {
+                if path.as_os_str() == "-" {
+                    return Ok(("stdin".to_string(), read_stdin_query()?));
+                }
+                let query = std::fs::read_to_string(path)
+                    .with_context(|| format!("failed to read query file {}", path.display()))?;
+                let query = query.trim();
+                if query.is_empty() {
+                    bail!("query file {} is empty", path.display());
+                }
+                Ok((path.display().to_string(), query.to_string()))
+            })
+            .collect();
+    }
+
+    match args.query.as_deref() {
+        Some("-") => Ok(vec![("stdin".to_string(), read_stdin_query()?)]),
+        Some(query) => Ok(vec![("query".to_string(), query.to_string())]),
+        None if !io::stdin().is_terminal() => Ok(vec![("stdin".to_string(), read_stdin_query()?)]),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn read_stdin_query() -> Result<String> {
+    let mut query = String::new();
+    io::stdin()
+        .read_to_string(&mut query)
+        .context("failed to read query from stdin")?;
+    let query = query.trim();
+    if query.is_empty() {
+        bail!("no query provided on stdin");
+    }
+    Ok(query.to_string())
 }
 
-async fn run_interactive(base: BaseArgs, client: ApiClient) -> Result<()> {
+async fn run_query(
+    client: &ApiClient,
+    cache: &QueryCache,
+    query: &str,
+    args: &SqlArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let query = &match args.limit {
+        Some(rows) => limit::apply_limit(
+            query,
+            RowLimit {
+                rows,
+                explicit: true,
+            },
+        ),
+        None => query.to_string(),
+    };
+    let follow = args.all || args.max_pages.is_some();
+    let parquet_output = args.output.as_deref().is_some_and(is_parquet_path);
+    if format == OutputFormat::Jsonl && !parquet_output {
+        return match &args.output {
+            Some(path) => {
+                let mut file = AtomicFile::create(path)?;
+                let rows = stream_jsonl(
+                    client,
+                    query,
+                    args.engine,
+                    follow,
+                    args.max_pages,
+                    &mut file,
+                )
+                .await?;
+                file.commit()?;
+                report_written(rows, path);
+                Ok(())
+            }
+            None => {
+                let mut stdout = io::stdout();
+                stream_jsonl(
+                    client,
+                    query,
+                    args.engine,
+                    follow,
+                    args.max_pages,
+                    &mut stdout,
+                )
+                .await?;
+                Ok(())
+            }
+        };
+    }
+
+    let cache_key = cache.key(query, args.engine, follow, args.max_pages);
+    let started = Instant::now();
+    let (mut response, elapsed) = match cache.get(&cache_key) {
+        Some((response, age)) => {
+            eprintln!(
+                "Using results cached {}s ago (--no-cache to refresh).",
+                age.as_secs()
+            );
+            (response, None)
+        }
+        None => {
+            let response = if follow {
+                with_spinner(
+                    "Running query...",
+                    execute_query_all(client, query, args.engine, args.max_pages),
+                )
+                .await?
+            } else {
+                with_spinner(
+                    "Running query...",
+                    execute_query(client, query, args.engine),
+                )
+                .await?
+            };
+            if let Err(err) = cache.put(&cache_key, &response) {
+                eprintln!("Warning: failed to cache results: {err}");
+            }
+            (response, Some(started.elapsed()))
+        }
+    };
+    response.select_columns(&args.columns)?;
+    match &args.output {
+        Some(path) => {
+            let mut file = AtomicFile::create(path)?;
+            if parquet_output {
+                let headers = result_headers(&response);
+                parquet::write_parquet(&mut file, &headers, &response.schema, &response.data)?;
+            } else {
+                // Files keep full cell values; truncation is only for fitting the terminal.
+                writeln!(file, "{}", format_response(&response, format, None)?)
+                    .with_context(|| format!("failed to write {}", path.display()))?;
+            }
+            file.commit()?;
+            report_written(response.data.len(), path);
+        }
+        None => print_response(
+            &response,
+            format,
+            args.max_col_width(),
+            !args.no_pager && args.watch.is_none(),
+        )?,
+    }
+    if let (Some(max_pages), Some(_)) = (args.max_pages, &response.cursor) {
+        eprintln!("Stopped after {max_pages} page(s); more rows are available.");
+    }
+    if io::stderr().is_terminal() {
+        eprintln!(
+            "{}",
+            console::style(stats::format_stats(&response, elapsed)).dim()
+        );
+    }
+    Ok(())
+}
+
+/// Re-run `query` every `interval`, clearing the terminal before each run. Runs until interrupted.
+async fn watch_query(
+    client: &ApiClient,
+    cache: &QueryCache,
+    query: &str,
+    args: &SqlArgs,
+    format: OutputFormat,
+    interval: Duration,
+) -> Result<()> {
+    let clear = io::stdout().is_terminal();
+    let summary = query.lines().next().unwrap_or_default();
+    loop {
+        if clear {
+            io::stdout()
+                .execute(Clear(ClearType::All))?
+                .execute(MoveTo(0, 0))?;
+        }
+        eprintln!(
+            "{}",
+            console::style(format!("Every {}s: {summary}", interval.as_secs())).dim()
+        );
+        if let Err(err) = run_query(client, cache, query, args, format).await {
+            eprintln!("Error: {err}");
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+async fn run_interactive(app: App, client: ApiClient) -> Result<()> {
     let handle = tokio::runtime::Handle::current();
-    tokio::task::block_in_place(|| run_interactive_blocking(base.json, client, handle))
+    let client = Arc::new(client);
+    tokio::task::block_in_place(|| run_interactive_blocking(app, client, handle))
 }
 
-fn run_interactive_blocking(
-    json_output: bool,
+async fn run_plain_repl(
+    format: OutputFormat,
+    limit: Option<RowLimit>,
+    max_col_width: Option<usize>,
+    engine: EngineOptions,
     client: ApiClient,
+    history: HistoryFile,
+) -> Result<()> {
+    let handle = tokio::runtime::Handle::current();
+    tokio::task::block_in_place(|| {
+        run_plain_repl_blocking(
+            format,
+            limit,
+            max_col_width,
+            engine,
+            &client,
+            &history,
+            &handle,
+        )
+    })
+}
+
+fn run_plain_repl_blocking(
+    format: OutputFormat,
+    limit: Option<RowLimit>,
+    max_col_width: Option<usize>,
+    engine: EngineOptions,
+    client: &ApiClient,
+    history: &HistoryFile,
+    handle: &tokio::runtime::Handle,
+) -> Result<()> {
+    let mut entries = history.load();
+    eprintln!("Enter SQL and press Enter. Type \\q or press Ctrl+D to exit.");
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("sql> ");
+        io::stdout().flush()?;
+
+        let Some(line) = lines.next() else {
+            println!();
+            break;
+        };
+        let query = line?.trim().to_string();
+        if query.is_empty() {
+            continue;
+        }
+        if matches!(query.as_str(), "\\q" | "exit" | "quit") {
+            break;
+        }
+
+        let started = Instant::now();
+        match handle.block_on(execute_limited_query(client, &query, engine, limit)) {
+            Ok(response) => {
+                print_response(&response, format, max_col_width, false)?;
+                eprintln!(
+                    "{}",
+                    console::style(stats::format_stats(&response, Some(started.elapsed()))).dim()
+                );
+                if let Some(warning) = truncation_warning(&response, &query, limit) {
+                    eprintln!("{warning}");
+                }
+            }
+            Err(err) => eprintln!("Error: {err}"),
+        }
+
+        if entries.last() != Some(&query) {
+            entries.push(query);
+            if let Err(err) = history.save(&entries) {
+                eprintln!("Warning: {err}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn run_interactive_blocking(
+    app: App,
+    client: Arc<ApiClient>,
     handle: tokio::runtime::Handle,
 ) -> Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     stdout.execute(EnterAlternateScreen)?;
+    // Lets terminals that support it report Shift+Enter distinctly from Enter.
+    let keyboard_enhanced = supports_keyboard_enhancement().unwrap_or(false);
+    if keyboard_enhanced {
+        stdout
+            .execute(PushKeyboardEnhancementFlags(
+                KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES,
+            ))
+            .ok();
+    }
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let res = run_app(&mut terminal, json_output, client, handle);
+    let res = run_app(&mut terminal, app, client, handle);
 
+    if keyboard_enhanced {
+        terminal
+            .backend_mut()
+            .execute(PopKeyboardEnhancementFlags)
+            .ok();
+    }
     disable_raw_mode().ok();
     terminal.backend_mut().execute(LeaveAlternateScreen).ok();
     terminal.show_cursor().ok();
@@ -100,22 +802,17 @@ fn run_interactive_blocking(
 
 fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
-    json_output: bool,
-    client: ApiClient,
+    mut app: App,
+    client: Arc<ApiClient>,
     handle: tokio::runtime::Handle,
 ) -> Result<()> {
-    let mut app = App::new(json_output);
-
     loop {
+        app.poll_running(&handle);
         terminal.draw(|f| ui(f, &app))?;
 
-        if event::poll(Duration::from_millis(200))? {
+        if event::poll(Duration::from_millis(100))? {
             match event::read()? {
-                Event::Key(key) => {
-                    if handle_key_event(&mut app, key, &client, &handle)? {
-                        break;
-                    }
-                }
+                Event::Key(key) if handle_key_event(&mut app, key, &client, &handle)? => break,
                 Event::Resize(_, _) => {}
                 _ => {}
             }
@@ -128,103 +825,539 @@ fn run_app(
 fn handle_key_event(
     app: &mut App,
     key: KeyEvent,
-    client: &ApiClient,
+    client: &Arc<ApiClient>,
     handle: &tokio::runtime::Handle,
 ) -> Result<bool> {
+    if app.running.is_some() {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => app.cancel_running(),
+            KeyCode::Char('c') if ctrl => app.cancel_running(),
+            KeyCode::Char('d') if ctrl => {
+                app.cancel_running();
+                return Ok(true);
+            }
+            // Tabs stay usable; the results land in the tab the query was started from.
+            KeyCode::Char('t') if ctrl => app.new_tab(),
+            KeyCode::Left if ctrl => app.cycle_tab(false),
+            KeyCode::Right if ctrl => app.cycle_tab(true),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    if let Some(inspector) = &mut app.inspector {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.inspector = None,
+            KeyCode::Up => inspector.scroll_by(-1),
+            KeyCode::Down => inspector.scroll_by(1),
+            KeyCode::PageUp => inspector.scroll_by(-20),
+            KeyCode::PageDown => inspector.scroll_by(20),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    if app.search.is_some() {
+        handle_search_key_event(app, key);
+        return Ok(false);
+    }
+
+    if app.export.is_some() {
+        handle_export_key_event(app, key);
+        return Ok(false);
+    }
+
+    if key.code == KeyCode::Char('b') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        toggle_schema(app, client, handle);
+        return Ok(false);
+    }
+    if app.schema.as_ref().is_some_and(|schema| schema.focused) {
+        handle_schema_key_event(app, key, client, handle);
+        return Ok(false);
+    }
+    if app.results_focus {
+        handle_results_key_event(app, key);
+        return Ok(false);
+    }
+
     match key.code {
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.start_search();
+        }
         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.clear_input();
             app.status = "Cleared input".to_string();
         }
-        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(true),
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            return Ok(app.close_tab());
+        }
+        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => app.new_tab(),
+        KeyCode::Left if key.modifiers.contains(KeyModifiers::CONTROL) => app.cycle_tab(false),
+        KeyCode::Right if key.modifiers.contains(KeyModifiers::CONTROL) => app.cycle_tab(true),
         KeyCode::Esc => return Ok(true),
         KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.output.clear();
         }
+        KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.start_export();
+        }
+        KeyCode::Char('o') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.cycle_sort();
+        }
+        KeyCode::Tab => app.focus_results(),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::ALT) => app.copy_results(),
+        KeyCode::Char('q') if key.modifiers.contains(KeyModifiers::ALT) => app.copy_query(),
+        KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.edit(Editor::insert_newline);
+        }
+        KeyCode::Enter if !app.is_submit(key) => app.edit(Editor::insert_newline),
         KeyCode::Enter => {
-            let query = app.input.trim().to_string();
+            let query = app.input.text().trim().to_string();
             if query.is_empty() {
                 return Ok(false);
             }
+            app.start_query(query, client, handle);
+        }
+        KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.input.move_home();
+        }
+        KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.input.move_end();
+        }
+        KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.edit(Editor::delete_word_back);
+        }
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.edit(Editor::kill_to_line_start);
+        }
+        KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.edit(Editor::kill_to_line_end);
+        }
+        KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.input.move_word_left();
+        }
+        KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.input.move_word_right();
+        }
+        KeyCode::Backspace => app.edit(Editor::backspace),
+        KeyCode::Delete => app.edit(Editor::delete),
+        KeyCode::Left => app.input.move_left(),
+        KeyCode::Right => app.input.move_right(),
+        KeyCode::Home => app.input.move_home(),
+        KeyCode::End => app.input.move_end(),
+        KeyCode::Up => app.move_up(),
+        KeyCode::Down => app.move_down(),
+        KeyCode::Char(ch)
+            if !key.modifiers.contains(KeyModifiers::CONTROL)
+                && !key.modifiers.contains(KeyModifiers::ALT) =>
+        {
+            app.edit(|input| input.insert_char(ch));
+        }
+        _ => {}
+    }
 
-            app.status = "Running query...".to_string();
-            let result = handle.block_on(execute_query(client, &query));
-            match result {
-                Ok(response) => {
-                    app.output = format_response(&response, app.json_output)?;
-                    app.status = "OK".to_string();
-                }
-                Err(err) => {
-                    app.output = format!("Error: {err}");
-                    app.status = "Error".to_string();
+    Ok(false)
+}
+
+/// Ctrl+B: open and focus the schema sidebar, or close it when it already has focus.
+fn toggle_schema(app: &mut App, client: &ApiClient, handle: &tokio::runtime::Handle) {
+    match &mut app.schema {
+        Some(schema) if schema.focused => app.schema = None,
+        Some(schema) => schema.focused = true,
+        None => {
+            let schema = match &app.project {
+                None => SchemaBrowser::with_message("No project selected (use --project)"),
+                Some(project) => {
+                    app.status = "Loading schema...".to_string();
+                    match handle.block_on(schema::load_objects(client, project)) {
+                        Ok(objects) => SchemaBrowser::new(objects),
+                        Err(err) => SchemaBrowser::with_message(format!("Error: {err}")),
+                    }
                 }
+            };
+            app.status =
+                "Up/Down to browse, Right/Left to expand, Enter to insert, Ctrl+B to return."
+                    .to_string();
+            app.schema = Some(schema);
+        }
+    }
+}
+
+fn handle_schema_key_event(
+    app: &mut App,
+    key: KeyEvent,
+    client: &ApiClient,
+    handle: &tokio::runtime::Handle,
+) {
+    let Some(schema) = app.schema.as_mut() else {
+        return;
+    };
+    match key.code {
+        KeyCode::Up => schema.select_prev(),
+        KeyCode::Down => schema.select_next(),
+        KeyCode::Left => schema.toggle_expanded(false),
+        KeyCode::Right => match schema.pending_fields() {
+            Some(source) => match handle.block_on(schema::load_fields(client, &source, app.engine))
+            {
+                Ok(fields) => schema.set_fields(fields),
+                Err(err) => app.status = format!("Failed to load fields: {err}"),
+            },
+            None => schema.toggle_expanded(true),
+        },
+        KeyCode::Enter => {
+            if let Some(text) = schema.selected_text() {
+                schema.focused = false;
+                app.edit(|input| input.insert_str(&text));
             }
+        }
+        KeyCode::Esc => app.schema = None,
+        _ => {}
+    }
+}
 
-            app.push_history(&query);
-            app.clear_input();
+fn handle_results_key_event(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Tab | KeyCode::Esc => {
+            app.results_focus = false;
+            app.status = "Editing query".to_string();
+            return;
         }
-        KeyCode::Backspace => app.backspace(),
-        KeyCode::Delete => app.delete(),
-        KeyCode::Left => app.move_left(),
-        KeyCode::Right => app.move_right(),
-        KeyCode::Home => app.move_home(),
-        KeyCode::End => app.move_end(),
-        KeyCode::Up => app.history_prev(),
-        KeyCode::Down => app.history_next(),
-        KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
-            if !key.modifiers.contains(KeyModifiers::ALT) {
-                app.insert_char(ch);
-            }
+        KeyCode::Char('o') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.cycle_sort();
+            return;
+        }
+        KeyCode::Enter => {
+            app.inspect_selected();
+            return;
+        }
+        KeyCode::Up => app.move_selection(-1, 0),
+        KeyCode::Down => app.move_selection(1, 0),
+        KeyCode::Left => app.move_selection(0, -1),
+        KeyCode::Right => app.move_selection(0, 1),
+        KeyCode::PageUp => app.move_selection(-20, 0),
+        KeyCode::PageDown => app.move_selection(20, 0),
+        _ => return,
+    }
+    app.status = app.selection_status();
+}
+
+fn handle_export_key_event(app: &mut App, key: KeyEvent) {
+    let Some(export) = app.export.as_mut() else {
+        return;
+    };
+    match key.code {
+        KeyCode::Esc => {
+            app.export = None;
+            app.status = "Export cancelled".to_string();
+        }
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.export = None;
+            app.status = "Export cancelled".to_string();
+        }
+        KeyCode::Enter => app.finish_export(),
+        KeyCode::Tab => {
+            export.format = export.format.next();
+            let path = export.format.apply_extension(export.path.text());
+            export.path.set_text(path);
+        }
+        KeyCode::Backspace => export.path.backspace(),
+        KeyCode::Delete => export.path.delete(),
+        KeyCode::Left => export.path.move_left(),
+        KeyCode::Right => export.path.move_right(),
+        KeyCode::Home => export.path.move_home(),
+        KeyCode::End => export.path.move_end(),
+        KeyCode::Char(ch)
+            if !key.modifiers.contains(KeyModifiers::CONTROL)
+                && !key.modifiers.contains(KeyModifiers::ALT) =>
+        {
+            export.path.insert_char(ch);
         }
         _ => {}
     }
+}
 
-    Ok(false)
+fn handle_search_key_event(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.search_older();
+        }
+        KeyCode::Char('g') | KeyCode::Char('c')
+            if key.modifiers.contains(KeyModifiers::CONTROL) =>
+        {
+            app.cancel_search();
+        }
+        KeyCode::Esc => app.cancel_search(),
+        KeyCode::Backspace => app.search_backspace(),
+        KeyCode::Char(ch)
+            if !key.modifiers.contains(KeyModifiers::CONTROL)
+                && !key.modifiers.contains(KeyModifiers::ALT) =>
+        {
+            app.search_insert_char(ch);
+        }
+        _ => app.accept_search(),
+    }
 }
 
 fn ui(frame: &mut Frame<'_>, app: &App) {
+    let mut area = frame.area();
+    if let Some(schema) = &app.schema {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(SCHEMA_WIDTH), Constraint::Min(20)])
+            .split(area);
+        schema.render(frame, columns[0]);
+        area = columns[1];
+    }
+
+    if app.tabs.len() > 1 {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+        let tabs = Tabs::new(app.tab_titles())
+            .select(app.active_tab)
+            .highlight_style(Style::default().fg(Color::Black).bg(Color::Cyan));
+        frame.render_widget(tabs, rows[0]);
+        area = rows[1];
+    }
+
+    let input_lines = if app.search.is_some() || app.export.is_some() {
+        1
+    } else {
+        app.input.line_count().min(MAX_INPUT_LINES)
+    };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Min(3),
-            Constraint::Length(3),
+            Constraint::Length(input_lines as u16 + 2),
             Constraint::Length(1),
         ])
-        .split(frame.area());
+        .split(area);
 
+    let results_block = if app.results_focus {
+        Block::default()
+            .title(format!("Results - {}", app.selection_status()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+    } else {
+        Block::default().title("Results").borders(Borders::ALL)
+    };
     let output = Paragraph::new(app.output.as_str())
-        .block(Block::default().title("Results").borders(Borders::ALL))
+        .block(results_block)
         .wrap(Wrap { trim: false });
     frame.render_widget(output, chunks[0]);
 
-    let (input_view, cursor_col) = app.input_view(chunks[1]);
-    let input =
-        Paragraph::new(input_view).block(Block::default().title("SQL").borders(Borders::ALL));
-    frame.render_widget(input, chunks[1]);
-    frame.set_cursor_position((chunks[1].x + 1 + cursor_col, chunks[1].y + 1));
+    if let Some(export) = &app.export {
+        let (lines, (cursor_col, _)) = export
+            .path
+            .view(chunks[1].width.saturating_sub(2) as usize, 1);
+        let title = format!(
+            "Export to ({}, Tab to change format)",
+            export.format.extension()
+        );
+        let input = Paragraph::new(lines.join(""))
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(input, chunks[1]);
+        frame.set_cursor_position((chunks[1].x + 1 + cursor_col, chunks[1].y + 1));
+    } else if let Some(search) = &app.search {
+        let (search_view, cursor_col) = search_view(app, search, chunks[1]);
+        let title = format!("(reverse-i-search)'{}'", search.query);
+        let input =
+            Paragraph::new(search_view).block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(input, chunks[1]);
+        frame.set_cursor_position((chunks[1].x + 1 + cursor_col, chunks[1].y + 1));
+    } else {
+        let (lines, (cursor_col, cursor_row)) = app.input.view(
+            chunks[1].width.saturating_sub(2) as usize,
+            chunks[1].height.saturating_sub(2) as usize,
+        );
+        let lines: Vec<Line> = lines
+            .iter()
+            .map(|line| highlight::highlight_line(line))
+            .collect();
+        let input =
+            Paragraph::new(lines).block(Block::default().title("SQL").borders(Borders::ALL));
+        frame.render_widget(input, chunks[1]);
+        frame.set_cursor_position((chunks[1].x + 1 + cursor_col, chunks[1].y + 1 + cursor_row));
+    }
 
     let status = Paragraph::new(Line::from(app.status.as_str()))
         .style(Style::default())
         .block(Block::default().borders(Borders::TOP))
         .wrap(Wrap { trim: true });
     frame.render_widget(status, chunks[2]);
+
+    if let Some(inspector) = &app.inspector {
+        inspector.render(frame);
+    }
 }
 
-fn format_response(response: &SqlResponse, json_output: bool) -> Result<String> {
-    if json_output {
-        Ok(serde_json::to_string(response)?)
-    } else if let Some(table) = render_table(response) {
-        Ok(table)
-    } else {
-        Ok(serde_json::to_string_pretty(response)?)
+fn search_view(app: &App, search: &HistorySearch, area: Rect) -> (Line<'static>, u16) {
+    let Some(idx) = search.match_index else {
+        return (Line::from(""), 0);
+    };
+    // Newlines are shown as spaces so multi-line entries fit on the single search line.
+    let entry = &app.history[idx].replace('\n', " ");
+    let match_start = entry.find(&search.query).unwrap_or(0);
+    let match_end = match_start + search.query.len();
+
+    let (start, end) = visible_range(entry, match_start, area);
+    let mut spans = Vec::new();
+    let clamp = |pos: usize| pos.clamp(start, end);
+    spans.push(Span::raw(entry[start..clamp(match_start)].to_string()));
+    spans.push(Span::styled(
+        entry[clamp(match_start)..clamp(match_end)].to_string(),
+        Style::default().fg(Color::Black).bg(Color::Yellow),
+    ));
+    spans.push(Span::raw(entry[clamp(match_end)..end].to_string()));
+
+    let cursor_col = match_start.saturating_sub(start) as u16;
+    (Line::from(spans), cursor_col)
+}
+
+/// Render `response` in `format`. Table cells are truncated to `max_col_width` when given.
+fn format_response(
+    response: &SqlResponse,
+    format: OutputFormat,
+    max_col_width: Option<usize>,
+) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string(response)?),
+        OutputFormat::Jsonl => {
+            let rows = response
+                .data
+                .iter()
+                .map(serde_json::to_string)
+                .collect::<serde_json::Result<Vec<_>>>()?;
+            Ok(rows.join("\n"))
+        }
+        OutputFormat::Vertical => Ok(render_vertical(response)),
+        OutputFormat::Html => Ok(html::render_html(response)),
+        OutputFormat::Table => match render_table(response, max_col_width) {
+            Some(table) => Ok(table),
+            None => Ok(serde_json::to_string_pretty(response)?),
+        },
+    }
+}
+
+/// Run `query` with `limit` applied when given.
+async fn execute_limited_query(
+    client: &ApiClient,
+    query: &str,
+    engine: EngineOptions,
+    limit: Option<RowLimit>,
+) -> Result<SqlResponse> {
+    match limit {
+        Some(limit) => execute_query(client, &limit::apply_limit(query, limit), engine).await,
+        None => execute_query(client, query, engine).await,
     }
 }
 
-async fn execute_query(client: &ApiClient, query: &str) -> Result<SqlResponse> {
-    let body = json!({
+/// Warning shown when an interactive query filled the row limit we applied to it; a
+/// query whose own LIMIT stood got exactly what it asked for.
+fn truncation_warning(
+    response: &SqlResponse,
+    query: &str,
+    limit: Option<RowLimit>,
+) -> Option<String> {
+    let limit = limit?;
+    limit::limited(query, limit)?;
+    (response.data.len() >= limit.rows).then(|| {
+        format!(
+            "Showing the first {} rows; results may be truncated (use --limit to change).",
+            limit.rows
+        )
+    })
+}
+
+/// `value` as a single-quoted SQL string literal, with embedded quotes doubled.
+pub(crate) fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Run a BTQL query and return its rows, for commands that aggregate data under the hood.
+pub(crate) async fn query_rows(client: &ApiClient, query: &str) -> Result<Vec<Map<String, Value>>> {
+    Ok(execute_query(client, query, EngineOptions::default())
+        .await?
+        .data)
+}
+
+/// Run one page of a BTQL query, returning its rows and the cursor for the next page.
+pub(crate) async fn query_page(
+    client: &ApiClient,
+    query: &str,
+    cursor: Option<&str>,
+) -> Result<(Vec<Map<String, Value>>, Option<String>)> {
+    let page = execute_query_page(client, query, EngineOptions::default(), cursor).await?;
+    Ok((page.data, page.cursor))
+}
+
+/// Like `query_rows`, but follows cursors until every page has been read.
+pub(crate) async fn query_all_rows(
+    client: &ApiClient,
+    query: &str,
+) -> Result<Vec<Map<String, Value>>> {
+    Ok(
+        execute_query_all(client, query, EngineOptions::default(), None)
+            .await?
+            .data,
+    )
+}
+
+async fn execute_query(
+    client: &ApiClient,
+    query: &str,
+    engine: EngineOptions,
+) -> Result<SqlResponse> {
+    execute_query_page(client, query, engine, None).await
+}
+
+/// Re-issue `query` with each returned cursor, concatenating rows until the
+/// cursor is exhausted or `max_pages` pages have been fetched.
+async fn execute_query_all(
+    client: &ApiClient,
+    query: &str,
+    engine: EngineOptions,
+    max_pages: Option<usize>,
+) -> Result<SqlResponse> {
+    let mut response = execute_query_page(client, query, engine, None).await?;
+    let mut pages = 1;
+
+    while let Some(cursor) = response.cursor.take() {
+        if max_pages.is_some_and(|max| pages >= max) {
+            response.cursor = Some(cursor);
+            break;
+        }
+        let page = execute_query_page(client, query, engine, Some(&cursor)).await?;
+        if page.data.is_empty() {
+            break;
+        }
+        response.data.extend(page.data);
+        response.cursor = page.cursor;
+        response.freshness_state = page.freshness_state.or(response.freshness_state);
+        response.realtime_state = page.realtime_state.or(response.realtime_state);
+        pages += 1;
+    }
+
+    Ok(response)
+}
+
+async fn execute_query_page(
+    client: &ApiClient,
+    query: &str,
+    engine: EngineOptions,
+    cursor: Option<&str>,
+) -> Result<SqlResponse> {
+    let mut body = json!({
         "query": query,
         "fmt": "json",
     });
+    if let Some(cursor) = cursor {
+        body["cursor"] = json!(cursor);
+    }
+    engine.apply(&mut body);
 
     let org_name = client.org_name();
     let headers = if !org_name.is_empty() {
@@ -236,20 +1369,82 @@ async fn execute_query(client: &ApiClient, query: &str) -> Result<SqlResponse> {
     client.post_with_headers("/btql", &body, &headers).await
 }
 
-fn print_response(response: &SqlResponse, json_output: bool) -> Result<()> {
-    let output = format_response(response, json_output)?;
-    println!("{output}");
+/// Print rows as JSON lines as each page arrives, so memory stays bounded by page size.
+/// Returns the number of rows written.
+async fn stream_jsonl(
+    client: &ApiClient,
+    query: &str,
+    engine: EngineOptions,
+    follow: bool,
+    max_pages: Option<usize>,
+    out: &mut dyn Write,
+) -> Result<usize> {
+    let mut cursor: Option<String> = None;
+    let mut pages = 0;
+    let mut rows = 0;
+    loop {
+        let page = execute_query_page(client, query, engine, cursor.as_deref()).await?;
+        pages += 1;
+
+        for row in &page.data {
+            serde_json::to_writer(&mut *out, row)?;
+            out.write_all(b"\n")?;
+        }
+        out.flush()?;
+        rows += page.data.len();
+
+        cursor = page.cursor;
+        if !follow || page.data.is_empty() || cursor.is_none() {
+            break;
+        }
+        if max_pages.is_some_and(|max| pages >= max) {
+            eprintln!("Stopped after {pages} page(s); more rows are available.");
+            break;
+        }
+    }
+    Ok(rows)
+}
+
+fn report_written(rows: usize, path: &Path) {
+    eprintln!("Wrote {rows} row(s) to {}", path.display());
+}
+
+fn print_response(
+    response: &SqlResponse,
+    format: OutputFormat,
+    max_col_width: Option<usize>,
+    paged: bool,
+) -> Result<()> {
+    let output = format_response(response, format, max_col_width)?;
+    if paged {
+        print_paged(&output)?;
+    } else {
+        println!("{output}");
+    }
     Ok(())
 }
 
-fn render_table(response: &SqlResponse) -> Option<String> {
+fn is_parquet_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("parquet"))
+}
+
+fn result_headers(response: &SqlResponse) -> Vec<String> {
+    if let Some(columns) = &response.columns {
+        return columns.clone();
+    }
     let mut headers = extract_headers(&response.schema);
     if headers.is_empty() {
         if let Some(first_row) = response.data.first() {
             headers = first_row.keys().cloned().collect();
         }
     }
+    headers
+}
 
+fn render_table(response: &SqlResponse, max_col_width: Option<usize>) -> Option<String> {
+    let headers = result_headers(response);
     if headers.is_empty() {
         if response.data.is_empty() {
             return Some("(no rows)".to_string());
@@ -263,7 +1458,13 @@ fn render_table(response: &SqlResponse) -> Option<String> {
         .map(|row| {
             headers
                 .iter()
-                .map(|header| format_cell(row.get(header)))
+                .map(|header| {
+                    let cell = format_cell(row.get(header));
+                    match max_col_width {
+                        Some(max) => truncate_cell(&cell, max),
+                        None => cell,
+                    }
+                })
                 .collect()
         })
         .collect();
@@ -271,6 +1472,38 @@ fn render_table(response: &SqlResponse) -> Option<String> {
     Some(build_table(&headers, &rows))
 }
 
+/// Render each row as a block of `column: value` lines, like psql's `\x`.
+/// JSON objects and arrays are pretty-printed and indented under their column.
+fn render_vertical(response: &SqlResponse) -> String {
+    if response.data.is_empty() {
+        return "(no rows)".to_string();
+    }
+    let headers = result_headers(response);
+    let name_width = headers
+        .iter()
+        .map(|h| UnicodeWidthStr::width(h.as_str()))
+        .max()
+        .unwrap_or(0);
+    let indent = " ".repeat(name_width + 2);
+
+    let mut blocks = Vec::with_capacity(response.data.len());
+    for (idx, row) in response.data.iter().enumerate() {
+        let mut lines = vec![format!("-[ RECORD {} ]", idx + 1)];
+        for header in &headers {
+            let value = match row.get(header) {
+                Some(v @ (Value::Array(_) | Value::Object(_))) => {
+                    serde_json::to_string_pretty(v).unwrap_or_default()
+                }
+                other => format_cell(other),
+            };
+            let value = value.replace('\n', &format!("\n{indent}"));
+            lines.push(format!("{}: {value}", pad_cell(header, name_width)));
+        }
+        blocks.push(lines.join("\n"));
+    }
+    blocks.join("\n")
+}
+
 fn extract_headers(schema: &Value) -> Vec<String> {
     let items = schema.get("items").and_then(|v| v.as_object());
     let properties = items
@@ -347,6 +1580,25 @@ fn build_row(cells: &[String], widths: &[usize]) -> String {
     line
 }
 
+/// Shorten `cell` to at most `max` display columns, ending in an ellipsis when cut.
+pub(crate) fn truncate_cell(cell: &str, max: usize) -> String {
+    if max == 0 || UnicodeWidthStr::width(cell) <= max {
+        return cell.to_string();
+    }
+    let mut out = String::new();
+    let mut width = 0;
+    for ch in cell.chars() {
+        let ch_width = UnicodeWidthStr::width(ch.encode_utf8(&mut [0; 4]) as &str);
+        if width + ch_width > max - 1 {
+            break;
+        }
+        out.push(ch);
+        width += ch_width;
+    }
+    out.push('…');
+    out
+}
+
 fn pad_cell(cell: &str, width: usize) -> String {
     let current = UnicodeWidthStr::width(cell);
     if current >= width {
@@ -358,79 +1610,490 @@ fn pad_cell(cell: &str, width: usize) -> String {
     out
 }
 
+const MAX_INPUT_LINES: usize = 10;
+const SCHEMA_WIDTH: u16 = 32;
+const TAB_TITLE_WIDTH: usize = 20;
+
 struct App {
-    input: String,
-    cursor: usize,
+    input: Editor,
     output: String,
     status: String,
     history: Vec<String>,
     history_index: Option<usize>,
-    json_output: bool,
+    search: Option<HistorySearch>,
+    history_file: HistoryFile,
+    format: OutputFormat,
+    submit_key: SubmitKey,
+    limit: Option<RowLimit>,
+    project: Option<String>,
+    schema: Option<SchemaBrowser>,
+    last_response: Option<SqlResponse>,
+    export: Option<ExportPrompt>,
+    running: Option<RunningQuery>,
+    max_col_width: Option<usize>,
+    engine: EngineOptions,
+    /// Column index and direction (true for descending) the results are sorted by.
+    sort: Option<(usize, bool)>,
+    /// `last_response` with `sort` applied, when sorted.
+    sorted: Option<SqlResponse>,
+    results_focus: bool,
+    /// Selected (row, column) in the results while they have focus.
+    selected: (usize, usize),
+    inspector: Option<Inspector>,
+    /// Per-tab state for every tab; the active tab's slot is a placeholder while
+    /// its state lives in the fields above.
+    tabs: Vec<TabState>,
+    active_tab: usize,
+}
+
+/// Editor and results state of one query tab, parked while another tab is active.
+#[derive(Default)]
+struct TabState {
+    input: Editor,
+    output: String,
+    history_index: Option<usize>,
+    last_response: Option<SqlResponse>,
+    sort: Option<(usize, bool)>,
+    sorted: Option<SqlResponse>,
+    results_focus: bool,
+    selected: (usize, usize),
+}
+
+/// A query running on a background task, so the UI stays responsive and it can be cancelled.
+struct RunningQuery {
+    query: String,
+    /// Tab the query was started from, which receives its results.
+    tab: usize,
+    started: Instant,
+    task: tokio::task::JoinHandle<Result<SqlResponse>>,
+}
+
+/// State of an in-progress Ctrl+S export of the last results.
+struct ExportPrompt {
+    path: Editor,
+    format: ExportFormat,
+}
+
+/// State of an in-progress Ctrl+R reverse history search.
+struct HistorySearch {
+    query: String,
+    match_index: Option<usize>,
+    saved_input: String,
 }
 
 impl App {
-    fn new(json_output: bool) -> Self {
+    fn new(
+        format: OutputFormat,
+        submit_key: SubmitKey,
+        limit: Option<RowLimit>,
+        project: Option<String>,
+        history_file: HistoryFile,
+    ) -> Self {
         Self {
-            input: String::new(),
-            cursor: 0,
+            input: Editor::default(),
             output: String::new(),
-            status: "Enter SQL and press Enter. Ctrl+C to exit.".to_string(),
-            history: Vec::new(),
+            status: match submit_key {
+                SubmitKey::Enter => "Enter to run, Shift+Enter for a new line, Ctrl+R to search history, Ctrl+B for schema, Ctrl+S to export, Ctrl+O to sort, Tab to inspect, Ctrl+T for a new tab, Ctrl+D to close it, Esc to exit.",
+                SubmitKey::AltEnter => "Alt+Enter to run, Enter for a new line, Ctrl+R to search history, Ctrl+B for schema, Ctrl+S to export, Ctrl+O to sort, Tab to inspect, Ctrl+T for a new tab, Ctrl+D to close it, Esc to exit.",
+            }
+            .to_string(),
+            history: history_file.load(),
             history_index: None,
-            json_output,
+            search: None,
+            history_file,
+            format,
+            submit_key,
+            limit,
+            project,
+            schema: None,
+            last_response: None,
+            export: None,
+            running: None,
+            max_col_width: None,
+            engine: EngineOptions::default(),
+            sort: None,
+            sorted: None,
+            results_focus: false,
+            selected: (0, 0),
+            inspector: None,
+            tabs: vec![TabState::default()],
+            active_tab: 0,
         }
     }
 
-    fn insert_char(&mut self, ch: char) {
-        self.input.insert(self.cursor, ch);
-        self.cursor += ch.len_utf8();
-        self.history_index = None;
+    fn park_tab(&mut self) -> TabState {
+        TabState {
+            input: std::mem::take(&mut self.input),
+            output: std::mem::take(&mut self.output),
+            history_index: self.history_index.take(),
+            last_response: self.last_response.take(),
+            sort: self.sort.take(),
+            sorted: self.sorted.take(),
+            results_focus: std::mem::take(&mut self.results_focus),
+            selected: std::mem::take(&mut self.selected),
+        }
     }
 
-    fn backspace(&mut self) {
-        if self.cursor == 0 {
+    fn restore_tab(&mut self, tab: TabState) {
+        self.input = tab.input;
+        self.output = tab.output;
+        self.history_index = tab.history_index;
+        self.last_response = tab.last_response;
+        self.sort = tab.sort;
+        self.sorted = tab.sorted;
+        self.results_focus = tab.results_focus;
+        self.selected = tab.selected;
+    }
+
+    /// Ctrl+T: open an empty tab after the current ones and switch to it.
+    fn new_tab(&mut self) {
+        let parked = self.park_tab();
+        self.tabs[self.active_tab] = parked;
+        self.tabs.push(TabState::default());
+        self.active_tab = self.tabs.len() - 1;
+        self.status = format!("Opened tab {}", self.active_tab + 1);
+    }
+
+    /// Ctrl+Left/Right: switch to the previous or next tab, wrapping around.
+    fn cycle_tab(&mut self, forward: bool) {
+        let count = self.tabs.len();
+        if count < 2 {
             return;
         }
-        let new_cursor = prev_char_boundary(&self.input, self.cursor);
-        self.input.replace_range(new_cursor..self.cursor, "");
-        self.cursor = new_cursor;
-        self.history_index = None;
+        let next = if forward {
+            (self.active_tab + 1) % count
+        } else {
+            (self.active_tab + count - 1) % count
+        };
+        let parked = self.park_tab();
+        self.tabs[self.active_tab] = parked;
+        let tab = std::mem::take(&mut self.tabs[next]);
+        self.restore_tab(tab);
+        self.active_tab = next;
+        self.status = format!("Tab {} of {count}", next + 1);
     }
 
-    fn delete(&mut self) {
-        if self.cursor >= self.input.len() {
+    /// Ctrl+D: close the active tab. Returns true when it was the last one.
+    fn close_tab(&mut self) -> bool {
+        if self.tabs.len() < 2 {
+            return true;
+        }
+        self.tabs.remove(self.active_tab);
+        self.active_tab = self.active_tab.min(self.tabs.len() - 1);
+        let tab = std::mem::take(&mut self.tabs[self.active_tab]);
+        self.restore_tab(tab);
+        self.status = format!("Closed tab; now on tab {}", self.active_tab + 1);
+        false
+    }
+
+    fn tab_titles(&self) -> Vec<String> {
+        (0..self.tabs.len())
+            .map(|idx| {
+                let input = if idx == self.active_tab {
+                    &self.input
+                } else {
+                    &self.tabs[idx].input
+                };
+                let first_line = input.text().lines().next().unwrap_or_default().trim();
+                let title: String = first_line.chars().take(TAB_TITLE_WIDTH).collect();
+                if title.is_empty() {
+                    format!("{} untitled", idx + 1)
+                } else {
+                    format!("{} {title}", idx + 1)
+                }
+            })
+            .collect()
+    }
+
+    /// Alt+C: copy the displayed results to the clipboard as TSV.
+    fn copy_results(&mut self) {
+        let Some(response) = self.displayed() else {
+            self.status = "No results to copy; run a query first.".to_string();
+            return;
+        };
+        let rows = response.data.len();
+        let tsv = export::to_tsv(response);
+        self.status = match copy_to_clipboard(&tsv) {
+            Ok(method) => format!("Copied {rows} row(s) as TSV via {method}"),
+            Err(err) => format!("Copy failed: {err}"),
+        };
+    }
+
+    /// Alt+Q: copy the query being edited, or the last one run, to the clipboard.
+    fn copy_query(&mut self) {
+        let query = match self.input.text().trim() {
+            "" => self.history.last().cloned().unwrap_or_default(),
+            text => text.to_string(),
+        };
+        if query.is_empty() {
+            self.status = "No query to copy".to_string();
             return;
         }
-        let next_cursor = next_char_boundary(&self.input, self.cursor);
-        self.input.replace_range(self.cursor..next_cursor, "");
-        self.history_index = None;
+        self.status = match copy_to_clipboard(&query) {
+            Ok(method) => format!("Copied query via {method}"),
+            Err(err) => format!("Copy failed: {err}"),
+        };
+    }
+
+    /// The results as displayed, with any sort applied.
+    fn displayed(&self) -> Option<&SqlResponse> {
+        self.sorted.as_ref().or(self.last_response.as_ref())
+    }
+
+    /// Tab: move focus to the results to select a cell.
+    fn focus_results(&mut self) {
+        if self
+            .displayed()
+            .is_none_or(|response| response.data.is_empty())
+        {
+            self.status = "No results to inspect; run a query first.".to_string();
+            return;
+        }
+        self.results_focus = true;
+        self.status = "Arrows to select a cell, Enter to inspect, Tab to edit".to_string();
+    }
+
+    fn move_selection(&mut self, rows: isize, columns: isize) {
+        let Some(response) = self.displayed() else {
+            return;
+        };
+        let row_count = response.data.len();
+        let column_count = result_headers(response).len();
+        let (row, column) = self.selected;
+        self.selected = (
+            row.saturating_add_signed(rows)
+                .min(row_count.saturating_sub(1)),
+            column
+                .saturating_add_signed(columns)
+                .min(column_count.saturating_sub(1)),
+        );
+    }
+
+    fn selection_status(&self) -> String {
+        let Some(response) = self.displayed() else {
+            return String::new();
+        };
+        let (row, column) = self.selected;
+        let header = result_headers(response)
+            .get(column)
+            .cloned()
+            .unwrap_or_default();
+        format!("row {}/{}, column '{header}'", row + 1, response.data.len())
+    }
+
+    /// Open the selected cell in the inspector popup.
+    fn inspect_selected(&mut self) {
+        let Some(response) = self.displayed() else {
+            return;
+        };
+        let (row, column) = self.selected;
+        let Some(header) = result_headers(response).get(column).cloned() else {
+            return;
+        };
+        let value = response.data.get(row).and_then(|r| r.get(&header));
+        self.inspector = Some(Inspector::new(format!("{header} (row {})", row + 1), value));
+    }
+
+    /// Ctrl+O: step through each column ascending, then descending, then back to unsorted.
+    fn cycle_sort(&mut self) {
+        let Some(response) = &self.last_response else {
+            self.status = "No results to sort; run a query first.".to_string();
+            return;
+        };
+        let headers = result_headers(response);
+        if headers.is_empty() {
+            return;
+        }
+        self.sort = match self.sort {
+            None => Some((0, false)),
+            Some((idx, false)) => Some((idx, true)),
+            Some((idx, true)) if idx + 1 < headers.len() => Some((idx + 1, false)),
+            Some((_, true)) => None,
+        };
+        self.status = match self.sort {
+            Some((idx, descending)) => format!(
+                "Sorted by {} {} (Ctrl+O for next)",
+                headers[idx],
+                if descending { "desc" } else { "asc" }
+            ),
+            None => "Unsorted".to_string(),
+        };
+        self.render_results();
+    }
+
+    /// Render the last results into the output pane, applying the current sort.
+    fn render_results(&mut self) {
+        let Some(response) = &self.last_response else {
+            return;
+        };
+        self.sorted = self.sort.map(|(idx, descending)| {
+            let mut sorted = response.clone();
+            if let Some(column) = result_headers(response).get(idx) {
+                sort::sort_rows(&mut sorted.data, column, descending);
+            }
+            sorted
+        });
+        let response = self.sorted.as_ref().unwrap_or(response);
+        self.output = match format_response(response, self.format, self.max_col_width) {
+            Ok(output) => output,
+            Err(err) => format!("Error: {err}"),
+        };
+    }
+
+    fn start_query(
+        &mut self,
+        query: String,
+        client: &Arc<ApiClient>,
+        handle: &tokio::runtime::Handle,
+    ) {
+        let client = Arc::clone(client);
+        let limit = self.limit;
+        let engine = self.engine;
+        let task_query = query.clone();
+        let task = handle
+            .spawn(async move { execute_limited_query(&client, &task_query, engine, limit).await });
+        self.running = Some(RunningQuery {
+            query,
+            tab: self.active_tab,
+            started: Instant::now(),
+            task,
+        });
+    }
+
+    /// Update the status of a running query, and show its results once it finishes.
+    fn poll_running(&mut self, handle: &tokio::runtime::Handle) {
+        let Some(running) = &self.running else {
+            return;
+        };
+        if !running.task.is_finished() {
+            self.status = format!(
+                "Running query... {:.1}s (Esc or Ctrl+C to cancel)",
+                running.started.elapsed().as_secs_f64()
+            );
+            return;
+        }
+
+        let Some(running) = self.running.take() else {
+            return;
+        };
+        let RunningQuery {
+            query,
+            tab,
+            started,
+            task,
+        } = running;
+        let result = match handle.block_on(task) {
+            Ok(result) => result,
+            Err(err) => Err(anyhow::anyhow!("query task failed: {err}")),
+        };
+        if tab == self.active_tab || tab >= self.tabs.len() {
+            self.finish_query(&query, started, result);
+            return;
+        }
+        let active = self.park_tab();
+        let origin = std::mem::take(&mut self.tabs[tab]);
+        self.restore_tab(origin);
+        self.finish_query(&query, started, result);
+        self.status = format!("Tab {}: {}", tab + 1, self.status);
+        self.tabs[tab] = self.park_tab();
+        self.restore_tab(active);
     }
 
-    fn move_left(&mut self) {
-        if self.cursor == 0 {
+    /// Show a finished query's results in the current tab and record it in history.
+    fn finish_query(&mut self, query: &str, started: Instant, result: Result<SqlResponse>) {
+        match result {
+            Ok(response) => {
+                let stats = stats::format_stats(&response, Some(started.elapsed()));
+                self.status = match truncation_warning(&response, query, self.limit) {
+                    Some(warning) => format!("{stats} · {warning}"),
+                    None => stats,
+                };
+                self.last_response = Some(response);
+                self.sort = None;
+                self.selected = (0, 0);
+                self.render_results();
+            }
+            Err(err) => {
+                self.output = format!("Error: {err}");
+                self.status = "Error".to_string();
+                self.last_response = None;
+                self.sorted = None;
+            }
+        }
+        self.push_history(query);
+        self.clear_input();
+    }
+
+    fn cancel_running(&mut self) {
+        if let Some(running) = self.running.take() {
+            running.task.abort();
+            self.status = "Query cancelled".to_string();
+        }
+    }
+
+    fn start_export(&mut self) {
+        if self.last_response.is_none() {
+            self.status = "No results to export; run a query first.".to_string();
             return;
         }
-        self.cursor = prev_char_boundary(&self.input, self.cursor);
+        let format = ExportFormat::Csv;
+        let mut path = Editor::default();
+        path.set_text(format!("results.{}", format.extension()));
+        self.export = Some(ExportPrompt { path, format });
+        self.status = "Enter to save, Tab to change format, Esc to cancel.".to_string();
     }
 
-    fn move_right(&mut self) {
-        if self.cursor >= self.input.len() {
+    fn finish_export(&mut self) {
+        let (Some(export), Some(response)) = (self.export.take(), &self.last_response) else {
+            return;
+        };
+        let path = PathBuf::from(export.path.text().trim());
+        if path.as_os_str().is_empty() {
+            self.status = "Export cancelled: no path given".to_string();
             return;
         }
-        self.cursor = next_char_boundary(&self.input, self.cursor);
+        self.status = match export::write_export(&path, export.format, response) {
+            Ok(()) => format!(
+                "Exported {} row(s) to {}",
+                response.data.len(),
+                path.display()
+            ),
+            Err(err) => format!("Export failed: {err:#}"),
+        };
     }
 
-    fn move_home(&mut self) {
-        self.cursor = 0;
+    /// Apply an edit to the input, leaving history navigation.
+    fn edit(&mut self, f: impl FnOnce(&mut Editor)) {
+        f(&mut self.input);
+        self.history_index = None;
     }
 
-    fn move_end(&mut self) {
-        self.cursor = self.input.len();
+    /// Move up a line, or to the previous history entry from the first line.
+    fn move_up(&mut self) {
+        if !self.input.move_up() {
+            self.history_prev();
+        }
+    }
+
+    /// Move down a line, or to the next history entry from the last line.
+    fn move_down(&mut self) {
+        if !self.input.move_down() {
+            self.history_next();
+        }
+    }
+
+    fn is_submit(&self, key: KeyEvent) -> bool {
+        let modified = key
+            .modifiers
+            .intersects(KeyModifiers::ALT | KeyModifiers::SHIFT);
+        match self.submit_key {
+            SubmitKey::Enter => !modified,
+            SubmitKey::AltEnter => key.modifiers.contains(KeyModifiers::ALT),
+        }
     }
 
     fn clear_input(&mut self) {
         self.input.clear();
-        self.cursor = 0;
         self.history_index = None;
     }
 
@@ -440,6 +2103,9 @@ impl App {
         }
         if self.history.last().map(String::as_str) != Some(query) {
             self.history.push(query.to_string());
+            if let Err(err) = self.history_file.save(&self.history) {
+                self.status = format!("Failed to save history: {err}");
+            }
         }
         self.history_index = None;
     }
@@ -454,8 +2120,7 @@ impl App {
             Some(idx) => idx - 1,
         };
         self.history_index = Some(next_index);
-        self.input = self.history[next_index].clone();
-        self.cursor = self.input.len();
+        self.input.set_text(self.history[next_index].clone());
     }
 
     fn history_next(&mut self) {
@@ -469,42 +2134,107 @@ impl App {
             return;
         }
         self.history_index = Some(next_index);
-        self.input = self.history[next_index].clone();
-        self.cursor = self.input.len();
+        self.input.set_text(self.history[next_index].clone());
     }
 
-    fn input_view(&self, area: Rect) -> (String, u16) {
-        let available_width = area.width.saturating_sub(2) as usize;
-        if available_width == 0 {
-            return (String::new(), 0);
+    fn start_search(&mut self) {
+        self.search = Some(HistorySearch {
+            query: String::new(),
+            match_index: None,
+            saved_input: self.input.text().to_string(),
+        });
+    }
+
+    /// Find the newest history entry at or before `from` containing the search query.
+    fn find_history_match(&self, query: &str, from: usize) -> Option<usize> {
+        if self.history.is_empty() {
+            return None;
         }
+        let from = from.min(self.history.len() - 1);
+        (0..=from)
+            .rev()
+            .find(|&idx| self.history[idx].contains(query))
+    }
+
+    fn search_insert_char(&mut self, ch: char) {
+        let Some(mut search) = self.search.take() else {
+            return;
+        };
+        search.query.push(ch);
+        let from = search
+            .match_index
+            .unwrap_or_else(|| self.history.len().saturating_sub(1));
+        search.match_index = self.find_history_match(&search.query, from);
+        self.search = Some(search);
+    }
 
-        let mut start = self.cursor.saturating_sub(available_width);
+    fn search_backspace(&mut self) {
+        let Some(mut search) = self.search.take() else {
+            return;
+        };
+        search.query.pop();
+        search.match_index = if search.query.is_empty() {
+            None
+        } else {
+            self.find_history_match(&search.query, self.history.len().saturating_sub(1))
+        };
+        self.search = Some(search);
+    }
 
-        while start > 0 && !self.input.is_char_boundary(start) {
-            start -= 1;
+    fn search_older(&mut self) {
+        let Some(mut search) = self.search.take() else {
+            return;
+        };
+        if !search.query.is_empty() {
+            let next = match search.match_index {
+                Some(0) => None,
+                Some(idx) => self.find_history_match(&search.query, idx - 1),
+                None => self.find_history_match(&search.query, self.history.len()),
+            };
+            if next.is_some() {
+                search.match_index = next;
+            }
         }
+        self.search = Some(search);
+    }
 
-        let mut end = (start + available_width).min(self.input.len());
-        while end < self.input.len() && !self.input.is_char_boundary(end) {
-            end += 1;
+    fn accept_search(&mut self) {
+        let Some(search) = self.search.take() else {
+            return;
+        };
+        match search.match_index {
+            Some(idx) => {
+                self.input.set_text(self.history[idx].clone());
+                self.history_index = Some(idx);
+            }
+            None => self.input.set_text(search.saved_input),
         }
+    }
 
-        let visible = self.input[start..end].to_string();
-        let cursor_col = self.cursor.saturating_sub(start) as u16;
-        (visible, cursor_col)
+    fn cancel_search(&mut self) {
+        if let Some(search) = self.search.take() {
+            self.input.set_text(search.saved_input);
+        }
     }
 }
 
-fn prev_char_boundary(s: &str, idx: usize) -> usize {
-    s[..idx].char_indices().last().map(|(i, _)| i).unwrap_or(0)
-}
+/// Byte range of `text` that fits in `area` while keeping `cursor` visible.
+fn visible_range(text: &str, cursor: usize, area: Rect) -> (usize, usize) {
+    let available_width = area.width.saturating_sub(2) as usize;
+    if available_width == 0 {
+        return (cursor, cursor);
+    }
+
+    let mut start = cursor.saturating_sub(available_width);
 
-fn next_char_boundary(s: &str, idx: usize) -> usize {
-    if idx >= s.len() {
-        return s.len();
+    while start > 0 && !text.is_char_boundary(start) {
+        start -= 1;
     }
-    let mut iter = s[idx..].char_indices();
-    iter.next();
-    iter.next().map(|(i, _)| idx + i).unwrap_or_else(|| s.len())
+
+    let mut end = (start + available_width).min(text.len());
+    while end < text.len() && !text.is_char_boundary(end) {
+        end += 1;
+    }
+
+    (start, end)
 }
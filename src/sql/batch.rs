@@ -0,0 +1,117 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use super::cache::QueryCache;
+use super::{run_query, statements, template, OutputFormat, SqlArgs};
+use crate::http::ApiClient;
+use crate::ui::{print_command_status, CommandStatus};
+
+const QUERY_EXTENSIONS: [&str; 2] = ["sql", "btql"];
+
+/// Run every `.sql`/`.btql` file in `dir`, writing one result file per query to `out_dir`.
+pub async fn run_batch(
+    client: &ApiClient,
+    cache: &QueryCache,
+    dir: &Path,
+    out_dir: &Path,
+    args: &SqlArgs,
+    format: OutputFormat,
+) -> Result<()> {
+    let files = query_files(dir)?;
+    if files.is_empty() {
+        bail!("no .sql or .btql files found in {}", dir.display());
+    }
+    std::fs::create_dir_all(out_dir)
+        .with_context(|| format!("failed to create {}", out_dir.display()))?;
+
+    let mut failures = 0;
+    let mut total = 0;
+    for file in &files {
+        let stem = file
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let statements = match read_statements(file, args) {
+            Ok(statements) => statements,
+            Err(err) => {
+                total += 1;
+                failures += 1;
+                print_command_status(
+                    CommandStatus::Error,
+                    &format!("{}: {err:#}", file.display()),
+                );
+                continue;
+            }
+        };
+
+        for (idx, query) in statements.iter().enumerate() {
+            total += 1;
+            let name = if statements.len() == 1 {
+                stem.clone()
+            } else {
+                format!("{stem}.{}", idx + 1)
+            };
+            let output = out_dir.join(format!("{name}.{}", extension(format)));
+            let mut query_args = args.clone();
+            query_args.output = Some(output.clone());
+            match run_query(client, cache, query, &query_args, format).await {
+                Ok(()) => print_command_status(
+                    CommandStatus::Success,
+                    &format!("{} -> {}", file.display(), output.display()),
+                ),
+                Err(err) => {
+                    failures += 1;
+                    print_command_status(
+                        CommandStatus::Error,
+                        &format!("{}: {err:#}", file.display()),
+                    );
+                }
+            }
+        }
+    }
+
+    println!("{} succeeded, {failures} failed", total - failures);
+    if failures > 0 {
+        bail!("{failures} of {total} queries failed");
+    }
+    Ok(())
+}
+
+fn query_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries =
+        std::fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?;
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let is_query = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| QUERY_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()));
+        if is_query && path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn read_statements(file: &Path, args: &SqlArgs) -> Result<Vec<String>> {
+    let query = std::fs::read_to_string(file)
+        .with_context(|| format!("failed to read {}", file.display()))?;
+    let query = template::fill_placeholders(&query, &args.params)?;
+    let statements = statements::split_statements(&query);
+    if statements.is_empty() {
+        bail!("query file is empty");
+    }
+    Ok(statements)
+}
+
+fn extension(format: OutputFormat) -> &'static str {
+    match format {
+        OutputFormat::Table | OutputFormat::Vertical => "txt",
+        OutputFormat::Json => "json",
+        OutputFormat::Jsonl => "jsonl",
+        OutputFormat::Html => "html",
+    }
+}
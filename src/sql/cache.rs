@@ -0,0 +1,110 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{EngineOptions, SqlResponse};
+use crate::config::state_dir;
+
+/// Identifies a cached result: the same query against the same org and project,
+/// fetched with the same paging and engine options.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CacheKey {
+    pub org: String,
+    pub project: Option<String>,
+    pub query: String,
+    pub engine: EngineOptions,
+    pub follow: bool,
+    pub max_pages: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct CacheEntry {
+    key: CacheKey,
+    stored_at: u64,
+    response: SqlResponse,
+}
+
+/// Query results cached under `<state dir>/sql_cache`, one JSON file per key.
+pub struct QueryCache {
+    dir: Option<PathBuf>,
+    ttl: Duration,
+    org: String,
+    project: Option<String>,
+}
+
+impl QueryCache {
+    pub fn new(ttl: Duration, enabled: bool, org: &str, project: Option<&str>) -> Self {
+        let dir = if enabled && !ttl.is_zero() {
+            state_dir().map(|dir| dir.join("sql_cache"))
+        } else {
+            None
+        };
+        Self {
+            dir,
+            ttl,
+            org: org.to_string(),
+            project: project.map(str::to_string),
+        }
+    }
+
+    pub fn key(
+        &self,
+        query: &str,
+        engine: EngineOptions,
+        follow: bool,
+        max_pages: Option<usize>,
+    ) -> CacheKey {
+        CacheKey {
+            org: self.org.clone(),
+            project: self.project.clone(),
+            query: query.to_string(),
+            engine,
+            follow,
+            max_pages,
+        }
+    }
+
+    fn path(&self, key: &CacheKey) -> Option<PathBuf> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let dir = self.dir.as_ref()?;
+        Some(dir.join(format!("{:016x}.json", hasher.finish())))
+    }
+
+    /// A fresh cached response for `key`, with its age.
+    pub fn get(&self, key: &CacheKey) -> Option<(SqlResponse, Duration)> {
+        let path = self.path(key)?;
+        let contents = std::fs::read_to_string(&path).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&contents).ok()?;
+        let age = Duration::from_secs(now_secs().saturating_sub(entry.stored_at));
+        if entry.key != *key || age > self.ttl {
+            let _ = std::fs::remove_file(&path);
+            return None;
+        }
+        Some((entry.response, age))
+    }
+
+    pub fn put(&self, key: &CacheKey, response: &SqlResponse) -> Result<()> {
+        let Some(path) = self.path(key) else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        let entry = json!({ "key": key, "stored_at": now_secs(), "response": response });
+        std::fs::write(&path, entry.to_string())
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
@@ -0,0 +1,314 @@
+use unicode_width::UnicodeWidthStr;
+
+/// Multi-line text buffer for the SQL input pane. `cursor` is a byte offset into `text`.
+#[derive(Debug, Default, Clone)]
+pub struct Editor {
+    text: String,
+    cursor: usize,
+}
+
+impl Editor {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, text: String) {
+        self.text = text;
+        self.cursor = self.text.len();
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.text.split('\n').count()
+    }
+
+    pub fn insert_char(&mut self, ch: char) {
+        self.text.insert(self.cursor, ch);
+        self.cursor += ch.len_utf8();
+    }
+
+    pub fn insert_str(&mut self, text: &str) {
+        self.text.insert_str(self.cursor, text);
+        self.cursor += text.len();
+    }
+
+    pub fn insert_newline(&mut self) {
+        self.insert_char('\n');
+    }
+
+    pub fn backspace(&mut self) {
+        if self.cursor == 0 {
+            return;
+        }
+        let new_cursor = prev_char_boundary(&self.text, self.cursor);
+        self.text.replace_range(new_cursor..self.cursor, "");
+        self.cursor = new_cursor;
+    }
+
+    pub fn delete(&mut self) {
+        if self.cursor >= self.text.len() {
+            return;
+        }
+        let next_cursor = next_char_boundary(&self.text, self.cursor);
+        self.text.replace_range(self.cursor..next_cursor, "");
+    }
+
+    pub fn move_left(&mut self) {
+        if self.cursor == 0 {
+            return;
+        }
+        self.cursor = prev_char_boundary(&self.text, self.cursor);
+    }
+
+    pub fn move_right(&mut self) {
+        if self.cursor >= self.text.len() {
+            return;
+        }
+        self.cursor = next_char_boundary(&self.text, self.cursor);
+    }
+
+    /// Move to the start of the current line.
+    pub fn move_home(&mut self) {
+        self.cursor = self.line_start(self.cursor);
+    }
+
+    /// Move to the end of the current line.
+    pub fn move_end(&mut self) {
+        self.cursor = self.line_end(self.cursor);
+    }
+
+    /// Move up one line, keeping the column where possible. Returns false on the first line.
+    pub fn move_up(&mut self) -> bool {
+        let start = self.line_start(self.cursor);
+        if start == 0 {
+            return false;
+        }
+        let column = self.text[start..self.cursor].chars().count();
+        let prev_start = self.line_start(start - 1);
+        self.cursor = advance_chars(&self.text, prev_start, start - 1, column);
+        true
+    }
+
+    /// Move down one line, keeping the column where possible. Returns false on the last line.
+    pub fn move_down(&mut self) -> bool {
+        let end = self.line_end(self.cursor);
+        if end >= self.text.len() {
+            return false;
+        }
+        let column = self.text[self.line_start(self.cursor)..self.cursor]
+            .chars()
+            .count();
+        let next_start = end + 1;
+        let next_end = self.line_end(next_start);
+        self.cursor = advance_chars(&self.text, next_start, next_end, column);
+        true
+    }
+
+    /// Move to the start of the previous word (Alt+B).
+    pub fn move_word_left(&mut self) {
+        self.cursor = self.prev_word_start();
+    }
+
+    /// Move past the end of the next word (Alt+F).
+    pub fn move_word_right(&mut self) {
+        let rest = &self.text[self.cursor..];
+        let word_start = rest.find(|c: char| is_word_char(c)).unwrap_or(rest.len());
+        let word_len = rest[word_start..]
+            .find(|c: char| !is_word_char(c))
+            .unwrap_or(rest.len() - word_start);
+        self.cursor += word_start + word_len;
+    }
+
+    /// Delete the word before the cursor (Ctrl+W).
+    pub fn delete_word_back(&mut self) {
+        let start = self.prev_word_start();
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    /// Delete from the start of the line to the cursor (Ctrl+U).
+    pub fn kill_to_line_start(&mut self) {
+        let start = self.line_start(self.cursor);
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    /// Delete from the cursor to the end of the line, or the line break when
+    /// already at the end (Ctrl+K).
+    pub fn kill_to_line_end(&mut self) {
+        let end = self.line_end(self.cursor);
+        if end == self.cursor {
+            self.delete();
+        } else {
+            self.text.replace_range(self.cursor..end, "");
+        }
+    }
+
+    fn prev_word_start(&self) -> usize {
+        let before = &self.text[..self.cursor];
+        let word_end = before
+            .rfind(|c: char| is_word_char(c))
+            .map(|idx| next_char_boundary(before, idx))
+            .unwrap_or(0);
+        before[..word_end]
+            .rfind(|c: char| !is_word_char(c))
+            .map(|idx| next_char_boundary(before, idx))
+            .unwrap_or(0)
+    }
+
+    fn line_start(&self, pos: usize) -> usize {
+        self.text[..pos].rfind('\n').map(|idx| idx + 1).unwrap_or(0)
+    }
+
+    fn line_end(&self, pos: usize) -> usize {
+        self.text[pos..]
+            .find('\n')
+            .map(|idx| pos + idx)
+            .unwrap_or(self.text.len())
+    }
+
+    /// Zero-based (row, display column) of the cursor.
+    pub fn cursor_position(&self) -> (usize, usize) {
+        let before = &self.text[..self.cursor];
+        let row = before.matches('\n').count();
+        let line_start = self.line_start(self.cursor);
+        (row, self.text[line_start..self.cursor].width())
+    }
+
+    /// Visible slice of the buffer for a `width` x `height` viewport that keeps the
+    /// cursor in view. Returns the visible lines and the cursor position within them.
+    pub fn view(&self, width: usize, height: usize) -> (Vec<String>, (u16, u16)) {
+        if width == 0 || height == 0 {
+            return (Vec::new(), (0, 0));
+        }
+
+        let (row, col) = self.cursor_position();
+        let first_row = (row + 1).saturating_sub(height);
+        let first_col = (col + 1).saturating_sub(width);
+
+        let lines = self
+            .text
+            .split('\n')
+            .skip(first_row)
+            .take(height)
+            .map(|line| slice_columns(line, first_col, width))
+            .collect();
+        (lines, ((col - first_col) as u16, (row - first_row) as u16))
+    }
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+/// Take the part of `line` between display columns `start` and `start + width`.
+fn slice_columns(line: &str, start: usize, width: usize) -> String {
+    let mut out = String::new();
+    let mut column = 0;
+    for ch in line.chars() {
+        let ch_width = UnicodeWidthStr::width(ch.encode_utf8(&mut [0; 4]) as &str);
+        if column >= start && column + ch_width <= start + width {
+            out.push(ch);
+        }
+        column += ch_width;
+        if column >= start + width {
+            break;
+        }
+    }
+    out
+}
+
+/// Byte offset `count` chars after `start`, clamped to `limit`.
+fn advance_chars(text: &str, start: usize, limit: usize, count: usize) -> usize {
+    text[start..limit]
+        .char_indices()
+        .nth(count)
+        .map(|(idx, _)| start + idx)
+        .unwrap_or(limit)
+}
+
+fn prev_char_boundary(s: &str, idx: usize) -> usize {
+    s[..idx].char_indices().last().map(|(i, _)| i).unwrap_or(0)
+}
+
+fn next_char_boundary(s: &str, idx: usize) -> usize {
+    if idx >= s.len() {
+        return s.len();
+    }
+    let mut iter = s[idx..].char_indices();
+    iter.next();
+    iter.next().map(|(i, _)| idx + i).unwrap_or_else(|| s.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor(text: &str, cursor: usize) -> Editor {
+        Editor {
+            text: text.to_string(),
+            cursor,
+        }
+    }
+
+    #[test]
+    fn vertical_movement_keeps_column() {
+        let mut ed = editor("select *\nfrom t\nwhere x = 1", 5);
+        assert!(!ed.move_up());
+        assert!(ed.move_down());
+        assert_eq!(ed.cursor_position(), (1, 5));
+        assert!(ed.move_down());
+        assert_eq!(ed.cursor_position(), (2, 5));
+        assert!(!ed.move_down());
+        assert!(ed.move_up());
+        assert!(ed.move_up());
+        assert_eq!(ed.cursor, 5);
+    }
+
+    #[test]
+    fn vertical_movement_clamps_to_short_lines() {
+        let mut ed = editor("select *\nx\nfrom t", 8);
+        assert!(ed.move_down());
+        assert_eq!(ed.cursor_position(), (1, 1));
+    }
+
+    #[test]
+    fn word_movement_and_deletion() {
+        let mut ed = editor("select foo_bar, baz", 19);
+        ed.move_word_left();
+        assert_eq!(ed.cursor, 16);
+        ed.move_word_left();
+        assert_eq!(ed.cursor, 7);
+        ed.move_word_right();
+        assert_eq!(ed.cursor, 14);
+
+        ed.delete_word_back();
+        assert_eq!(ed.text, "select , baz");
+        assert_eq!(ed.cursor, 7);
+    }
+
+    #[test]
+    fn kill_line_segments() {
+        let mut ed = editor("select *\nfrom t", 13);
+        ed.kill_to_line_start();
+        assert_eq!(ed.text, "select *\n t");
+        ed.kill_to_line_end();
+        assert_eq!(ed.text, "select *\n");
+        ed.move_up();
+        ed.move_end();
+        ed.kill_to_line_end();
+        assert_eq!(ed.text, "select *");
+    }
+
+    #[test]
+    fn view_scrolls_to_cursor() {
+        let ed = editor("a\nb\nc\nd", 6);
+        let (lines, cursor) = ed.view(10, 2);
+        assert_eq!(lines, vec!["c".to_string(), "d".to_string()]);
+        assert_eq!(cursor, (0, 1));
+    }
+}
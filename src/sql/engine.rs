@@ -0,0 +1,63 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// BTQL engine options forwarded in the body of every `/btql` request.
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq, Serialize, Deserialize, Args)]
+pub struct EngineOptions {
+    /// Include rows that haven't been indexed yet (slower, but fully up to date)
+    #[arg(long, global = true)]
+    pub realtime: bool,
+
+    /// Answer the query from the columnstore
+    #[arg(long, global = true)]
+    pub use_columnstore: bool,
+
+    /// Query the audit log of the matched rows instead of the rows themselves
+    #[arg(long, global = true)]
+    pub audit_log: bool,
+}
+
+impl EngineOptions {
+    /// Set the enabled options on a `/btql` request body.
+    pub fn apply(self, body: &mut Value) {
+        let options = [
+            ("brainstore_realtime", self.realtime),
+            ("use_columnstore", self.use_columnstore),
+            ("audit_log", self.audit_log),
+        ];
+        for (key, enabled) in options {
+            if enabled {
+                body[key] = json!(true);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_only_sets_enabled_options() {
+        let mut body = json!({ "query": "SELECT 1", "fmt": "json" });
+        EngineOptions::default().apply(&mut body);
+        assert_eq!(body, json!({ "query": "SELECT 1", "fmt": "json" }));
+
+        let options = EngineOptions {
+            realtime: true,
+            audit_log: true,
+            ..Default::default()
+        };
+        options.apply(&mut body);
+        assert_eq!(
+            body,
+            json!({
+                "query": "SELECT 1",
+                "fmt": "json",
+                "brainstore_realtime": true,
+                "audit_log": true,
+            })
+        );
+    }
+}
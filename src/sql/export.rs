@@ -0,0 +1,133 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+use super::{format_cell, format_response, result_headers, OutputFormat, SqlResponse};
+
+/// File formats offered when exporting results from the interactive session.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Jsonl,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Jsonl => "jsonl",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            ExportFormat::Csv => ExportFormat::Json,
+            ExportFormat::Json => ExportFormat::Jsonl,
+            ExportFormat::Jsonl => ExportFormat::Csv,
+        }
+    }
+
+    /// Replace the extension of `path` with this format's extension.
+    pub fn apply_extension(self, path: &str) -> String {
+        PathBuf::from(path)
+            .with_extension(self.extension())
+            .display()
+            .to_string()
+    }
+}
+
+/// Write `response` to `path` in the given format.
+pub fn write_export(path: &Path, format: ExportFormat, response: &SqlResponse) -> Result<()> {
+    let contents = match format {
+        ExportFormat::Csv => to_csv(response),
+        ExportFormat::Json => format_response(response, OutputFormat::Json, None)?,
+        ExportFormat::Jsonl => format_response(response, OutputFormat::Jsonl, None)?,
+    };
+    std::fs::write(path, format!("{contents}\n"))
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+pub fn to_csv(response: &SqlResponse) -> String {
+    delimited(response, ",", csv_escape)
+}
+
+/// Tab-separated values, with tabs and line breaks inside cells replaced by spaces.
+pub fn to_tsv(response: &SqlResponse) -> String {
+    delimited(response, "\t", |cell| cell.replace(['\t', '\n', '\r'], " "))
+}
+
+fn delimited(response: &SqlResponse, separator: &str, escape: impl Fn(&str) -> String) -> String {
+    let headers = result_headers(response);
+    let line = |cells: &[String]| {
+        cells
+            .iter()
+            .map(|cell| escape(cell))
+            .collect::<Vec<_>>()
+            .join(separator)
+    };
+    let mut lines = vec![line(&headers)];
+    for row in &response.data {
+        let cells: Vec<String> = headers
+            .iter()
+            .map(|h| match row.get(h) {
+                Some(Value::Null) => String::new(),
+                value => format_cell(value),
+            })
+            .collect();
+        lines.push(line(&cells));
+    }
+    lines.join("\n")
+}
+
+pub(crate) fn csv_escape(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_quotes_special_cells() {
+        let response: SqlResponse = serde_json::from_value(serde_json::json!({
+            "data": [
+                { "id": 1, "name": "a, b", "meta": { "k": "v" }, "note": null },
+                { "id": 2, "name": "say \"hi\"" }
+            ],
+            "schema": {}
+        }))
+        .expect("valid response");
+        let csv = to_csv(&response);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "id,meta,name,note");
+        assert_eq!(lines[1], "1,\"{\"\"k\"\":\"\"v\"\"}\",\"a, b\",");
+        assert_eq!(lines[2], "2,,\"say \"\"hi\"\"\",");
+    }
+
+    #[test]
+    fn tsv_flattens_tabs_and_newlines() {
+        let response: SqlResponse = serde_json::from_value(serde_json::json!({
+            "data": [{ "a": "x\ty", "b": "line\nbreak" }],
+            "schema": {}
+        }))
+        .expect("valid response");
+        assert_eq!(to_tsv(&response), "a\tb\nx y\tline break");
+    }
+
+    #[test]
+    fn cycling_format_rewrites_extension() {
+        let format = ExportFormat::Csv.next();
+        assert_eq!(format, ExportFormat::Json);
+        assert_eq!(
+            format.apply_extension("out/results.csv"),
+            "out/results.json"
+        );
+    }
+}
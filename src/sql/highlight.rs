@@ -0,0 +1,160 @@
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+const KEYWORDS: &[&str] = &[
+    "all",
+    "and",
+    "as",
+    "asc",
+    "between",
+    "by",
+    "case",
+    "cursor",
+    "desc",
+    "dimensions",
+    "distinct",
+    "else",
+    "end",
+    "false",
+    "filter",
+    "from",
+    "group",
+    "having",
+    "ilike",
+    "in",
+    "interval",
+    "is",
+    "join",
+    "like",
+    "limit",
+    "match",
+    "measures",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "or",
+    "order",
+    "pivot",
+    "select",
+    "sort",
+    "then",
+    "true",
+    "unpivot",
+    "when",
+    "where",
+    "with",
+];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum TokenKind {
+    Keyword,
+    Function,
+    String,
+    Number,
+    Comment,
+    Plain,
+}
+
+impl TokenKind {
+    fn style(self) -> Style {
+        match self {
+            TokenKind::Keyword => Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+            TokenKind::Function => Style::default().fg(Color::Cyan),
+            TokenKind::String => Style::default().fg(Color::Green),
+            TokenKind::Number => Style::default().fg(Color::Yellow),
+            TokenKind::Comment => Style::default().fg(Color::DarkGray),
+            TokenKind::Plain => Style::default(),
+        }
+    }
+}
+
+/// Highlight one line of SQL/BTQL. Each line is tokenized on its own, so a
+/// string spanning lines is only colored up to its first line break.
+pub fn highlight_line(line: &str) -> Line<'static> {
+    let spans = tokenize(line)
+        .into_iter()
+        .map(|(kind, text)| Span::styled(text.to_string(), kind.style()))
+        .collect::<Vec<_>>();
+    Line::from(spans)
+}
+
+fn tokenize(line: &str) -> Vec<(TokenKind, &str)> {
+    let mut tokens = Vec::new();
+    let mut rest = line;
+
+    while let Some(ch) = rest.chars().next() {
+        let (kind, len) = if rest.starts_with("--") {
+            (TokenKind::Comment, rest.len())
+        } else if ch == '\'' || ch == '"' {
+            (TokenKind::String, quoted_len(rest, ch))
+        } else if ch.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '_'))
+                .unwrap_or(rest.len());
+            (TokenKind::Number, len)
+        } else if ch.is_alphabetic() || ch == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..len];
+            let kind = if KEYWORDS.contains(&word.to_ascii_lowercase().as_str()) {
+                TokenKind::Keyword
+            } else if rest[len..].trim_start().starts_with('(') {
+                TokenKind::Function
+            } else {
+                TokenKind::Plain
+            };
+            (kind, len)
+        } else {
+            (TokenKind::Plain, ch.len_utf8())
+        };
+
+        let (text, remaining) = rest.split_at(len);
+        tokens.push((kind, text));
+        rest = remaining;
+    }
+
+    tokens
+}
+
+/// Length of a quoted literal starting at `text`, including an unterminated tail.
+fn quoted_len(text: &str, quote: char) -> usize {
+    let mut chars = text.char_indices().skip(1);
+    while let Some((idx, ch)) = chars.next() {
+        if ch == '\\' {
+            chars.next();
+        } else if ch == quote {
+            return idx + ch.len_utf8();
+        }
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_classifies_tokens() {
+        let tokens = tokenize("SELECT count(*) FROM logs WHERE name = 'a b' LIMIT 10 -- hi");
+        let kinds: Vec<(TokenKind, &str)> = tokens
+            .into_iter()
+            .filter(|(_, text)| !text.trim().is_empty())
+            .collect();
+        assert_eq!(kinds[0], (TokenKind::Keyword, "SELECT"));
+        assert_eq!(kinds[1], (TokenKind::Function, "count"));
+        assert!(kinds.contains(&(TokenKind::String, "'a b'")));
+        assert!(kinds.contains(&(TokenKind::Number, "10")));
+        assert_eq!(kinds.last(), Some(&(TokenKind::Comment, "-- hi")));
+    }
+
+    #[test]
+    fn tokens_cover_the_whole_line() {
+        let line = "select: * | from: project_logs('x') | filter: a > 1.5";
+        let joined: String = tokenize(line).into_iter().map(|(_, text)| text).collect();
+        assert_eq!(joined, line);
+    }
+}
@@ -0,0 +1,97 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::config::state_dir;
+
+/// Query history persisted to `<state dir>/sql_history`, one entry per line
+/// with newlines and backslashes escaped.
+pub struct HistoryFile {
+    path: Option<PathBuf>,
+    max_entries: usize,
+}
+
+impl HistoryFile {
+    pub fn new(max_entries: usize, enabled: bool) -> Self {
+        let path = if enabled && max_entries > 0 {
+            state_dir().map(|dir| dir.join("sql_history"))
+        } else {
+            None
+        };
+        Self { path, max_entries }
+    }
+
+    pub fn load(&self) -> Vec<String> {
+        let Some(path) = &self.path else {
+            return Vec::new();
+        };
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return Vec::new();
+        };
+        let entries: Vec<String> = contents
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(unescape_entry)
+            .collect();
+        let skip = entries.len().saturating_sub(self.max_entries);
+        entries.into_iter().skip(skip).collect()
+    }
+
+    pub fn save(&self, entries: &[String]) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        let skip = entries.len().saturating_sub(self.max_entries);
+        let mut contents = String::new();
+        for entry in &entries[skip..] {
+            contents.push_str(&escape_entry(entry));
+            contents.push('\n');
+        }
+        std::fs::write(path, contents)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+fn escape_entry(entry: &str) -> String {
+    entry.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape_entry(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escaped_entries_round_trip() {
+        for entry in [
+            "select 1",
+            "select *\nfrom logs",
+            "where x = '\\n'",
+            "trailing\\",
+        ] {
+            let escaped = escape_entry(entry);
+            assert!(!escaped.contains('\n'));
+            assert_eq!(unescape_entry(&escaped), entry);
+        }
+    }
+}
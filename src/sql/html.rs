@@ -0,0 +1,100 @@
+use serde_json::Value;
+
+use super::{format_cell, result_headers, SqlResponse};
+
+const STYLE: &str = "body{font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',sans-serif;margin:2rem;color:#1f2328}\
+table{border-collapse:collapse;font-size:13px}\
+th,td{border:1px solid #d0d7de;padding:4px 8px;text-align:left;vertical-align:top}\
+th{background:#f6f8fa;cursor:pointer;user-select:none;white-space:nowrap}\
+th.asc::after{content:' \\25B2'}th.desc::after{content:' \\25BC'}\
+tr:nth-child(even) td{background:#fbfbfc}\
+td{max-width:40rem;white-space:pre-wrap;word-break:break-word}\
+td.num{text-align:right;font-variant-numeric:tabular-nums}\
+p.meta{color:#656d76;font-size:12px}";
+
+const SCRIPT: &str = "document.querySelectorAll('th').forEach(function(th,i){\
+th.addEventListener('click',function(){\
+var body=th.closest('table').tBodies[0];var rows=Array.from(body.rows);\
+var desc=th.classList.contains('asc');\
+th.parentNode.querySelectorAll('th').forEach(function(h){h.classList.remove('asc','desc')});\
+th.classList.add(desc?'desc':'asc');\
+rows.sort(function(a,b){var x=a.cells[i].dataset.v,y=b.cells[i].dataset.v;\
+var nx=parseFloat(x),ny=parseFloat(y);\
+var c=(!isNaN(nx)&&!isNaN(ny))?nx-ny:x.localeCompare(y);return desc?-c:c});\
+rows.forEach(function(r){body.appendChild(r)});});});";
+
+/// Render results as a standalone HTML page with a click-to-sort table.
+pub fn render_html(response: &SqlResponse) -> String {
+    let headers = result_headers(response);
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str("<title>bt sql results</title>\n");
+    out.push_str(&format!("<style>{STYLE}</style>\n</head>\n<body>\n"));
+    out.push_str(&format!(
+        "<p class=\"meta\">{} row{}</p>\n<table>\n<thead><tr>",
+        response.data.len(),
+        if response.data.len() == 1 { "" } else { "s" }
+    ));
+    for header in &headers {
+        out.push_str(&format!("<th>{}</th>", escape(header)));
+    }
+    out.push_str("</tr></thead>\n<tbody>\n");
+    for row in &response.data {
+        out.push_str("<tr>");
+        for header in &headers {
+            let value = row.get(header);
+            let text = match value {
+                Some(Value::Null) => String::new(),
+                Some(v @ (Value::Array(_) | Value::Object(_))) => {
+                    serde_json::to_string_pretty(v).unwrap_or_default()
+                }
+                other => format_cell(other),
+            };
+            let class = if matches!(value, Some(Value::Number(_))) {
+                " class=\"num\""
+            } else {
+                ""
+            };
+            let text = escape(&text);
+            out.push_str(&format!("<td{class} data-v=\"{text}\">{text}</td>"));
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</tbody>\n</table>\n");
+    out.push_str(&format!("<script>{SCRIPT}</script>\n</body>\n</html>"));
+    out
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_cells_and_headers() {
+        let response: SqlResponse = serde_json::from_value(serde_json::json!({
+            "data": [{ "name": "<b>\"x\" & y</b>", "score": 1.5 }],
+            "schema": {}
+        }))
+        .expect("valid response");
+        let html = render_html(&response);
+        assert!(html.contains("<th>name</th><th>score</th>"));
+        assert!(html.contains("&lt;b&gt;&quot;x&quot; &amp; y&lt;/b&gt;"));
+        assert!(html.contains("<td class=\"num\" data-v=\"1.5\">1.5</td>"));
+        assert!(!html.contains("<b>"));
+    }
+}
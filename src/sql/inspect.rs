@@ -0,0 +1,152 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::Frame;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use serde_json::Value;
+
+/// Scrollable popup showing one result cell as pretty-printed JSON.
+pub struct Inspector {
+    title: String,
+    lines: Vec<Line<'static>>,
+    scroll: usize,
+}
+
+impl Inspector {
+    pub fn new(title: String, value: Option<&Value>) -> Self {
+        let text = match value {
+            Some(Value::String(s)) => serde_json::from_str::<Value>(s)
+                .ok()
+                .filter(|v| v.is_object() || v.is_array())
+                .and_then(|v| serde_json::to_string_pretty(&v).ok())
+                .unwrap_or_else(|| s.clone()),
+            Some(value) => serde_json::to_string_pretty(value).unwrap_or_default(),
+            None => "null".to_string(),
+        };
+        let lines = if matches!(value, Some(Value::String(s)) if text == *s) {
+            text.lines()
+                .map(|line| Line::from(line.to_string()))
+                .collect()
+        } else {
+            text.lines().map(highlight_json_line).collect()
+        };
+        Self {
+            title,
+            lines,
+            scroll: 0,
+        }
+    }
+
+    pub fn scroll_by(&mut self, delta: isize) {
+        let max = self.lines.len().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(delta).min(max);
+    }
+
+    pub fn render(&self, frame: &mut Frame<'_>) {
+        let area = centered(frame.area(), 80, 80);
+        let title = format!(
+            "{} ({}/{}, Esc to close)",
+            self.title,
+            (self.scroll + 1).min(self.lines.len()),
+            self.lines.len()
+        );
+        let body = Paragraph::new(self.lines.clone())
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll.min(u16::MAX as usize) as u16, 0));
+        frame.render_widget(Clear, area);
+        frame.render_widget(body, area);
+    }
+}
+
+fn centered(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
+/// Color one line of pretty-printed JSON: keys, strings, numbers, and literals.
+fn highlight_json_line(line: &str) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut rest = line;
+    while let Some(ch) = rest.chars().next() {
+        let (style, len) = if ch == '"' {
+            let len = string_len(rest);
+            let is_key = rest[len..].trim_start().starts_with(':');
+            let color = if is_key { Color::Cyan } else { Color::Green };
+            (Style::default().fg(color), len)
+        } else if ch == '-' || ch.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')))
+                .unwrap_or(rest.len());
+            (Style::default().fg(Color::Yellow), len)
+        } else if ch.is_ascii_alphabetic() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            (
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+                len,
+            )
+        } else {
+            (Style::default(), ch.len_utf8())
+        };
+        let (text, remaining) = rest.split_at(len);
+        spans.push(Span::styled(text.to_string(), style));
+        rest = remaining;
+    }
+    Line::from(spans)
+}
+
+/// Length of the JSON string literal at the start of `text`, including quotes.
+fn string_len(text: &str) -> usize {
+    let mut chars = text.char_indices().skip(1);
+    while let Some((idx, ch)) = chars.next() {
+        if ch == '\\' {
+            chars.next();
+        } else if ch == '"' {
+            return idx + 1;
+        }
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_keys_differently_from_values() {
+        let line = highlight_json_line(r#"  "name": "a \"b\"", "n": -1.5e3, "ok": true"#);
+        let styled: Vec<(String, Option<Color>)> = line
+            .spans
+            .iter()
+            .filter(|span| !span.content.trim().is_empty())
+            .map(|span| (span.content.to_string(), span.style.fg))
+            .collect();
+        assert_eq!(styled[0], ("\"name\"".to_string(), Some(Color::Cyan)));
+        assert_eq!(styled[2], (r#""a \"b\"""#.to_string(), Some(Color::Green)));
+        assert!(styled.contains(&("-1.5e3".to_string(), Some(Color::Yellow))));
+        assert!(styled.contains(&("true".to_string(), Some(Color::Magenta))));
+    }
+}
@@ -0,0 +1,172 @@
+/// A row limit to apply to queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowLimit {
+    pub rows: usize,
+    /// Set for an explicit `--limit`, which also caps a larger top-level limit the query
+    /// has of its own; the interactive default leaves such queries alone.
+    pub explicit: bool,
+}
+
+/// `query` with `limit` applied, or unchanged (trimmed) when its own limit stands.
+pub fn apply_limit(query: &str, limit: RowLimit) -> String {
+    limited(query, limit)
+        .unwrap_or_else(|| query.trim().trim_end_matches(';').trim_end().to_string())
+}
+
+/// `query` with `limit` applied, or `None` when the query's own top-level `LIMIT n` (SQL)
+/// or `limit: n` (BTQL pipe syntax) takes precedence. An added clause goes on its own
+/// line so a trailing `--` comment cannot swallow it.
+pub fn limited(query: &str, limit: RowLimit) -> Option<String> {
+    let query = query.trim().trim_end_matches(';').trim_end();
+    let words = top_level_words(query);
+
+    if let Some(&(_, end)) = words
+        .iter()
+        .rev()
+        .find(|&&(start, end)| query[start..end].eq_ignore_ascii_case("limit"))
+    {
+        if !limit.explicit {
+            return None;
+        }
+        let rest = query[end..].trim_start();
+        let rest = rest.strip_prefix(':').unwrap_or(rest).trim_start();
+        let start = query.len() - rest.len();
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let existing: usize = rest[..digits].parse().ok()?;
+        if existing <= limit.rows {
+            return None;
+        }
+        return Some(format!(
+            "{}{}{}",
+            &query[..start],
+            limit.rows,
+            &query[start + digits..]
+        ));
+    }
+
+    let is_btql = words.iter().any(|&(start, end)| {
+        query[start..end].eq_ignore_ascii_case("from") && query[end..].trim_start().starts_with(':')
+    });
+    Some(if is_btql {
+        format!("{query}\n| limit: {}", limit.rows)
+    } else {
+        format!("{query}\nLIMIT {}", limit.rows)
+    })
+}
+
+/// Byte ranges of identifier-like words outside strings, comments, and parentheses.
+fn top_level_words(query: &str) -> Vec<(usize, usize)> {
+    let mut words = Vec::new();
+    let mut depth = 0usize;
+    let mut chars = query.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '\'' | '"' => {
+                while let Some((_, c)) = chars.next() {
+                    if c == '\\' {
+                        chars.next();
+                    } else if c == ch {
+                        break;
+                    }
+                }
+            }
+            '-' if query[idx..].starts_with("--") => {
+                while chars.next_if(|&(_, c)| c != '\n').is_some() {}
+            }
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end = idx + c.len_utf8();
+                while let Some((i, c)) = chars.next_if(|&(_, c)| c.is_alphanumeric() || c == '_') {
+                    end = i + c.len_utf8();
+                }
+                if depth == 0 {
+                    words.push((idx, end));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEFAULT: RowLimit = RowLimit {
+        rows: 100,
+        explicit: false,
+    };
+
+    fn rows(rows: usize) -> RowLimit {
+        RowLimit {
+            rows,
+            explicit: false,
+        }
+    }
+
+    fn explicit(rows: usize) -> RowLimit {
+        RowLimit {
+            rows,
+            explicit: true,
+        }
+    }
+
+    #[test]
+    fn keeps_existing_limits() {
+        assert_eq!(
+            apply_limit("SELECT * FROM logs LIMIT 5000;", DEFAULT),
+            "SELECT * FROM logs LIMIT 5000"
+        );
+        assert_eq!(
+            apply_limit("select: * | from: project_logs('p') | limit: 10", rows(50)),
+            "select: * | from: project_logs('p') | limit: 10"
+        );
+        assert!(limited("SELECT * FROM logs LIMIT 5", explicit(10)).is_none());
+    }
+
+    #[test]
+    fn explicit_limits_cap_larger_ones() {
+        assert_eq!(
+            apply_limit("SELECT * FROM logs LIMIT 1000000 OFFSET 5;", explicit(10)),
+            "SELECT * FROM logs LIMIT 10 OFFSET 5"
+        );
+        assert_eq!(
+            apply_limit(
+                "select: * | from: project_logs('p') | limit:500",
+                explicit(20)
+            ),
+            "select: * | from: project_logs('p') | limit:20"
+        );
+    }
+
+    #[test]
+    fn appends_missing_limits() {
+        assert_eq!(
+            apply_limit(
+                "SELECT * FROM (SELECT * FROM t LIMIT 5) WHERE x = 'limit 3'",
+                rows(7)
+            ),
+            "SELECT * FROM (SELECT * FROM t LIMIT 5) WHERE x = 'limit 3'\nLIMIT 7"
+        );
+        assert_eq!(
+            apply_limit("select: *\n| from: experiment('e')", explicit(20)),
+            "select: *\n| from: experiment('e')\n| limit: 20"
+        );
+    }
+
+    #[test]
+    fn appends_after_trailing_comment() {
+        assert_eq!(
+            apply_limit("SELECT * FROM logs -- recent rows", rows(10)),
+            "SELECT * FROM logs -- recent rows\nLIMIT 10"
+        );
+        assert_eq!(
+            apply_limit("SELECT * FROM logs -- LIMIT 5", rows(10)),
+            "SELECT * FROM logs -- LIMIT 5\nLIMIT 10"
+        );
+    }
+}
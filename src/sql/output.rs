@@ -0,0 +1,97 @@
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// A file written to a temporary path next to its destination and renamed into
+/// place on `commit`, so readers never see a partially written file. The
+/// temporary file is removed if the writer is dropped without committing.
+pub(crate) struct AtomicFile {
+    file: Option<File>,
+    temp_path: PathBuf,
+    path: PathBuf,
+}
+
+impl AtomicFile {
+    pub fn create(path: &Path) -> Result<Self> {
+        let file_name = path
+            .file_name()
+            .with_context(|| format!("invalid output path {}", path.display()))?;
+        let temp_path = path.with_file_name(format!(
+            ".{}.{}.tmp",
+            file_name.to_string_lossy(),
+            std::process::id()
+        ));
+        let file = File::create(&temp_path)
+            .with_context(|| format!("failed to create {}", temp_path.display()))?;
+        Ok(Self {
+            file: Some(file),
+            temp_path,
+            path: path.to_path_buf(),
+        })
+    }
+
+    pub fn commit(mut self) -> Result<()> {
+        if let Some(file) = &self.file {
+            file.sync_all()
+                .with_context(|| format!("failed to write {}", self.temp_path.display()))?;
+        }
+        self.file = None;
+        std::fs::rename(&self.temp_path, &self.path)
+            .inspect_err(|_| {
+                let _ = std::fs::remove_file(&self.temp_path);
+            })
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+}
+
+impl Write for AtomicFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match &mut self.file {
+            Some(file) => file.write(buf),
+            None => Err(io::Error::other("file already committed")),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.file {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for AtomicFile {
+    fn drop(&mut self) {
+        if self.file.take().is_some() {
+            let _ = std::fs::remove_file(&self.temp_path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commit_replaces_destination_and_drop_cleans_up() {
+        let dir = std::env::temp_dir().join(format!("bt-sql-output-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        let path = dir.join("out.json");
+        std::fs::write(&path, "old").expect("seed file");
+
+        let mut abandoned = AtomicFile::create(&path).expect("create");
+        abandoned.write_all(b"partial").expect("write");
+        drop(abandoned);
+        assert_eq!(std::fs::read_to_string(&path).expect("read"), "old");
+        assert_eq!(std::fs::read_dir(&dir).expect("list").count(), 1);
+
+        let mut file = AtomicFile::create(&path).expect("create");
+        file.write_all(b"new").expect("write");
+        file.commit().expect("commit");
+        assert_eq!(std::fs::read_to_string(&path).expect("read"), "new");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,226 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use parquet::basic::{Compression, LogicalType, Repetition, Type as PhysicalType};
+use parquet::data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::Type;
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum ColumnKind {
+    Int,
+    Float,
+    Bool,
+    String,
+}
+
+/// Write rows as Parquet to `out`, one optional column per header. Column types
+/// come from the response schema and are widened if the data disagrees.
+pub fn write_parquet(
+    out: &mut (impl Write + Send),
+    headers: &[String],
+    schema: &Value,
+    rows: &[Map<String, Value>],
+) -> Result<()> {
+    let kinds: Vec<ColumnKind> = headers
+        .iter()
+        .map(|header| {
+            let declared = schema_kind(schema_property(schema, header));
+            widen_kind(declared, rows.iter().filter_map(|row| row.get(header)))
+        })
+        .collect();
+
+    let fields = headers
+        .iter()
+        .zip(&kinds)
+        .map(|(header, kind)| build_field(header, *kind).map(Arc::new))
+        .collect::<Result<Vec<_>>>()?;
+    let message = Type::group_type_builder("schema")
+        .with_fields(fields)
+        .build()
+        .context("failed to build parquet schema")?;
+
+    let props = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer = SerializedFileWriter::new(out, Arc::new(message), Arc::new(props))
+        .context("failed to start parquet file")?;
+
+    let mut row_group = writer.next_row_group()?;
+    for (header, kind) in headers.iter().zip(&kinds) {
+        let Some(mut column) = row_group.next_column()? else {
+            break;
+        };
+        let values = rows
+            .iter()
+            .map(|row| row.get(header).filter(|v| !v.is_null()));
+        let def_levels: Vec<i16> = values.clone().map(|v| v.is_some() as i16).collect();
+
+        match kind {
+            ColumnKind::Int => {
+                let data: Vec<i64> = values.flatten().filter_map(Value::as_i64).collect();
+                column
+                    .typed::<Int64Type>()
+                    .write_batch(&data, Some(&def_levels), None)?;
+            }
+            ColumnKind::Float => {
+                let data: Vec<f64> = values.flatten().filter_map(Value::as_f64).collect();
+                column
+                    .typed::<DoubleType>()
+                    .write_batch(&data, Some(&def_levels), None)?;
+            }
+            ColumnKind::Bool => {
+                let data: Vec<bool> = values.flatten().filter_map(Value::as_bool).collect();
+                column
+                    .typed::<BoolType>()
+                    .write_batch(&data, Some(&def_levels), None)?;
+            }
+            ColumnKind::String => {
+                let data: Vec<ByteArray> = values
+                    .flatten()
+                    .map(|v| match v {
+                        Value::String(s) => ByteArray::from(s.as_str()),
+                        other => ByteArray::from(other.to_string().as_str()),
+                    })
+                    .collect();
+                column
+                    .typed::<ByteArrayType>()
+                    .write_batch(&data, Some(&def_levels), None)?;
+            }
+        }
+        column.close()?;
+    }
+    row_group.close()?;
+    writer.close().context("failed to finish parquet file")?;
+    Ok(())
+}
+
+fn schema_property<'a>(schema: &'a Value, header: &str) -> Option<&'a Value> {
+    schema
+        .get("items")
+        .and_then(|items| items.get("properties"))
+        .and_then(|props| props.get(header))
+}
+
+fn schema_kind(property: Option<&Value>) -> ColumnKind {
+    let Some(property) = property else {
+        return ColumnKind::String;
+    };
+
+    let mut types: Vec<&str> = Vec::new();
+    match property.get("type") {
+        Some(Value::String(t)) => types.push(t),
+        Some(Value::Array(list)) => types.extend(list.iter().filter_map(Value::as_str)),
+        _ => {}
+    }
+    for key in ["anyOf", "oneOf"] {
+        if let Some(Value::Array(variants)) = property.get(key) {
+            types.extend(
+                variants
+                    .iter()
+                    .filter_map(|v| v.get("type").and_then(Value::as_str)),
+            );
+        }
+    }
+    types.retain(|t| *t != "null");
+
+    match types.as_slice() {
+        ["integer"] => ColumnKind::Int,
+        ["number"] | ["integer", "number"] | ["number", "integer"] => ColumnKind::Float,
+        ["boolean"] => ColumnKind::Bool,
+        _ => ColumnKind::String,
+    }
+}
+
+fn widen_kind<'a>(declared: ColumnKind, values: impl Iterator<Item = &'a Value>) -> ColumnKind {
+    let mut kind = declared;
+    for value in values {
+        kind = match (kind, value) {
+            (_, Value::Null) => kind,
+            (ColumnKind::Int, v) if v.is_i64() => kind,
+            (ColumnKind::Int | ColumnKind::Float, v) if v.is_number() => ColumnKind::Float,
+            (ColumnKind::Bool, Value::Bool(_)) => kind,
+            (ColumnKind::String, _) => kind,
+            _ => ColumnKind::String,
+        };
+    }
+    kind
+}
+
+fn build_field(name: &str, kind: ColumnKind) -> Result<Type> {
+    let (physical, logical) = match kind {
+        ColumnKind::Int => (PhysicalType::INT64, None),
+        ColumnKind::Float => (PhysicalType::DOUBLE, None),
+        ColumnKind::Bool => (PhysicalType::BOOLEAN, None),
+        ColumnKind::String => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+    };
+    Type::primitive_type_builder(name, physical)
+        .with_repetition(Repetition::OPTIONAL)
+        .with_logical_type(logical)
+        .build()
+        .with_context(|| format!("invalid parquet column '{name}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use serde_json::json;
+
+    #[test]
+    fn schema_kind_ignores_null_variants() {
+        assert_eq!(
+            schema_kind(Some(&json!({ "type": ["integer", "null"] }))),
+            ColumnKind::Int
+        );
+        assert_eq!(
+            schema_kind(Some(
+                &json!({ "anyOf": [{ "type": "number" }, { "type": "null" }] })
+            )),
+            ColumnKind::Float
+        );
+        assert_eq!(
+            schema_kind(Some(&json!({ "type": "object" }))),
+            ColumnKind::String
+        );
+    }
+
+    #[test]
+    fn write_parquet_round_trips_row_count() {
+        let path = std::env::temp_dir().join(format!(
+            "bt-sql-parquet-test-{}.parquet",
+            std::process::id()
+        ));
+        let schema = json!({
+            "items": { "properties": {
+                "id": { "type": "string" },
+                "count": { "type": "integer" },
+                "score": { "type": "number" },
+            } }
+        });
+        let rows: Vec<Map<String, Value>> = vec![
+            json!({ "id": "a", "count": 1, "score": 0.5 }),
+            json!({ "id": "b", "count": null, "score": 1 }),
+        ]
+        .into_iter()
+        .map(|v| v.as_object().cloned().expect("row is an object"))
+        .collect();
+        let headers = vec!["id".to_string(), "count".to_string(), "score".to_string()];
+
+        let mut file = File::create(&path).expect("create parquet file");
+        write_parquet(&mut file, &headers, &schema, &rows).expect("parquet should be written");
+
+        let reader = SerializedFileReader::new(File::open(&path).expect("open parquet"))
+            .expect("parquet should be readable");
+        let metadata = reader.metadata();
+        assert_eq!(metadata.file_metadata().num_rows(), 2);
+        assert_eq!(metadata.file_metadata().schema_descr().num_columns(), 3);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+
+use crate::config::config_dir;
+
+const QUERY_EXTENSION: &str = "sql";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SavedQuery {
+    pub name: String,
+    pub query: String,
+}
+
+/// Saved queries live as `<name>.sql` files under `<config dir>/queries`.
+fn queries_dir() -> Result<PathBuf> {
+    config_dir()
+        .map(|dir| dir.join("queries"))
+        .context("failed to resolve bt config directory")
+}
+
+fn query_path(name: &str) -> Result<PathBuf> {
+    validate_name(name)?;
+    Ok(queries_dir()?.join(format!("{name}.{QUERY_EXTENSION}")))
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        bail!("invalid query name '{name}' (use letters, digits, '-', '_' or '.')");
+    }
+    Ok(())
+}
+
+pub fn save(name: &str, query: &str, force: bool) -> Result<PathBuf> {
+    let path = query_path(name)?;
+    if path.exists() && !force {
+        bail!("saved query '{name}' already exists (use --force to overwrite)");
+    }
+    let dir = queries_dir()?;
+    std::fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    std::fs::write(&path, format!("{}\n", query.trim()))
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+pub fn load(name: &str) -> Result<String> {
+    let path = query_path(name)?;
+    match std::fs::read_to_string(&path) {
+        Ok(query) => Ok(query.trim().to_string()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            bail!("saved query '{name}' not found")
+        }
+        Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+    }
+}
+
+pub fn list() -> Result<Vec<SavedQuery>> {
+    let dir = queries_dir()?;
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err).with_context(|| format!("failed to read {}", dir.display())),
+    };
+
+    let mut queries = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(QUERY_EXTENSION) {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let query = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        queries.push(SavedQuery {
+            name: name.to_string(),
+            query: query.trim().to_string(),
+        });
+    }
+    queries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(queries)
+}
+
+pub fn delete(name: &str) -> Result<()> {
+    let path = query_path(name)?;
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            bail!("saved query '{name}' not found")
+        }
+        Err(err) => Err(err).with_context(|| format!("failed to delete {}", path.display())),
+    }
+}
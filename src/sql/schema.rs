@@ -0,0 +1,221 @@
+use anyhow::{Context, Result};
+use ratatui::layout::Rect;
+use ratatui::prelude::Frame;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
+use serde::Deserialize;
+use urlencoding::encode;
+
+use crate::http::ApiClient;
+use crate::projects::api::{get_project_by_name, ObjectList};
+
+use super::engine::EngineOptions;
+use super::{execute_query, quote_literal, result_headers};
+
+/// A queryable object in the active project, e.g. `experiment('<id>')`.
+pub struct SchemaObject {
+    label: String,
+    source: String,
+    fields: Option<Vec<String>>,
+    expanded: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Row {
+    Object(usize),
+    Field(usize, usize),
+}
+
+/// Sidebar listing the project's logs, experiments, and datasets with their fields.
+pub struct SchemaBrowser {
+    objects: Vec<SchemaObject>,
+    selected: usize,
+    pub focused: bool,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NamedObject {
+    id: String,
+    name: String,
+}
+
+impl SchemaBrowser {
+    pub fn new(objects: Vec<SchemaObject>) -> Self {
+        Self {
+            objects,
+            selected: 0,
+            focused: true,
+            message: None,
+        }
+    }
+
+    pub fn with_message(message: impl Into<String>) -> Self {
+        Self {
+            message: Some(message.into()),
+            ..Self::new(Vec::new())
+        }
+    }
+
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        for (idx, object) in self.objects.iter().enumerate() {
+            rows.push(Row::Object(idx));
+            if object.expanded {
+                let count = object.fields.as_ref().map_or(0, Vec::len);
+                rows.extend((0..count).map(|field| Row::Field(idx, field)));
+            }
+        }
+        rows
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.rows().len();
+        if self.selected + 1 < count {
+            self.selected += 1;
+        }
+    }
+
+    /// The object under the selection if it still needs its fields loaded.
+    pub fn pending_fields(&self) -> Option<String> {
+        match self.rows().get(self.selected) {
+            Some(Row::Object(idx)) if self.objects[*idx].fields.is_none() => {
+                Some(self.objects[*idx].source.clone())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn set_fields(&mut self, fields: Vec<String>) {
+        if let Some(Row::Object(idx)) = self.rows().get(self.selected).copied() {
+            self.objects[idx].fields = Some(fields);
+            self.objects[idx].expanded = true;
+        }
+    }
+
+    /// Expand or collapse the selected object.
+    pub fn toggle_expanded(&mut self, expanded: bool) {
+        let row = self.rows().get(self.selected).copied();
+        if let Some(Row::Object(idx) | Row::Field(idx, _)) = row {
+            self.objects[idx].expanded = expanded;
+            if !expanded {
+                self.selected = self
+                    .rows()
+                    .iter()
+                    .position(|r| matches!(r, Row::Object(i) if *i == idx))
+                    .unwrap_or(0);
+            }
+        }
+    }
+
+    /// Text to insert for the selected row: the object source or the field name.
+    pub fn selected_text(&self) -> Option<String> {
+        match self.rows().get(self.selected)? {
+            Row::Object(idx) => Some(self.objects[*idx].source.clone()),
+            Row::Field(idx, field) => self.objects[*idx]
+                .fields
+                .as_ref()
+                .and_then(|fields| fields.get(*field))
+                .cloned(),
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+        let border_style = if self.focused {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default()
+        };
+        let block = Block::default()
+            .title("Schema")
+            .borders(Borders::ALL)
+            .border_style(border_style);
+
+        if let Some(message) = &self.message {
+            let list = List::new([ListItem::new(message.as_str())]).block(block);
+            frame.render_widget(list, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .rows()
+            .into_iter()
+            .map(|row| match row {
+                Row::Object(idx) => {
+                    let object = &self.objects[idx];
+                    let marker = if object.expanded { "▾ " } else { "▸ " };
+                    ListItem::new(Line::from(vec![
+                        Span::raw(marker),
+                        Span::styled(
+                            object.label.clone(),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
+                    ]))
+                }
+                Row::Field(idx, field) => {
+                    let name = self.objects[idx]
+                        .fields
+                        .as_ref()
+                        .and_then(|fields| fields.get(field))
+                        .cloned()
+                        .unwrap_or_default();
+                    ListItem::new(format!("    {name}"))
+                }
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().bg(Color::DarkGray));
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+}
+
+/// List queryable objects in `project`: its logs plus every experiment and dataset.
+pub async fn load_objects(client: &ApiClient, project: &str) -> Result<Vec<SchemaObject>> {
+    let project = get_project_by_name(client, project)
+        .await?
+        .with_context(|| format!("project '{project}' not found"))?;
+
+    let mut objects = vec![SchemaObject {
+        label: "logs".to_string(),
+        source: format!("project_logs({})", quote_literal(&project.id)),
+        fields: None,
+        expanded: false,
+    }];
+
+    for (kind, path) in [("experiment", "/v1/experiment"), ("dataset", "/v1/dataset")] {
+        let list: ObjectList<NamedObject> = client
+            .get(&format!("{path}?project_id={}", encode(&project.id)))
+            .await?;
+        let mut named = list.objects;
+        named.sort_by(|a, b| a.name.cmp(&b.name));
+        objects.extend(named.into_iter().map(|object| SchemaObject {
+            label: format!("{kind}: {}", object.name),
+            source: format!("{kind}({})", quote_literal(&object.id)),
+            fields: None,
+            expanded: false,
+        }));
+    }
+
+    Ok(objects)
+}
+
+/// Discover an object's fields from the schema of a one-row query.
+pub async fn load_fields(
+    client: &ApiClient,
+    source: &str,
+    engine: EngineOptions,
+) -> Result<Vec<String>> {
+    let query = format!("SELECT * FROM {source} LIMIT 1");
+    let response = execute_query(client, &query, engine).await?;
+    let mut fields = result_headers(&response);
+    fields.sort();
+    Ok(fields)
+}
@@ -0,0 +1,66 @@
+use std::cmp::Ordering;
+
+use serde_json::{Map, Value};
+
+/// Sort rows by `column`. Numbers compare numerically, strings lexically, and
+/// rows with a missing or null value sort last in either direction.
+pub fn sort_rows(rows: &mut [Map<String, Value>], column: &str, descending: bool) {
+    rows.sort_by(|a, b| {
+        let a = a.get(column).filter(|v| !v.is_null());
+        let b = b.get(column).filter(|v| !v.is_null());
+        match (a, b) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(a), Some(b)) => {
+                let ordering = compare_values(a, b);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        }
+    });
+}
+
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (a, b) => a.to_string().cmp(&b.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn sorts_numbers_numerically_with_nulls_last() {
+        let mut rows: Vec<Map<String, Value>> = [
+            json!({ "score": 10 }),
+            json!({ "score": null }),
+            json!({ "score": 9.5 }),
+            json!({}),
+        ]
+        .into_iter()
+        .map(|v| v.as_object().cloned().expect("row is an object"))
+        .collect();
+
+        sort_rows(&mut rows, "score", false);
+        let scores: Vec<Option<&Value>> = rows.iter().map(|r| r.get("score")).collect();
+        assert_eq!(scores[0], Some(&json!(9.5)));
+        assert_eq!(scores[1], Some(&json!(10)));
+
+        sort_rows(&mut rows, "score", true);
+        assert_eq!(rows[0].get("score"), Some(&json!(10)));
+        assert!(rows[2].get("score").is_none_or(Value::is_null));
+        assert!(rows[3].get("score").is_none_or(Value::is_null));
+    }
+}
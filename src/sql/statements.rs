@@ -0,0 +1,63 @@
+/// Split `query` into statements on top-level semicolons, ignoring semicolons in
+/// string literals and `--` comments. Empty statements are dropped.
+pub fn split_statements(query: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut chars = query.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '\'' | '"' => {
+                while let Some((_, c)) = chars.next() {
+                    if c == '\\' {
+                        chars.next();
+                    } else if c == ch {
+                        break;
+                    }
+                }
+            }
+            '-' if query[idx..].starts_with("--") => {
+                while chars.next_if(|&(_, c)| c != '\n').is_some() {}
+            }
+            ';' => {
+                statements.push(&query[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    statements.push(&query[start..]);
+
+    statements
+        .into_iter()
+        .map(str::trim)
+        .filter(|statement| !is_blank(statement))
+        .map(str::to_string)
+        .collect()
+}
+
+/// True when `statement` holds nothing but whitespace and comments.
+fn is_blank(statement: &str) -> bool {
+    statement
+        .lines()
+        .all(|line| line.trim().is_empty() || line.trim_start().starts_with("--"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_on_top_level_semicolons() {
+        let statements = split_statements(
+            "SELECT 'a;b' FROM t; -- note; not a statement\nSELECT 2;\n;\n-- trailing comment",
+        );
+        assert_eq!(
+            statements,
+            vec![
+                "SELECT 'a;b' FROM t".to_string(),
+                "-- note; not a statement\nSELECT 2".to_string(),
+            ]
+        );
+    }
+}
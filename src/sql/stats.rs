@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use super::SqlResponse;
+
+/// One-line summary of a query run: rows, wall time, bytes read, and freshness.
+/// `elapsed` is `None` for results served from the local cache.
+pub fn format_stats(response: &SqlResponse, elapsed: Option<Duration>) -> String {
+    let rows = response.data.len();
+    let mut parts = vec![format!("{rows} row{}", if rows == 1 { "" } else { "s" })];
+    match elapsed {
+        Some(elapsed) => parts.push(format!("{:.2}s", elapsed.as_secs_f64())),
+        None => parts.push("cached".to_string()),
+    }
+    if let Some(realtime) = &response.realtime_state {
+        parts.push(format!("{} read", format_bytes(realtime.read_bytes)));
+    }
+    if let Some(freshness) = &response.freshness_state {
+        parts.push(format!(
+            "processed through xact {}",
+            freshness.last_processed_xact_id
+        ));
+    }
+    parts.join(" · ")
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_bytes_picks_unit() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+    }
+
+    #[test]
+    fn stats_include_server_state() {
+        let response: SqlResponse = serde_json::from_value(serde_json::json!({
+            "data": [{ "id": 1 }],
+            "schema": {},
+            "realtime_state": {
+                "actual_xact_id": "2",
+                "minimum_xact_id": "1",
+                "read_bytes": 2048,
+                "type": "on"
+            },
+            "freshness_state": {
+                "last_considered_xact_id": "9",
+                "last_processed_xact_id": "8"
+            }
+        }))
+        .expect("valid response");
+        assert_eq!(
+            format_stats(&response, Some(Duration::from_millis(1250))),
+            "1 row · 1.25s · 2.0 KB read · processed through xact 8"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+
+use crate::ui;
+
+/// Parse a `--param name=value` argument.
+pub fn parse_param(raw: &str) -> Result<(String, String), String> {
+    let (name, value) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got '{raw}'"))?;
+    let name = name.trim();
+    if name.is_empty() || !name.chars().all(is_name_char) {
+        return Err(format!("invalid parameter name '{name}'"));
+    }
+    Ok((name.to_string(), value.to_string()))
+}
+
+/// Fill every `{{name}}` placeholder in `query`, taking values from `params`
+/// and prompting for any that are missing when running interactively.
+pub fn fill_placeholders(query: &str, params: &[(String, String)]) -> Result<String> {
+    let mut values: HashMap<&str, String> = params
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.clone()))
+        .collect();
+    for name in placeholders(query) {
+        if values.contains_key(name) {
+            continue;
+        }
+        if !ui::is_interactive() {
+            bail!("missing value for {{{{{name}}}}}; pass --param {name}=VALUE");
+        }
+        let value = ui::input(name).with_context(|| format!("failed to read {name}"))?;
+        values.insert(name, value);
+    }
+    Ok(render(query, |name| values.get(name).cloned()))
+}
+
+/// Placeholder names in order of first appearance.
+fn placeholders(query: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = query;
+    while let Some(((_, name), after)) = next_placeholder(rest) {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+        rest = after;
+    }
+    names
+}
+
+fn render(query: &str, value: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(query.len());
+    let mut rest = query;
+    while let Some(((before, name), after)) = next_placeholder(rest) {
+        out.push_str(before);
+        out.push_str(&value(name).unwrap_or_default());
+        rest = after;
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Find the next `{{ name }}`, returning the text before it, the name, and the text after it.
+fn next_placeholder(text: &str) -> Option<((&str, &str), &str)> {
+    let mut offset = 0;
+    while let Some(start) = text[offset..].find("{{").map(|idx| offset + idx) {
+        let inner_start = start + 2;
+        if let Some(len) = text[inner_start..].find("}}") {
+            let name = text[inner_start..inner_start + len].trim();
+            if !name.is_empty() && name.chars().all(is_name_char) {
+                return Some(((&text[..start], name), &text[inner_start + len + 2..]));
+            }
+        }
+        offset = inner_start;
+    }
+    None
+}
+
+fn is_name_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_and_renders_placeholders() {
+        let query = "SELECT * FROM project_logs('{{ project_id }}') WHERE created > '{{since}}' \
+                     AND input = '{{since}}' AND x = '{{not a name}}'";
+        assert_eq!(placeholders(query), vec!["project_id", "since"]);
+
+        let rendered = render(query, |name| match name {
+            "project_id" => Some("p1".to_string()),
+            "since" => Some("2024-01-01".to_string()),
+            _ => None,
+        });
+        assert_eq!(
+            rendered,
+            "SELECT * FROM project_logs('p1') WHERE created > '2024-01-01' \
+             AND input = '2024-01-01' AND x = '{{not a name}}'"
+        );
+    }
+
+    #[test]
+    fn parse_param_splits_on_first_equals() {
+        assert_eq!(
+            parse_param("filter=a=b"),
+            Ok(("filter".to_string(), "a=b".to_string()))
+        );
+        assert!(parse_param("novalue").is_err());
+        assert!(parse_param("bad name=1").is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+
+use crate::args::BaseArgs;
+
+static ANSWERS: OnceLock<Answers> = OnceLock::new();
+
+/// Pre-recorded responses for interactive prompts, keyed by prompt text.
+#[derive(Debug, Default)]
+struct Answers {
+    prompts: HashMap<String, Answer>,
+    /// `--select-index` values, consumed one per selection prompt.
+    select_indices: Vec<usize>,
+    next_select: AtomicUsize,
+}
+
+impl Answers {
+    fn next_select_index(&self) -> Option<usize> {
+        let next = self.next_select.fetch_add(1, Ordering::Relaxed);
+        self.select_indices.get(next).copied()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Bool(bool),
+    Index(usize),
+    Text(String),
+    List(Vec<Answer>),
+}
+
+/// Load answers from `--answers`/`BT_ANSWERS` and `--select-index`.
+pub fn init_answers(base: &BaseArgs) -> Result<()> {
+    let prompts = match &base.answers {
+        Some(path) => read_answers_file(path)?,
+        None => HashMap::new(),
+    };
+    let _ = ANSWERS.set(Answers {
+        prompts,
+        select_indices: base.select_index.clone(),
+        next_select: AtomicUsize::new(0),
+    });
+    Ok(())
+}
+
+fn read_answers_file(path: &Path) -> Result<HashMap<String, Answer>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read answers file {}", path.display()))?;
+    serde_yaml::from_str(&contents)
+        .with_context(|| format!("failed to parse answers file {}", path.display()))
+}
+
+fn answers() -> Option<&'static Answers> {
+    ANSWERS
+        .get()
+        .filter(|a| !a.prompts.is_empty() || !a.select_indices.is_empty())
+}
+
+/// True when prompts can be answered, either by a user at a TTY or from scripted answers.
+pub fn is_interactive() -> bool {
+    std::io::stdin().is_terminal() || answers().is_some()
+}
+
+pub(super) fn answer_for(prompt: &str) -> Option<&'static Answer> {
+    answers().and_then(|a| a.prompts.get(prompt))
+}
+
+/// The next unused `--select-index` value, if any remain.
+pub(super) fn next_select_index() -> Option<usize> {
+    answers().and_then(Answers::next_select_index)
+}
+
+/// Error for a prompt that has no scripted answer and no TTY to ask on.
+pub(super) fn unanswered(prompt: &str) -> anyhow::Error {
+    if answers().is_some() {
+        anyhow!("no answer for prompt '{prompt}' (add it to the --answers file)")
+    } else {
+        anyhow!("interactive mode requires TTY")
+    }
+}
+
+impl Answer {
+    pub(super) fn as_bool(&self, prompt: &str) -> Result<bool> {
+        match self {
+            Answer::Bool(value) => Ok(*value),
+            Answer::Text(text) => match text.trim().to_ascii_lowercase().as_str() {
+                "y" | "yes" | "true" => Ok(true),
+                "n" | "no" | "false" => Ok(false),
+                _ => bail!("invalid answer '{text}' for prompt '{prompt}' (expected yes/no)"),
+            },
+            Answer::Index(_) | Answer::List(_) => {
+                bail!("invalid answer for prompt '{prompt}' (expected yes/no)")
+            }
+        }
+    }
+
+    pub(super) fn as_text(&self) -> String {
+        match self {
+            Answer::Bool(value) => value.to_string(),
+            Answer::Index(value) => value.to_string(),
+            Answer::Text(text) => text.clone(),
+            Answer::List(items) => items
+                .iter()
+                .map(Answer::as_text)
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+
+    pub(super) fn as_index(&self, prompt: &str, labels: &[String]) -> Result<usize> {
+        let index = match self {
+            Answer::Index(idx) => Some(*idx),
+            Answer::Text(text) => labels.iter().position(|label| label == text),
+            Answer::Bool(_) | Answer::List(_) => None,
+        };
+        match index {
+            Some(idx) if idx < labels.len() => Ok(idx),
+            _ => bail!(
+                "answer '{}' for prompt '{prompt}' does not match any option",
+                self.as_text()
+            ),
+        }
+    }
+
+    /// Indices for a multi-select prompt: a list of options, or a single one.
+    pub(super) fn as_indices(&self, prompt: &str, labels: &[String]) -> Result<Vec<usize>> {
+        match self {
+            Answer::List(items) => items
+                .iter()
+                .map(|item| item.as_index(prompt, labels))
+                .collect(),
+            single => Ok(vec![single.as_index(prompt, labels)?]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_file_parses_mixed_values() {
+        let parsed: HashMap<String, Answer> = serde_yaml::from_str(
+            "\"Delete project 'demo'?\": yes\nSelect project: 2\nProject name: demo\n",
+        )
+        .expect("answers should parse");
+
+        let confirm = &parsed["Delete project 'demo'?"];
+        assert!(confirm.as_bool("confirm").expect("yes is a bool answer"));
+        assert_eq!(parsed["Project name"].as_text(), "demo");
+
+        let labels = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert_eq!(
+            parsed["Select project"]
+                .as_index("select", &labels)
+                .expect("index in range"),
+            2
+        );
+    }
+
+    #[test]
+    fn select_answer_matches_label_text() {
+        let labels = vec!["alpha".to_string(), "beta".to_string()];
+        let answer = Answer::Text("beta".to_string());
+        assert_eq!(answer.as_index("select", &labels).expect("label exists"), 1);
+        assert!(Answer::Text("gamma".to_string())
+            .as_index("select", &labels)
+            .is_err());
+    }
+
+    #[test]
+    fn select_indices_are_consumed_in_order() {
+        let answers = Answers {
+            prompts: HashMap::new(),
+            select_indices: vec![1, 0],
+            next_select: AtomicUsize::new(0),
+        };
+        assert_eq!(answers.next_select_index(), Some(1));
+        assert_eq!(answers.next_select_index(), Some(0));
+        assert_eq!(answers.next_select_index(), None);
+    }
+
+    #[test]
+    fn multi_select_answer_accepts_list() {
+        let labels = vec!["alpha".to_string(), "beta".to_string(), "gamma".to_string()];
+        let parsed: HashMap<String, Answer> = serde_yaml::from_str(
+            "Select projects: [gamma, 0]
+",
+        )
+        .expect("answers should parse");
+        assert_eq!(
+            parsed["Select projects"]
+                .as_indices("select", &labels)
+                .expect("options exist"),
+            vec![2, 0]
+        );
+    }
+}
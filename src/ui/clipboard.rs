@@ -0,0 +1,61 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+use base64::Engine;
+
+/// Copy `text` to the system clipboard using the platform's clipboard tool, or
+/// an OSC 52 escape sequence over SSH or when no tool is available. Returns a
+/// short description of the method used.
+pub fn copy_to_clipboard(text: &str) -> Result<&'static str> {
+    let over_ssh =
+        std::env::var_os("SSH_TTY").is_some() || std::env::var_os("SSH_CONNECTION").is_some();
+    if !over_ssh {
+        for (program, args) in clipboard_commands() {
+            if pipe_to(program, args, text).is_ok() {
+                return Ok(program);
+            }
+        }
+    }
+    copy_osc52(text)?;
+    Ok("terminal (OSC 52)")
+}
+
+fn clipboard_commands() -> Vec<(&'static str, &'static [&'static str])> {
+    if cfg!(target_os = "macos") {
+        vec![("pbcopy", &[])]
+    } else if cfg!(windows) {
+        vec![("clip", &[])]
+    } else {
+        let mut commands: Vec<(&'static str, &'static [&'static str])> = Vec::new();
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            commands.push(("wl-copy", &[]));
+        }
+        commands.push(("xclip", &["-selection", "clipboard"]));
+        commands.push(("xsel", &["--clipboard", "--input"]));
+        commands
+    }
+}
+
+fn pipe_to(program: &str, args: &[&str], text: &str) -> Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    anyhow::ensure!(status.success(), "{program} exited with {status}");
+    Ok(())
+}
+
+fn copy_osc52(text: &str) -> Result<()> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{encoded}\x07")
+        .and_then(|_| stdout.flush())
+        .context("failed to write to terminal")
+}
@@ -1,10 +1,22 @@
+mod answers;
+mod clipboard;
+mod pager;
+mod progress;
+mod prompt;
 mod select;
 mod shell;
 mod spinner;
 mod status;
+mod terminal;
 
-pub use select::fuzzy_select;
+pub use answers::{init_answers, is_interactive};
+pub use clipboard::copy_to_clipboard;
+pub use pager::print_paged;
+pub use progress::progress_bar;
+pub use prompt::{confirm, input};
+pub use select::{fuzzy_select, multi_select};
 pub use shell::print_env_export;
 pub use spinner::{with_spinner, with_spinner_visible};
+pub use terminal::is_limited_terminal;
 
 pub use status::{print_command_status, CommandStatus};
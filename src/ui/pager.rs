@@ -0,0 +1,60 @@
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+use unicode_width::UnicodeWidthStr;
+
+const DEFAULT_PAGER: &str = "less -SRX";
+
+/// Print `text` to stdout, piping it through `$PAGER` (default `less -SRX`) when
+/// stdout is a terminal and the text is taller or wider than the terminal.
+pub fn print_paged(text: &str) -> Result<()> {
+    if !std::io::stdout().is_terminal() || fits_terminal(text) {
+        println!("{text}");
+        return Ok(());
+    }
+
+    let pager = std::env::var("PAGER")
+        .ok()
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_PAGER.to_string());
+    let mut parts = pager.split_whitespace();
+    let Some(program) = parts.next() else {
+        println!("{text}");
+        return Ok(());
+    };
+
+    let mut child = match Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(_) => {
+            println!("{text}");
+            return Ok(());
+        }
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // The pager may exit before reading everything (e.g. `q` in less).
+        let _ = writeln!(stdin, "{text}");
+    }
+    child
+        .wait()
+        .with_context(|| format!("failed to run pager '{pager}'"))?;
+    Ok(())
+}
+
+fn fits_terminal(text: &str) -> bool {
+    let Ok((width, height)) = crossterm::terminal::size() else {
+        return true;
+    };
+    let mut lines = 0;
+    for line in text.lines() {
+        lines += 1;
+        if lines >= height as usize || line.width() > width as usize {
+            return false;
+        }
+    }
+    true
+}
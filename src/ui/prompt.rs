@@ -0,0 +1,33 @@
+use std::io::IsTerminal;
+
+use anyhow::Result;
+use dialoguer::{Confirm, Input};
+
+use super::answers::{answer_for, unanswered};
+
+/// Ask a yes/no question. Requires TTY unless answered via `--answers`.
+pub fn confirm(prompt: &str, default: bool) -> Result<bool> {
+    if let Some(answer) = answer_for(prompt) {
+        return answer.as_bool(prompt);
+    }
+    if !std::io::stdin().is_terminal() {
+        return Err(unanswered(prompt));
+    }
+
+    Ok(Confirm::new()
+        .with_prompt(prompt)
+        .default(default)
+        .interact()?)
+}
+
+/// Prompt for a line of text. Requires TTY unless answered via `--answers`.
+pub fn input(prompt: &str) -> Result<String> {
+    if let Some(answer) = answer_for(prompt) {
+        return Ok(answer.as_text());
+    }
+    if !std::io::stdin().is_terminal() {
+        return Err(unanswered(prompt));
+    }
+
+    Ok(Input::new().with_prompt(prompt).interact_text()?)
+}
@@ -1,20 +1,35 @@
 use std::io::IsTerminal;
 
 use anyhow::{bail, Result};
-use dialoguer::{theme::ColorfulTheme, FuzzySelect};
+use dialoguer::{theme::ColorfulTheme, FuzzySelect, MultiSelect};
 
-/// Fuzzy select from a list of items. Requires TTY.
-pub fn fuzzy_select<T: ToString>(prompt: &str, items: &[T]) -> Result<usize> {
-    if !std::io::stdin().is_terminal() {
-        bail!("interactive mode requires TTY");
-    }
+use super::answers::{answer_for, next_select_index, unanswered};
 
+/// Fuzzy select from a list of items. Requires TTY unless answered via `--answers`/`--select-index`.
+pub fn fuzzy_select<T: ToString>(prompt: &str, items: &[T]) -> Result<usize> {
     if items.is_empty() {
         bail!("no items to select from");
     }
 
     let labels: Vec<String> = items.iter().map(|i| i.to_string()).collect();
 
+    if let Some(answer) = answer_for(prompt) {
+        return answer.as_index(prompt, &labels);
+    }
+    if let Some(idx) = next_select_index() {
+        if idx >= labels.len() {
+            bail!(
+                "--select-index {idx} is out of range ({} options)",
+                labels.len()
+            );
+        }
+        return Ok(idx);
+    }
+
+    if !std::io::stdin().is_terminal() {
+        return Err(unanswered(prompt));
+    }
+
     let selection = FuzzySelect::with_theme(&ColorfulTheme::default())
         .with_prompt(prompt)
         .items(&labels)
@@ -23,3 +38,25 @@ pub fn fuzzy_select<T: ToString>(prompt: &str, items: &[T]) -> Result<usize> {
 
     Ok(selection)
 }
+
+/// Pick any number of items with checkboxes. Requires TTY unless answered via `--answers`.
+pub fn multi_select<T: ToString>(prompt: &str, items: &[T]) -> Result<Vec<usize>> {
+    if items.is_empty() {
+        bail!("no items to select from");
+    }
+
+    let labels: Vec<String> = items.iter().map(|i| i.to_string()).collect();
+
+    if let Some(answer) = answer_for(prompt) {
+        return answer.as_indices(prompt, &labels);
+    }
+
+    if !std::io::stdin().is_terminal() {
+        return Err(unanswered(prompt));
+    }
+
+    Ok(MultiSelect::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .items(&labels)
+        .interact()?)
+}
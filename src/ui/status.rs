@@ -2,12 +2,14 @@ use dialoguer::console::style;
 
 pub enum CommandStatus {
     Success,
+    Warning,
     Error,
 }
 
 pub fn print_command_status(status: CommandStatus, message: &str) {
     let indicator = match status {
         CommandStatus::Success => style("✓").green(),
+        CommandStatus::Warning => style("!").yellow(),
         CommandStatus::Error => style("✗").red(),
     };
     println!("{indicator} {message}");
@@ -0,0 +1,21 @@
+const MIN_TUI_WIDTH: u16 = 40;
+const MIN_TUI_HEIGHT: u16 = 10;
+
+/// Returns true when the current terminal can't reasonably host a full-screen TUI
+/// (TERM=dumb, too small, or a legacy Windows console without ANSI support).
+pub fn is_limited_terminal() -> bool {
+    let term = std::env::var("TERM").unwrap_or_default();
+    if term == "dumb" || (cfg!(unix) && term.is_empty()) {
+        return true;
+    }
+
+    #[cfg(windows)]
+    if !crossterm::ansi_support::supports_ansi() {
+        return true;
+    }
+
+    match crossterm::terminal::size() {
+        Ok((width, height)) => width < MIN_TUI_WIDTH || height < MIN_TUI_HEIGHT,
+        Err(_) => true,
+    }
+}